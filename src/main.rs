@@ -0,0 +1,14 @@
+use std::io;
+use std::process::ExitCode;
+use clap::Parser;
+use planner::workcontent::main::cli::{self, Cli};
+
+fn main() -> ExitCode {
+    match cli::run(Cli::parse(), &mut io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use joda_rs::{LocalDate, LocalDateTime};
+use crate::workcontent::common::numbers::round_to_i32;
+
+const MINUTES_PER_HOUR: f64 = 60.0;
+
+pub fn plus_hours(date_time: LocalDateTime, hours: f64) -> LocalDateTime {
+    date_time.plus_minutes(round_to_i32(hours * MINUTES_PER_HOUR) as i64)
+}
+
+pub fn minus_hours(date_time: LocalDateTime, hours: f64) -> LocalDateTime {
+    date_time.minus_minutes(round_to_i32(hours * MINUTES_PER_HOUR) as i64)
+}
+
+/// The date as `yyyy-MM-dd`.
+pub fn format_date(date: LocalDate) -> String {
+    format!("{:04}-{:02}-{:02}", date.year(), date.month_value(), date.day_of_month())
+}
+
+/// The date and time as `yyyy-MM-ddTHH:mm:ss`.
+pub fn format_date_time(date_time: LocalDateTime) -> String {
+    format!(
+        "{}T{:02}:{:02}:{:02}",
+        format_date(date_time.to_local_date()),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::{LocalDate, LocalTime};
+
+    #[test]
+    fn adds_and_subtracts_fractional_hours_to_the_minute() {
+        let date_time = LocalDate::new(2025, 1, 6).at_time(LocalTime::new(23, 0, 0));
+
+        assert_eq!(plus_hours(date_time, 1.5), LocalDate::new(2025, 1, 7).at_time(LocalTime::new(0, 30, 0)));
+        assert_eq!(minus_hours(date_time, 0.25), LocalDate::new(2025, 1, 6).at_time(LocalTime::new(22, 45, 0)));
+    }
+
+    #[test]
+    fn formats_dates_and_date_times() {
+        assert_eq!(format_date(LocalDate::new(2025, 1, 6)), "2025-01-06");
+        assert_eq!(format_date_time(LocalDate::new(2025, 1, 6).at_time(LocalTime::new(7, 5, 9))), "2025-01-06T07:05:09");
+    }
+}
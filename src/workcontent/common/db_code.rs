@@ -0,0 +1,165 @@
+use std::fmt;
+
+/// A domain enum stored in the database as the code of a schema enum, e.g. `Units::Hours` as
+/// the `UnitsEnum` code `HR`.
+pub trait DbCode: Sized + Copy + PartialEq + 'static {
+    /// The name of the schema enum, e.g. `UnitsEnum`.
+    const ENUM_TYPE: &'static str;
+    /// Every variant, for reading codes back.
+    const VALUES: &'static [Self];
+
+    /// The code of the variant, matched for every variant so that none can be left without one.
+    fn code(&self) -> &'static str;
+
+    /// Codes are matched exactly. Codes of the schema enum that have no variant are errors like
+    /// unknown codes.
+    fn from_code(code: &str) -> Result<Self, CodeError> {
+        Self::VALUES.iter()
+            .find(|value| value.code() == code)
+            .copied()
+            .ok_or_else(|| CodeError {
+                enum_type: Self::ENUM_TYPE,
+                code: code.to_string(),
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeError {
+    pub enum_type: &'static str,
+    pub code: String,
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} code \"{}\" is not supported", self.enum_type, self.code)
+    }
+}
+
+impl std::error::Error for CodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use crate::workcontent::domain::distribution_method::DistributionMethod;
+    use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+    use crate::workcontent::domain::planned_shift_source::PlannedShiftSource;
+    use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+    use crate::workcontent::domain::recurring_task_standard::{DurationType, FrequencyType, MonthlyIntervalType, OccurrenceType};
+    use crate::workcontent::domain::salary_mode::SalaryMode;
+    use crate::workcontent::domain::spread_standard::SpreadStandardType;
+    use crate::workcontent::domain::standard_type::StandardType;
+    use crate::workcontent::domain::units::Units;
+    use crate::workcontent::domain::value_type::ValueType;
+    use crate::workcontent::domain::work_content_detail_type::WorkContentDetailType;
+    use crate::workcontent::domain::work_content_type::WorkContentType;
+    use crate::workcontent::domain::work_type::WorkType;
+    use crate::workcontent::schema::schema_catalogue::SchemaCatalogue;
+    use crate::workcontent::schema::schema_reader::SchemaReader;
+
+    fn assert_codes_are_in_the_schema<T: DbCode + fmt::Debug>(catalogue: &SchemaCatalogue) {
+        let enumeration = catalogue.enumeration(T::ENUM_TYPE)
+            .unwrap_or_else(|| panic!("schema.xml has no enum {}", T::ENUM_TYPE));
+
+        for value in T::VALUES {
+            assert!(enumeration.name_for(value.code()).is_some(), "{:?}: {} has no code {}", value, T::ENUM_TYPE, value.code());
+            assert_eq!(T::from_code(value.code()), Ok(*value));
+        }
+    }
+
+    fn assert_schema_codes_round_trip<T: DbCode + fmt::Debug>(catalogue: &SchemaCatalogue) {
+        let enumeration = catalogue.enumeration(T::ENUM_TYPE).unwrap();
+
+        for value in &enumeration.values {
+            let variant = T::from_code(&value.code).unwrap_or_else(|error| panic!("{} {}: {}", T::ENUM_TYPE, value.name, error));
+            assert_eq!(variant.code(), value.code);
+        }
+    }
+
+    fn catalogue() -> SchemaCatalogue {
+        SchemaReader::new()
+            .read_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("resources").join("schema.xml"))
+            .unwrap()
+    }
+
+    #[test]
+    fn every_plan_type_code_has_a_shift_type() {
+        assert_schema_codes_round_trip::<PlannedShiftType>(&catalogue());
+    }
+
+    #[test]
+    fn every_shift_source_type_code_has_a_shift_source() {
+        assert_schema_codes_round_trip::<PlannedShiftSource>(&catalogue());
+    }
+
+    #[test]
+    fn every_standard_type_code_has_a_standard_type() {
+        assert_schema_codes_round_trip::<StandardType>(&catalogue());
+    }
+
+    #[test]
+    fn every_code_is_a_schema_enum_code() {
+        let catalogue = catalogue();
+
+        assert_codes_are_in_the_schema::<Units>(&catalogue);
+        assert_codes_are_in_the_schema::<StandardType>(&catalogue);
+        assert_codes_are_in_the_schema::<SalaryMode>(&catalogue);
+        assert_codes_are_in_the_schema::<NonFlowedDistributionMethod>(&catalogue);
+        assert_codes_are_in_the_schema::<PlannedShiftType>(&catalogue);
+        assert_codes_are_in_the_schema::<PlannedShiftSource>(&catalogue);
+        assert_codes_are_in_the_schema::<WorkType>(&catalogue);
+        assert_codes_are_in_the_schema::<DistributionMethod>(&catalogue);
+        assert_codes_are_in_the_schema::<SpreadStandardType>(&catalogue);
+        assert_codes_are_in_the_schema::<ValueType>(&catalogue);
+        assert_codes_are_in_the_schema::<FrequencyType>(&catalogue);
+        assert_codes_are_in_the_schema::<MonthlyIntervalType>(&catalogue);
+        assert_codes_are_in_the_schema::<OccurrenceType>(&catalogue);
+        assert_codes_are_in_the_schema::<DurationType>(&catalogue);
+        assert_codes_are_in_the_schema::<WorkContentType>(&catalogue);
+        assert_codes_are_in_the_schema::<WorkContentDetailType>(&catalogue);
+    }
+
+    #[test]
+    fn standard_types_are_the_schema_shift_event_and_kbi_related_types() {
+        let catalogue = catalogue();
+        let enumeration = catalogue.enumeration(StandardType::ENUM_TYPE).unwrap();
+
+        let names: Vec<(StandardType, Option<&str>)> = StandardType::VALUES.iter()
+            .map(|standard_type| (*standard_type, enumeration.name_for(standard_type.code())))
+            .collect();
+
+        assert_eq!(names, [
+            (StandardType::NONE, Some("NONE")),
+            (StandardType::BASIC, Some("SHIFT_RELATED")),
+            (StandardType::EVENT, Some("EVENT_RELATED")),
+            (StandardType::ADVANCED, Some("KBI_RELATED")),
+            (StandardType::SALARIED, Some("SALARIED")),
+        ]);
+        for standard_type in StandardType::VALUES {
+            assert_eq!(StandardType::from_code(standard_type.code()), Ok(*standard_type));
+        }
+    }
+
+    #[test]
+    fn codes_convert_both_ways() {
+        assert_eq!(Units::MinutesPerUnit.code(), "MU");
+        assert_eq!(Units::from_code("US"), Ok(Units::UnitsPerShift));
+        assert_eq!(Units::from_code("UP"), Ok(Units::UnitsPerPerson));
+        assert_eq!(WorkContentType::NonEventRelated.code(), "N");
+        assert_eq!(WorkContentDetailType::from_code("T"), Ok(WorkContentDetailType::NormalTask));
+        assert_eq!(StandardType::from_code("K"), Ok(StandardType::ADVANCED));
+        assert_eq!(PlannedShiftType::Projected.code(), "F");
+        assert_eq!(PlannedShiftSource::from_code("M"), Ok(PlannedShiftSource::Manual));
+        assert_eq!(PlannedShiftType::from_code("O"), Ok(PlannedShiftType::Original));
+        assert_eq!(StandardType::from_code("E"), Ok(StandardType::EVENT));
+    }
+
+    #[test]
+    fn unknown_and_unsupported_codes_are_errors() {
+        assert_eq!(Units::from_code("hr").unwrap_err().to_string(), "UnitsEnum code \"hr\" is not supported");
+        assert_eq!(PlannedShiftType::from_code("o").unwrap_err(), CodeError { enum_type: "PlanType", code: "o".to_string() });
+        assert!(StandardType::from_code("X").is_err());
+        assert!(PlannedShiftSource::from_code("").is_err());
+    }
+}
@@ -7,6 +7,7 @@ macro_rules! id_type {
         pub struct $name(uuid::Uuid);
 
         impl $name {
+            #[allow(clippy::new_without_default)]
             pub fn new() -> Self {
                 Self(uuid::Uuid::new_v4())
             }
@@ -31,6 +32,7 @@ macro_rules! id_type {
         pub struct $name(uuid::Uuid);
 
         impl $name {
+            #[allow(clippy::new_without_default)]
             pub fn new() -> Self {
                 Self(uuid::Uuid::now_v7())
             }
@@ -1,2 +1,4 @@
+pub mod db_code;
 pub(crate) mod id_type;
 pub(crate) mod numbers;
+pub(crate) mod date_times;
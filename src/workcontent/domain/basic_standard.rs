@@ -0,0 +1,14 @@
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::domain::job_shift::JobShiftId;
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::domain::units::Units;
+
+pub struct BasicStandard {
+    pub job_id: JobId,
+    pub standard_set_id: StandardSetId,
+    pub shift_id: JobShiftId,
+    pub business_driver_id: Option<BusinessDriverId>,
+    pub units: Units,
+    pub standard_value: f64,
+}
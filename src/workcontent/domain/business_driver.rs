@@ -10,16 +10,23 @@ id_type!(BusinessDriverId, uuid_v4);
 pub struct BusinessDriver {
     id: BusinessDriverId,
     location_id: LocationId,
+    name: String,
 }
 
 impl BusinessDriver {
     pub fn new(id: BusinessDriverId, location_id: LocationId) -> Self {
         Self {
             id,
-            location_id
+            location_id,
+            name: String::new(),
         }
     }
 
+    pub fn with_name(mut self, name: String) -> Self {
+        self.name = name;
+        self
+    }
+
     pub fn id(&self) -> BusinessDriverId {
         self.id
     }
@@ -27,6 +34,10 @@ impl BusinessDriver {
     pub fn location_id(&self) -> LocationId {
         self.location_id
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 #[cfg(test)]
@@ -42,5 +53,7 @@ mod tests {
 
         assert_eq!(bd.id(), id);
         assert_eq!(bd.location_id(), location_id);
+        assert_eq!(bd.name(), "");
+        assert_eq!(bd.with_name("Arrivals".to_string()).name(), "Arrivals");
     }
 }
@@ -0,0 +1,159 @@
+use joda_rs::LocalDate;
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::flow_plan::FlowPattern;
+
+const MINUTES_PER_DAY: u32 = 1440;
+
+/// The values of a business driver for each period of a day. Periods are `period_length` minutes
+/// long and numbered from 1 for the period beginning at midnight, like flow pattern periods.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessDriverPeriodValues {
+    business_driver_id: BusinessDriverId,
+    date: LocalDate,
+    period_length: u32,
+    values: Vec<f64>,
+}
+
+impl BusinessDriverPeriodValues {
+    /// Values beyond the periods of the day are dropped and missing periods are 0.
+    pub fn new(business_driver_id: BusinessDriverId, date: LocalDate, period_length: u32, mut values: Vec<f64>) -> Self {
+        values.resize(number_of_periods(period_length), 0.0);
+        Self {
+            business_driver_id,
+            date,
+            period_length,
+            values,
+        }
+    }
+
+    /// Spreads the daily value over the periods in proportion to the flow pattern. A pattern
+    /// without any volume spreads the value evenly.
+    pub fn from_daily_value(business_driver_id: BusinessDriverId,
+                            date: LocalDate,
+                            period_length: u32,
+                            daily_value: f64,
+                            flow_pattern: &FlowPattern) -> Self {
+        let number_of_periods = number_of_periods(period_length);
+        let weights: Vec<f64> = (1..=number_of_periods as u32)
+            .map(|period_no| flow_pattern.pattern_value(period_no).max(0.0))
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+
+        let values = if total_weight > 0.0 {
+            weights.iter().map(|weight| daily_value * weight / total_weight).collect()
+        } else {
+            vec![daily_value / number_of_periods as f64; number_of_periods]
+        };
+
+        Self::new(business_driver_id, date, period_length, values)
+    }
+
+    pub fn business_driver_id(&self) -> BusinessDriverId {
+        self.business_driver_id
+    }
+    pub fn date(&self) -> LocalDate {
+        self.date
+    }
+    pub fn period_length(&self) -> u32 {
+        self.period_length
+    }
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn period_value(&self, period_no: u32) -> f64 {
+        if period_no == 0 {
+            return 0.0;
+        }
+        self.values.get(period_no as usize - 1).copied().unwrap_or(0.0)
+    }
+
+    /// The value for the whole day.
+    pub fn daily_value(&self) -> f64 {
+        self.values.iter().sum()
+    }
+
+    /// The series at a longer period length, adding up the periods that fall into each longer
+    /// period. Only multiples of the current period length can be aggregated to.
+    pub fn aggregate(&self, period_length: u32) -> Option<Self> {
+        if self.period_length == 0 || period_length == 0 || !period_length.is_multiple_of(self.period_length) {
+            return None;
+        }
+
+        let periods_per_period = (period_length / self.period_length) as usize;
+        let values = self.values
+            .chunks(periods_per_period)
+            .map(|periods| periods.iter().sum())
+            .collect();
+
+        Some(Self::new(self.business_driver_id, self.date, period_length, values))
+    }
+}
+
+fn number_of_periods(period_length: u32) -> usize {
+    if period_length == 0 {
+        return 0;
+    }
+    MINUTES_PER_DAY.div_ceil(period_length) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::flow_plan::{FlowPatternId, FlowPatternPeriod};
+
+    fn date() -> LocalDate {
+        LocalDate::new(2025, 1, 6)
+    }
+
+    #[test]
+    fn new_aligns_values_to_the_periods_of_the_day() {
+        let values = BusinessDriverPeriodValues::new(BusinessDriverId::new(), date(), 60, vec![1.0, 2.0]);
+
+        assert_eq!(values.values().len(), 24);
+        assert_eq!(values.period_value(1), 1.0);
+        assert_eq!(values.period_value(2), 2.0);
+        assert_eq!(values.period_value(3), 0.0);
+        assert_eq!(values.period_value(0), 0.0);
+        assert_eq!(values.period_value(25), 0.0);
+        assert_eq!(values.daily_value(), 3.0);
+    }
+
+    #[test]
+    fn daily_value_is_disaggregated_through_the_flow_pattern() {
+        let flow_pattern = FlowPattern::new(FlowPatternId::new(), "Breakfast".to_string(), vec![
+            FlowPatternPeriod::new(15, 25.0),
+            FlowPatternPeriod::new(16, 75.0),
+        ]);
+
+        let values = BusinessDriverPeriodValues::from_daily_value(BusinessDriverId::new(), date(), 30, 200.0, &flow_pattern);
+
+        assert_eq!(values.values().len(), 48);
+        assert_eq!(values.period_value(15), 50.0);
+        assert_eq!(values.period_value(16), 150.0);
+        assert_eq!(values.daily_value(), 200.0);
+    }
+
+    #[test]
+    fn empty_flow_pattern_disaggregates_evenly() {
+        let flow_pattern = FlowPattern::new(FlowPatternId::new(), "Empty".to_string(), vec![]);
+
+        let values = BusinessDriverPeriodValues::from_daily_value(BusinessDriverId::new(), date(), 60, 48.0, &flow_pattern);
+
+        assert!(values.values().iter().all(|value| *value == 2.0));
+    }
+
+    #[test]
+    fn periods_aggregate_to_longer_periods() {
+        let values = BusinessDriverPeriodValues::new(BusinessDriverId::new(), date(), 15, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let hourly = values.aggregate(60).unwrap();
+
+        assert_eq!(hourly.period_length(), 60);
+        assert_eq!(hourly.values().len(), 24);
+        assert_eq!(hourly.period_value(1), 10.0);
+        assert_eq!(hourly.period_value(2), 5.0);
+        assert_eq!(hourly.daily_value(), values.daily_value());
+        assert!(values.aggregate(20).is_none());
+    }
+}
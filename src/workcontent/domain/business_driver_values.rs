@@ -1,10 +1,49 @@
 use crate::workcontent::domain::business_driver::BusinessDriverId;
-use crate::workcontent::domain::location::LocationId;
 use std::collections::HashMap;
 use joda_rs::LocalDate;
 
+/// The daily values (forecast volumes) of a business driver.
+#[derive(Debug, Clone, PartialEq)]
 pub struct BusinessDriverValues {
     business_driver_id: BusinessDriverId,
     values: HashMap<LocalDate, u32>,
 }
-impl BusinessDriverValues {}
+
+impl BusinessDriverValues {
+    pub fn new(business_driver_id: BusinessDriverId, values: HashMap<LocalDate, u32>) -> Self {
+        Self {
+            business_driver_id,
+            values,
+        }
+    }
+
+    pub fn business_driver_id(&self) -> BusinessDriverId {
+        self.business_driver_id
+    }
+
+    pub fn values(&self) -> &HashMap<LocalDate, u32> {
+        &self.values
+    }
+
+    pub fn value(&self, date: LocalDate) -> Option<u32> {
+        self.values.get(&date).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_is_looked_up_by_date() {
+        let business_driver_id = BusinessDriverId::new();
+        let values = BusinessDriverValues::new(business_driver_id, HashMap::from([
+            (LocalDate::new(2025, 1, 6), 120),
+            (LocalDate::new(2025, 1, 7), 80),
+        ]));
+
+        assert_eq!(values.business_driver_id(), business_driver_id);
+        assert_eq!(values.value(LocalDate::new(2025, 1, 7)), Some(80));
+        assert_eq!(values.value(LocalDate::new(2025, 1, 8)), None);
+    }
+}
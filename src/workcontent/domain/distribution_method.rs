@@ -0,0 +1,34 @@
+use crate::workcontent::common::db_code::DbCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistributionMethod {
+    Flowed,
+    FillGaps,
+    NonFlowed,
+    Opening,
+    Closing,
+    ShareWith
+}
+
+impl DbCode for DistributionMethod {
+    const ENUM_TYPE: &'static str = "DistributionMethodEnum";
+    const VALUES: &'static [Self] = &[
+        DistributionMethod::Flowed,
+        DistributionMethod::FillGaps,
+        DistributionMethod::NonFlowed,
+        DistributionMethod::Opening,
+        DistributionMethod::Closing,
+        DistributionMethod::ShareWith,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            DistributionMethod::Flowed => "F",
+            DistributionMethod::FillGaps => "G",
+            DistributionMethod::NonFlowed => "N",
+            DistributionMethod::Opening => "O",
+            DistributionMethod::Closing => "C",
+            DistributionMethod::ShareWith => "H",
+        }
+    }
+}
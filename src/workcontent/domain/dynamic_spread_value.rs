@@ -0,0 +1,45 @@
+use joda_rs::LocalDate;
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::value_type::ValueType;
+
+/// A historical (or forecast) business driver value for one period of a day, used by dynamic
+/// spread standards to shape the staffing across the day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamicSpreadValue {
+    value_type: ValueType,
+    business_driver_id: BusinessDriverId,
+    stat_date: LocalDate,
+    period_no: u32,
+    value: u32,
+}
+
+impl DynamicSpreadValue {
+    pub fn new(value_type: ValueType,
+               business_driver_id: BusinessDriverId,
+               stat_date: LocalDate,
+               period_no: u32,
+               value: u32) -> Self {
+        Self {
+            value_type,
+            business_driver_id,
+            stat_date,
+            period_no,
+            value,
+        }
+    }
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+    pub fn business_driver_id(&self) -> BusinessDriverId {
+        self.business_driver_id
+    }
+    pub fn stat_date(&self) -> LocalDate {
+        self.stat_date
+    }
+    pub fn period_no(&self) -> u32 {
+        self.period_no
+    }
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
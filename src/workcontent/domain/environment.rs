@@ -1,10 +1,10 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Environment {
     id: i32,
 }
 
 impl Environment {
-    pub fn new(id: i32) -> Self {
+    pub const fn new(id: i32) -> Self {
         Self { id }
     }
     pub fn id(&self) -> i32 {
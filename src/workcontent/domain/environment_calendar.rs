@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use joda_rs::{DayOfWeek, LocalDate};
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::environment::Environment;
+
+/// An environment of the location, e.g. "High Season", or the system environment of a day of the
+/// week. An environment for a day of week only applies on that day unless it ignores the day of
+/// week.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvironmentDefinition {
+    environment: Environment,
+    name: String,
+    day_of_week: Option<DayOfWeek>,
+    ignore_day_of_week: bool,
+    system_environment: bool,
+}
+
+impl EnvironmentDefinition {
+    pub fn new(environment: Environment, name: String) -> Self {
+        Self {
+            environment,
+            name,
+            day_of_week: None,
+            ignore_day_of_week: false,
+            system_environment: false,
+        }
+    }
+
+    pub fn with_day_of_week(mut self, day_of_week: DayOfWeek) -> Self {
+        self.day_of_week = Some(day_of_week);
+        self
+    }
+
+    pub fn with_ignore_day_of_week(mut self, ignore_day_of_week: bool) -> Self {
+        self.ignore_day_of_week = ignore_day_of_week;
+        self
+    }
+
+    pub fn with_system_environment(mut self, system_environment: bool) -> Self {
+        self.system_environment = system_environment;
+        self
+    }
+
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn day_of_week(&self) -> Option<DayOfWeek> {
+        self.day_of_week
+    }
+
+    pub fn ignore_day_of_week(&self) -> bool {
+        self.ignore_day_of_week
+    }
+
+    pub fn system_environment(&self) -> bool {
+        self.system_environment
+    }
+
+    pub fn applies_on(&self, date: LocalDate) -> bool {
+        self.ignore_day_of_week || self.day_of_week.is_none_or(|day_of_week| day_of_week == date.day_of_week())
+    }
+}
+
+/// Whether planning uses the forecast or the actual environment of a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnvironmentSource {
+    #[default]
+    Forecast,
+    Actual,
+}
+
+/// The forecast and actual environments of a date for a business driver, or for the whole
+/// location when no driver is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvironmentStat {
+    date: LocalDate,
+    business_driver_id: Option<BusinessDriverId>,
+    forecast: Option<Environment>,
+    actual: Option<Environment>,
+}
+
+impl EnvironmentStat {
+    pub fn new(date: LocalDate,
+               business_driver_id: Option<BusinessDriverId>,
+               forecast: Option<Environment>,
+               actual: Option<Environment>) -> Self {
+        Self {
+            date,
+            business_driver_id,
+            forecast,
+            actual,
+        }
+    }
+
+    pub fn date(&self) -> LocalDate {
+        self.date
+    }
+
+    pub fn business_driver_id(&self) -> Option<BusinessDriverId> {
+        self.business_driver_id
+    }
+
+    pub fn environment(&self, source: EnvironmentSource) -> Option<Environment> {
+        match source {
+            EnvironmentSource::Forecast => self.forecast,
+            EnvironmentSource::Actual => self.actual,
+        }
+    }
+}
+
+/// Which environment applies on each date. The environment of a business driver's stat for the
+/// date wins over the location's, which wins over the system environment of the day of week.
+/// Without a system environment defined for the day, `Environment::new(1)` is Monday to
+/// `Environment::new(7)` Sunday. An environment for another day of week does not apply.
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentCalendar {
+    environments: Vec<EnvironmentDefinition>,
+    stats: HashMap<(Option<BusinessDriverId>, LocalDate), EnvironmentStat>,
+    source: EnvironmentSource,
+}
+
+impl EnvironmentCalendar {
+    pub fn new(environments: Vec<EnvironmentDefinition>, stats: Vec<EnvironmentStat>) -> Self {
+        Self {
+            environments,
+            stats: stats.into_iter()
+                .map(|stat| ((stat.business_driver_id, stat.date), stat))
+                .collect(),
+            source: EnvironmentSource::default(),
+        }
+    }
+
+    pub fn with_source(mut self, source: EnvironmentSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn environments(&self) -> &[EnvironmentDefinition] {
+        &self.environments
+    }
+
+    pub fn source(&self) -> EnvironmentSource {
+        self.source
+    }
+
+    pub fn environment_definition(&self, environment: Environment) -> Option<&EnvironmentDefinition> {
+        self.environments.iter().find(|definition| definition.environment == environment)
+    }
+
+    /// The environment of the location on the date.
+    pub fn environment_for_date(&self, date: LocalDate) -> Environment {
+        self.environment_for(None, date)
+    }
+
+    /// The environment of the business driver on the date.
+    pub fn environment_for(&self, business_driver_id: Option<BusinessDriverId>, date: LocalDate) -> Environment {
+        business_driver_id
+            .and_then(|business_driver_id| self.stat_environment(Some(business_driver_id), date))
+            .or_else(|| self.stat_environment(None, date))
+            .unwrap_or_else(|| self.system_environment(date.day_of_week()))
+    }
+
+    pub fn system_environment(&self, day_of_week: DayOfWeek) -> Environment {
+        self.environments.iter()
+            .find(|definition| definition.system_environment && definition.day_of_week == Some(day_of_week))
+            .map(|definition| definition.environment)
+            .unwrap_or_else(|| Environment::new(day_of_week.value()))
+    }
+
+    fn stat_environment(&self, business_driver_id: Option<BusinessDriverId>, date: LocalDate) -> Option<Environment> {
+        self.stats.get(&(business_driver_id, date))
+            .and_then(|stat| stat.environment(self.source))
+            .filter(|environment| self.environment_definition(*environment)
+                .is_none_or(|definition| definition.applies_on(date)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HIGH_SEASON: Environment = Environment::new(20);
+    const MONDAY_CONVENTION: Environment = Environment::new(21);
+
+    fn monday() -> LocalDate {
+        LocalDate::new(2025, 1, 6)
+    }
+
+    fn environments() -> Vec<EnvironmentDefinition> {
+        vec![
+            EnvironmentDefinition::new(Environment::new(101), "Monday".to_string())
+                .with_day_of_week(DayOfWeek::Monday)
+                .with_system_environment(true),
+            EnvironmentDefinition::new(HIGH_SEASON, "High Season".to_string())
+                .with_day_of_week(DayOfWeek::Saturday)
+                .with_ignore_day_of_week(true),
+            EnvironmentDefinition::new(MONDAY_CONVENTION, "Monday convention".to_string())
+                .with_day_of_week(DayOfWeek::Monday),
+        ]
+    }
+
+    #[test]
+    fn falls_back_to_the_system_environment_of_the_day_of_week() {
+        let calendar = EnvironmentCalendar::new(environments(), vec![]);
+
+        assert_eq!(calendar.environment_for_date(monday()), Environment::new(101));
+        assert_eq!(calendar.environment_for_date(monday().plus_days(1)), Environment::new(2));
+        assert_eq!(EnvironmentCalendar::default().environment_for_date(monday()), Environment::new(1));
+    }
+
+    #[test]
+    fn source_chooses_the_forecast_or_actual_environment() {
+        let stats = vec![EnvironmentStat::new(monday(), None, Some(HIGH_SEASON), Some(MONDAY_CONVENTION))];
+        let calendar = EnvironmentCalendar::new(environments(), stats);
+
+        assert_eq!(calendar.environment_for_date(monday()), HIGH_SEASON);
+        assert_eq!(calendar.with_source(EnvironmentSource::Actual).environment_for_date(monday()), MONDAY_CONVENTION);
+    }
+
+    #[test]
+    fn business_driver_environment_wins_over_the_location_environment() {
+        let rooms_id = BusinessDriverId::new();
+        let stats = vec![
+            EnvironmentStat::new(monday(), None, Some(HIGH_SEASON), None),
+            EnvironmentStat::new(monday(), Some(rooms_id), Some(MONDAY_CONVENTION), None),
+        ];
+        let calendar = EnvironmentCalendar::new(environments(), stats);
+
+        assert_eq!(calendar.environment_for(Some(rooms_id), monday()), MONDAY_CONVENTION);
+        assert_eq!(calendar.environment_for(Some(BusinessDriverId::new()), monday()), HIGH_SEASON);
+        assert_eq!(calendar.environment_for(Some(rooms_id), monday().plus_days(1)), Environment::new(2));
+    }
+
+    #[test]
+    fn environment_for_another_day_of_week_does_not_apply() {
+        let tuesday = monday().plus_days(1);
+        let stats = vec![
+            EnvironmentStat::new(tuesday, None, Some(MONDAY_CONVENTION), None),
+            EnvironmentStat::new(tuesday.plus_days(1), None, Some(HIGH_SEASON), None),
+        ];
+        let calendar = EnvironmentCalendar::new(environments(), stats);
+
+        assert_eq!(calendar.environment_for_date(tuesday), Environment::new(2));
+        assert_eq!(calendar.environment_for_date(tuesday.plus_days(1)), HIGH_SEASON);
+    }
+}
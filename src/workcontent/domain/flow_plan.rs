@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use joda_rs::DayOfWeek;
+use crate::id_type;
+use crate::workcontent::domain::environment::Environment;
+
+id_type!(FlowPlanId, uuid_v4);
+id_type!(FlowPatternId, uuid_v4);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowPatternPeriod {
+    period_no: u32,
+    pattern_value: f64,
+}
+
+impl FlowPatternPeriod {
+    pub fn new(period_no: u32, pattern_value: f64) -> Self {
+        Self {
+            period_no,
+            pattern_value,
+        }
+    }
+    pub fn period_no(&self) -> u32 {
+        self.period_no
+    }
+    pub fn pattern_value(&self) -> f64 {
+        self.pattern_value
+    }
+}
+
+/// The relative volume for each period of a day. Period numbers start at 1 for the period
+/// beginning at midnight.
+#[derive(Debug, Clone)]
+pub struct FlowPattern {
+    id: FlowPatternId,
+    name: String,
+    periods: Vec<FlowPatternPeriod>,
+}
+
+impl FlowPattern {
+    pub fn new(id: FlowPatternId, name: String, periods: Vec<FlowPatternPeriod>) -> Self {
+        Self {
+            id,
+            name,
+            periods,
+        }
+    }
+    pub fn id(&self) -> FlowPatternId {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn periods(&self) -> &[FlowPatternPeriod] {
+        &self.periods
+    }
+    pub fn pattern_value(&self, period_no: u32) -> f64 {
+        self.periods.iter()
+            .find(|period| period.period_no == period_no)
+            .map(|period| period.pattern_value)
+            .unwrap_or(0.0)
+    }
+    pub fn total_percent(&self) -> f64 {
+        self.periods.iter().map(|period| period.pattern_value).sum()
+    }
+}
+
+/// A flow plan selects the flow pattern to use for a date: a pattern configured for the
+/// environment of the date wins over the pattern configured for the day of week.
+#[derive(Debug, Clone)]
+pub struct FlowPlan {
+    id: FlowPlanId,
+    name: String,
+    day_of_week_patterns: HashMap<DayOfWeek, FlowPattern>,
+    environment_patterns: Vec<(Environment, FlowPattern)>,
+}
+
+impl FlowPlan {
+    pub fn new(id: FlowPlanId, name: String) -> Self {
+        Self {
+            id,
+            name,
+            day_of_week_patterns: HashMap::new(),
+            environment_patterns: Vec::new(),
+        }
+    }
+    pub fn with_day_of_week_patterns(mut self, day_of_week_patterns: HashMap<DayOfWeek, FlowPattern>) -> Self {
+        self.day_of_week_patterns = day_of_week_patterns;
+        self
+    }
+    pub fn with_environment_patterns(mut self, environment_patterns: Vec<(Environment, FlowPattern)>) -> Self {
+        self.environment_patterns = environment_patterns;
+        self
+    }
+    pub fn id(&self) -> FlowPlanId {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn pattern_for(&self, environment: Option<Environment>, day_of_week: DayOfWeek) -> Option<&FlowPattern> {
+        environment
+            .and_then(|environment| self.environment_patterns.iter()
+                .find(|(env, _)| env.id() == environment.id())
+                .map(|(_, pattern)| pattern))
+            .or_else(|| self.day_of_week_patterns.get(&day_of_week))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(name: &str) -> FlowPattern {
+        FlowPattern::new(FlowPatternId::new(), name.to_string(), vec![
+            FlowPatternPeriod::new(1, 25.0),
+            FlowPatternPeriod::new(2, 75.0),
+        ])
+    }
+
+    #[test]
+    fn pattern_value_defaults_to_zero_for_missing_periods() {
+        let pattern = pattern("p");
+        assert_eq!(pattern.pattern_value(2), 75.0);
+        assert_eq!(pattern.pattern_value(3), 0.0);
+        assert_eq!(pattern.total_percent(), 100.0);
+    }
+
+    #[test]
+    fn environment_pattern_wins_over_day_of_week_pattern() {
+        let plan = FlowPlan::new(FlowPlanId::new(), "plan".to_string())
+            .with_day_of_week_patterns(HashMap::from([(DayOfWeek::Monday, pattern("monday"))]))
+            .with_environment_patterns(vec![(Environment::new(10), pattern("convention"))]);
+
+        assert_eq!(plan.pattern_for(Some(Environment::new(10)), DayOfWeek::Monday).unwrap().name(), "convention");
+        assert_eq!(plan.pattern_for(Some(Environment::new(11)), DayOfWeek::Monday).unwrap().name(), "monday");
+        assert_eq!(plan.pattern_for(None, DayOfWeek::Monday).unwrap().name(), "monday");
+        assert!(plan.pattern_for(None, DayOfWeek::Tuesday).is_none());
+    }
+}
@@ -1,9 +1,13 @@
 use uuid::Uuid;
 use crate::id_type;
+use crate::workcontent::domain::basic_standard::BasicStandard;
 use crate::workcontent::domain::job_shift::JobShift;
 use crate::workcontent::domain::planner_settings::PlannerSettings;
 use crate::workcontent::domain::location::LocationId;
+use crate::workcontent::domain::recurring_task_standard::RecurringTaskStandard;
 use crate::workcontent::domain::salaried_standard::SalariedStandard;
+use crate::workcontent::domain::shift_related_standard::ShiftRelatedStandard;
+use crate::workcontent::domain::spread_standard::SpreadStandard;
 use crate::workcontent::domain::standard_set::{StandardSet, StandardSetId};
 
 id_type!(JobId, uuid_v4);
@@ -14,6 +18,10 @@ pub struct Job {
     planner_settings: PlannerSettings,
     shifts: Vec<JobShift>,
     salaried_standards: Vec<SalariedStandard>,
+    basic_standards: Vec<BasicStandard>,
+    shift_related_standards: Vec<ShiftRelatedStandard>,
+    spread_standards: Vec<SpreadStandard>,
+    recurring_task_standards: Vec<RecurringTaskStandard>,
 }
 
 impl Job {
@@ -26,14 +34,47 @@ impl Job {
             property_id,
             planner_settings,
             shifts,
-            salaried_standards
+            salaried_standards,
+            basic_standards: Vec::new(),
+            shift_related_standards: Vec::new(),
+            spread_standards: Vec::new(),
+            recurring_task_standards: Vec::new(),
         }
     }
 
+    pub fn with_id(mut self, id: JobId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_basic_standards(mut self, basic_standards: Vec<BasicStandard>) -> Self {
+        self.basic_standards = basic_standards;
+        self
+    }
+
+    pub fn with_shift_related_standards(mut self, shift_related_standards: Vec<ShiftRelatedStandard>) -> Self {
+        self.shift_related_standards = shift_related_standards;
+        self
+    }
+
+    pub fn with_spread_standards(mut self, spread_standards: Vec<SpreadStandard>) -> Self {
+        self.spread_standards = spread_standards;
+        self
+    }
+
+    pub fn with_recurring_task_standards(mut self, recurring_task_standards: Vec<RecurringTaskStandard>) -> Self {
+        self.recurring_task_standards = recurring_task_standards;
+        self
+    }
+
     pub fn id(&self) -> JobId {
         self.id
     }
 
+    pub fn property_id(&self) -> LocationId {
+        self.property_id
+    }
+
     pub fn planner_settings(&self) -> &PlannerSettings {
         &self.planner_settings
     }
@@ -59,6 +100,52 @@ impl Job {
                     standard.shift.id() == shift.id())
     }
 
+    pub fn basic_standards(&self) -> &[BasicStandard] {
+        &self.basic_standards
+    }
+
+    pub fn basic_standards_for_standard_set_and_shift(&self, standard_set_id: StandardSetId, shift: &JobShift) -> Vec<&BasicStandard> {
+        self.basic_standards.iter()
+            .filter(|standard|
+                standard.standard_set_id == standard_set_id &&
+                    standard.shift_id == *shift.id())
+            .collect()
+    }
+
+    pub fn shift_related_standards(&self) -> &[ShiftRelatedStandard] {
+        &self.shift_related_standards
+    }
+
+    pub fn shift_related_standards_for_standard_set_and_shift(&self, standard_set_id: StandardSetId, shift: &JobShift) -> Vec<&ShiftRelatedStandard> {
+        self.shift_related_standards.iter()
+            .filter(|standard|
+                standard.standard_set_id == standard_set_id &&
+                    standard.shift_id == *shift.id())
+            .collect()
+    }
+
+    pub fn spread_standards(&self) -> &[SpreadStandard] {
+        &self.spread_standards
+    }
+
+    pub fn spread_standards_for_standard_set_and_shift(&self, standard_set_id: StandardSetId, shift: &JobShift) -> Vec<&SpreadStandard> {
+        self.spread_standards.iter()
+            .filter(|standard|
+                standard.standard_set_id == standard_set_id &&
+                    standard.shift_id == *shift.id())
+            .collect()
+    }
+
+    pub fn recurring_task_standards(&self) -> &[RecurringTaskStandard] {
+        &self.recurring_task_standards
+    }
+
+    pub fn recurring_task_standards_for_standard_set(&self, standard_set_id: StandardSetId) -> Vec<&RecurringTaskStandard> {
+        self.recurring_task_standards.iter()
+            .filter(|standard| standard.standard_set_id == standard_set_id)
+            .collect()
+    }
+
     #[cfg(test)]
     pub fn test() -> Self {
         Self {
@@ -67,6 +154,10 @@ impl Job {
             planner_settings: PlannerSettings::default(),
             shifts: Vec::new(),
             salaried_standards: Vec::new(),
+            basic_standards: Vec::new(),
+            shift_related_standards: Vec::new(),
+            spread_standards: Vec::new(),
+            recurring_task_standards: Vec::new(),
         }
     }
 }
@@ -1,4 +1,6 @@
-use joda_rs::{DayOfWeek, LocalDate};
+use joda_rs::{DayOfWeek, LocalDate, LocalDateTime, LocalTime};
+use crate::workcontent::common::date_times::{minus_hours, plus_hours};
+use crate::workcontent::domain::environment::Environment;
 use uuid::Uuid;
 use crate::id_type;
 use crate::workcontent::domain::job::{Job, JobId};
@@ -8,6 +10,7 @@ use crate::workcontent::domain::standard_set::{StandardSet, StandardSetId};
 
 id_type!(JobShiftId, uuid_v4);
 
+#[derive(Debug, Clone)]
 pub struct JobShift {
     id: JobShiftId,
     job_id: JobId,
@@ -33,16 +36,43 @@ impl JobShift {
         }
     }
 
+    pub fn with_id(mut self, id: JobShiftId) -> Self {
+        self.id = id;
+        self
+    }
+
     pub fn id(&self) -> &JobShiftId {
         &self.id
     }
 
+    pub fn job_id(&self) -> JobId {
+        self.job_id
+    }
+
     pub fn standard_set_id(&self) -> &StandardSetId {
         &self.standard_set_id
     }
 
-    pub fn shift_detail_for_date(&self, date: LocalDate) -> Option<&JobShiftDefinition> {
-        self.shift_definitions.iter().find(|shift_definition| shift_definition.day_of_week == date.day_of_week())
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    pub fn shift_definitions(&self) -> &[JobShiftDefinition] {
+        &self.shift_definitions
+    }
+
+    /// The definition for a date: one configured for the environment of the date wins over the one
+    /// configured for its day of week.
+    pub fn shift_detail_for_date(&self, environment: Option<Environment>, date: LocalDate) -> Option<&JobShiftDefinition> {
+        environment
+            .and_then(|environment| self.shift_definitions.iter()
+                .find(|shift_definition| shift_definition.environment() == Some(environment)))
+            .or_else(|| self.shift_definitions.iter()
+                .find(|shift_definition| shift_definition.day_of_week() == Some(date.day_of_week())))
     }
 
     #[cfg(test)]
@@ -58,15 +88,212 @@ impl JobShift {
     }
 }
 
+/// When a shift definition applies: on a day of the week, or on every date of an environment
+/// such as "High Season" whatever its day of week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShiftDefinitionScope {
+    DayOfWeek(DayOfWeek),
+    Environment(Environment),
+}
+
+/// The times of a shift on a day of the week or in an environment. A shift ending at or before
+/// its start time ends the next day, e.g. 22:00–06:30.
+#[derive(Debug, Clone)]
 pub struct JobShiftDefinition {
-    job_shift: JobShift,
-    day_of_week: DayOfWeek,
-    start_time: LocalDate,
-    end_time: LocalDate,
+    scope: ShiftDefinitionScope,
+    start_time: LocalTime,
+    end_time: LocalTime,
     hours_before: f64,
     hours_after: f64,
     min_number_shifts: u32,
+    shift_grouping_for_same_event: u32,
+    weighting_factor: u32,
 }
 
 impl JobShiftDefinition {
+    pub fn new(day_of_week: DayOfWeek,
+               start_time: LocalTime,
+               end_time: LocalTime,
+               hours_before: f64,
+               hours_after: f64,
+               min_number_shifts: u32) -> Self {
+        Self::for_scope(ShiftDefinitionScope::DayOfWeek(day_of_week), start_time, end_time, hours_before, hours_after, min_number_shifts)
+    }
+
+    pub fn for_environment(environment: Environment,
+                           start_time: LocalTime,
+                           end_time: LocalTime,
+                           hours_before: f64,
+                           hours_after: f64,
+                           min_number_shifts: u32) -> Self {
+        Self::for_scope(ShiftDefinitionScope::Environment(environment), start_time, end_time, hours_before, hours_after, min_number_shifts)
+    }
+
+    fn for_scope(scope: ShiftDefinitionScope,
+                 start_time: LocalTime,
+                 end_time: LocalTime,
+                 hours_before: f64,
+                 hours_after: f64,
+                 min_number_shifts: u32) -> Self {
+        Self {
+            scope,
+            start_time,
+            end_time,
+            hours_before,
+            hours_after,
+            min_number_shifts,
+            shift_grouping_for_same_event: 0,
+            weighting_factor: 0,
+        }
+    }
+
+    pub fn with_shift_grouping_for_same_event(mut self, shift_grouping_for_same_event: u32) -> Self {
+        self.shift_grouping_for_same_event = shift_grouping_for_same_event;
+        self
+    }
+
+    pub fn with_weighting_factor(mut self, weighting_factor: u32) -> Self {
+        self.weighting_factor = weighting_factor;
+        self
+    }
+
+    pub fn scope(&self) -> ShiftDefinitionScope {
+        self.scope
+    }
+
+    pub fn day_of_week(&self) -> Option<DayOfWeek> {
+        match self.scope {
+            ShiftDefinitionScope::DayOfWeek(day_of_week) => Some(day_of_week),
+            ShiftDefinitionScope::Environment(_) => None,
+        }
+    }
+
+    pub fn environment(&self) -> Option<Environment> {
+        match self.scope {
+            ShiftDefinitionScope::DayOfWeek(_) => None,
+            ShiftDefinitionScope::Environment(environment) => Some(environment),
+        }
+    }
+
+    pub fn start_time(&self) -> LocalTime {
+        self.start_time
+    }
+
+    pub fn end_time(&self) -> LocalTime {
+        self.end_time
+    }
+
+    pub fn hours_before(&self) -> f64 {
+        self.hours_before
+    }
+
+    pub fn hours_after(&self) -> f64 {
+        self.hours_after
+    }
+
+    pub fn min_number_shifts(&self) -> u32 {
+        self.min_number_shifts
+    }
+
+    pub fn shift_grouping_for_same_event(&self) -> u32 {
+        self.shift_grouping_for_same_event
+    }
+
+    pub fn weighting_factor(&self) -> u32 {
+        self.weighting_factor
+    }
+
+    pub fn is_overnight(&self) -> bool {
+        !self.start_time.is_before(self.end_time)
+    }
+
+    /// Start of the shift on the date.
+    pub fn start_for(&self, date: LocalDate) -> LocalDateTime {
+        date.at_time(self.start_time)
+    }
+
+    /// End of the shift starting on the date, the next day for overnight shifts.
+    pub fn end_for(&self, date: LocalDate) -> LocalDateTime {
+        let end_date = if self.is_overnight() { date.plus_days(1) } else { date };
+        end_date.at_time(self.end_time)
+    }
+
+    /// Earliest start of work for the shift on the date, `hours_before` its start.
+    pub fn earliest_start_for(&self, date: LocalDate) -> LocalDateTime {
+        minus_hours(self.start_for(date), self.hours_before)
+    }
+
+    /// Latest end of work for the shift on the date, `hours_after` its end.
+    pub fn latest_end_for(&self, date: LocalDate) -> LocalDateTime {
+        plus_hours(self.end_for(date), self.hours_after)
+    }
+
+    /// Length of the shift in hours.
+    pub fn shift_length(&self) -> f64 {
+        let any_date = LocalDate::new(2000, 1, 1);
+        (self.end_for(any_date) - self.start_for(any_date)).fractional_hours()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn definition(start_time: LocalTime, end_time: LocalTime) -> JobShiftDefinition {
+        JobShiftDefinition::new(DayOfWeek::Monday, start_time, end_time, 1.0, 0.5, 0)
+    }
+
+    #[test]
+    fn day_shift_starts_and_ends_on_the_date() {
+        let date = LocalDate::new(2025, 1, 6);
+        let shift_definition = definition(LocalTime::new(6, 0, 0), LocalTime::new(14, 30, 0));
+
+        assert!(!shift_definition.is_overnight());
+        assert_eq!(shift_definition.start_for(date), date.at_time(LocalTime::new(6, 0, 0)));
+        assert_eq!(shift_definition.end_for(date), date.at_time(LocalTime::new(14, 30, 0)));
+        assert_eq!(shift_definition.earliest_start_for(date), date.at_time(LocalTime::new(5, 0, 0)));
+        assert_eq!(shift_definition.latest_end_for(date), date.at_time(LocalTime::new(15, 0, 0)));
+        assert_eq!(shift_definition.shift_length(), 8.5);
+    }
+
+    #[test]
+    fn overnight_shift_ends_the_next_day() {
+        let date = LocalDate::new(2024, 12, 31);
+        let shift_definition = definition(LocalTime::new(22, 0, 0), LocalTime::new(6, 30, 0));
+
+        assert!(shift_definition.is_overnight());
+        assert_eq!(shift_definition.end_for(date), LocalDate::new(2025, 1, 1).at_time(LocalTime::new(6, 30, 0)));
+        assert_eq!(shift_definition.latest_end_for(date), LocalDate::new(2025, 1, 1).at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(shift_definition.shift_length(), 8.5);
+    }
+
+    #[test]
+    fn environment_definition_wins_over_day_of_week_definition() {
+        let monday = LocalDate::new(2025, 1, 6);
+        let high_season = Environment::new(20);
+        let shift = JobShift::new(JobId::new(), StandardSetId::new(), "Day".to_string(), 1, vec![
+            JobShiftDefinition::for_environment(high_season, LocalTime::new(6, 0, 0), LocalTime::new(16, 0, 0), 0.0, 0.0, 3),
+            definition(LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0)),
+        ]);
+
+        let start_time = |environment: Option<Environment>, date: LocalDate| {
+            shift.shift_detail_for_date(environment, date).map(JobShiftDefinition::start_time)
+        };
+
+        assert_eq!(start_time(Some(high_season), monday), Some(LocalTime::new(6, 0, 0)));
+        assert_eq!(start_time(Some(high_season), monday.plus_days(1)), Some(LocalTime::new(6, 0, 0)));
+        assert_eq!(start_time(Some(Environment::new(21)), monday), Some(LocalTime::new(7, 0, 0)));
+        assert_eq!(start_time(None, monday), Some(LocalTime::new(7, 0, 0)));
+        assert_eq!(start_time(None, monday.plus_days(1)), None);
+    }
+
+    #[rstest]
+    #[case(LocalTime::new(0, 0, 0), LocalTime::new(0, 0, 0), 24.0)]
+    #[case(LocalTime::new(7, 0, 0), LocalTime::new(7, 0, 0), 24.0)]
+    #[case(LocalTime::new(23, 30, 0), LocalTime::new(0, 0, 0), 0.5)]
+    #[case(LocalTime::new(0, 0, 0), LocalTime::new(23, 45, 0), 23.75)]
+    fn shift_length_spans_midnight(#[case] start_time: LocalTime, #[case] end_time: LocalTime, #[case] expected: f64) {
+        assert_eq!(definition(start_time, end_time).shift_length(), expected);
+    }
 }
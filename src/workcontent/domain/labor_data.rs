@@ -31,6 +31,7 @@ impl LaborData {
 }
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use super::*;
     use rstest::rstest;
@@ -1,5 +1,6 @@
 pub mod job;
 pub mod planner_settings;
+pub mod planner_settings_resolver;
 pub mod planner_model;
 pub mod standard_set;
 pub mod location;
@@ -9,10 +10,13 @@ pub mod non_meal_break;
 pub mod non_flowed_distribution_method;
 pub mod labor_data;
 pub mod salaried_standard;
+pub mod basic_standard;
 pub mod job_shift;
 pub mod salary_mode;
 pub mod planned_shift;
 pub mod work_content;
+pub mod work_content_type;
+pub mod work_content_detail_type;
 pub mod planned_shift_type;
 pub mod planned_shift_source;
 pub mod business_driver;
@@ -20,4 +24,14 @@ pub mod business_driver_values;
 pub mod shift_category;
 pub mod units;
 pub mod environment;
+pub mod environment_calendar;
 pub mod task_standards;
+pub mod work_type;
+pub mod distribution_method;
+pub mod flow_plan;
+pub mod shift_related_standard;
+pub mod value_type;
+pub mod spread_standard;
+pub mod dynamic_spread_value;
+pub mod recurring_task_standard;
+pub mod business_driver_period_values;
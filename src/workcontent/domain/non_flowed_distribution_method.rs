@@ -1,3 +1,5 @@
+use crate::workcontent::common::db_code::DbCode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NonFlowedDistributionMethod {
     BEGINNING,
@@ -6,3 +8,24 @@ pub enum NonFlowedDistributionMethod {
     EVEN,
     VARYING
 }
+
+impl DbCode for NonFlowedDistributionMethod {
+    const ENUM_TYPE: &'static str = "NonFlowedDistributionMethodEnum";
+    const VALUES: &'static [Self] = &[
+        NonFlowedDistributionMethod::BEGINNING,
+        NonFlowedDistributionMethod::MIDDLE,
+        NonFlowedDistributionMethod::END,
+        NonFlowedDistributionMethod::EVEN,
+        NonFlowedDistributionMethod::VARYING,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            NonFlowedDistributionMethod::BEGINNING => "B",
+            NonFlowedDistributionMethod::MIDDLE => "M",
+            NonFlowedDistributionMethod::END => "E",
+            NonFlowedDistributionMethod::EVEN => "V",
+            NonFlowedDistributionMethod::VARYING => "Y",
+        }
+    }
+}
@@ -11,6 +11,7 @@ use crate::workcontent::domain::shift_category::ShiftCategoryId;
 
 id_type!(PlannedShiftId, uuid_v7);
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct PlannedShift {
     id: PlannedShiftId,
     location_id: LocationId,
@@ -24,6 +25,7 @@ pub struct PlannedShift {
     source: PlannedShiftSource,
     shift_category_id: Option<ShiftCategoryId>,
     assignment_id: Option<JobId>,
+    work_content_ids: Vec<WorkContentId>,
 }
 
 impl PlannedShift {
@@ -48,9 +50,41 @@ impl PlannedShift {
             source: PlannedShiftSource::Auto,
             shift_category_id: None,
             assignment_id: None,
+            work_content_ids: Vec::new(),
         }
     }
 
+    pub fn with_id(mut self, id: PlannedShiftId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_source(mut self, source: PlannedShiftSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_assignment_id(mut self, assignment_id: Option<JobId>) -> Self {
+        self.assignment_id = assignment_id;
+        self
+    }
+
+    pub fn with_date_shift_generated_from(mut self, date_shift_generated_from: LocalDate) -> Self {
+        self.date_shift_generated_from = date_shift_generated_from;
+        self
+    }
+
+    pub fn with_shift_category_id(mut self, shift_category_id: Option<ShiftCategoryId>) -> Self {
+        self.shift_category_id = shift_category_id;
+        self
+    }
+
+    /// The work contents the shift was planned to cover.
+    pub fn with_work_content_ids(mut self, work_content_ids: Vec<WorkContentId>) -> Self {
+        self.work_content_ids = work_content_ids;
+        self
+    }
+
     pub fn id(&self) -> PlannedShiftId {
         self.id
     }
@@ -96,6 +130,10 @@ impl PlannedShift {
     pub fn assignment_id(&self) -> Option<JobId> {
         self.assignment_id
     }
+
+    pub fn work_content_ids(&self) -> &[WorkContentId] {
+        &self.work_content_ids
+    }
 }
 
 #[cfg(test)]
@@ -131,5 +169,27 @@ mod tests {
         assert_eq!(planned_shift.source(), PlannedShiftSource::Auto);
         assert_eq!(planned_shift.shift_category_id(), None);
         assert_eq!(planned_shift.assignment_id(), None);
+        assert!(planned_shift.work_content_ids().is_empty());
+    }
+
+    #[test]
+    fn planned_shift_with_generated_from_date_and_category() {
+        let shift_date = LocalDate::new(2025, 10, 7);
+        let generated_from = LocalDate::new(2025, 10, 6);
+        let shift_category_id = ShiftCategoryId::new();
+        let planned_shift = PlannedShift::new(
+            LocationId::new(),
+            JobId::new(),
+            PlannedShiftType::Projected,
+            shift_date,
+            shift_date.at_time(LocalTime::new(0, 0, 0)),
+            shift_date.at_time(LocalTime::new(4, 0, 0)),
+        )
+        .with_date_shift_generated_from(generated_from)
+        .with_shift_category_id(Some(shift_category_id));
+
+        assert_eq!(planned_shift.shift_date(), shift_date);
+        assert_eq!(planned_shift.date_shift_generated_from(), generated_from);
+        assert_eq!(planned_shift.shift_category_id(), Some(shift_category_id));
     }
 }
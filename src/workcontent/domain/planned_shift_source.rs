@@ -1,5 +1,25 @@
+use crate::workcontent::common::db_code::DbCode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlannedShiftSource {
     Auto,
-    Manual
+    Manual,
+    Rule,
+}
+
+impl DbCode for PlannedShiftSource {
+    const ENUM_TYPE: &'static str = "ShiftSourceType";
+    const VALUES: &'static [Self] = &[
+        PlannedShiftSource::Auto,
+        PlannedShiftSource::Manual,
+        PlannedShiftSource::Rule,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            PlannedShiftSource::Auto => "A",
+            PlannedShiftSource::Manual => "M",
+            PlannedShiftSource::Rule => "R",
+        }
+    }
 }
@@ -1,5 +1,29 @@
+use crate::workcontent::common::db_code::DbCode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlannedShiftType {
     Projected,
-    Standard
+    Original,
+    Standard,
+    Generated,
+}
+
+/// Projected shifts are forecast plans.
+impl DbCode for PlannedShiftType {
+    const ENUM_TYPE: &'static str = "PlanType";
+    const VALUES: &'static [Self] = &[
+        PlannedShiftType::Projected,
+        PlannedShiftType::Original,
+        PlannedShiftType::Standard,
+        PlannedShiftType::Generated,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            PlannedShiftType::Projected => "F",
+            PlannedShiftType::Original => "O",
+            PlannedShiftType::Standard => "S",
+            PlannedShiftType::Generated => "G",
+        }
+    }
 }
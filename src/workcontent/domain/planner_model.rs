@@ -1,9 +1,18 @@
 use std::collections::HashMap;
 use date_range_rs::DateRange;
+use joda_rs::LocalDate;
 use crate::workcontent::domain::business_driver::{BusinessDriver, BusinessDriverId};
+use crate::workcontent::domain::business_driver_period_values::BusinessDriverPeriodValues;
+use crate::workcontent::domain::business_driver_values::BusinessDriverValues;
+use crate::workcontent::domain::dynamic_spread_value::DynamicSpreadValue;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::environment_calendar::EnvironmentCalendar;
+use crate::workcontent::domain::flow_plan::{FlowPlan, FlowPlanId};
 use crate::workcontent::domain::job::Job;
 use crate::workcontent::domain::standard_set::{StandardSet, StandardSetId};
 use crate::workcontent::domain::location::{Location, LocationId};
+use crate::workcontent::domain::shift_category::ShiftCategoryId;
+use crate::workcontent::domain::value_type::ValueType;
 
 pub struct PlannerModel {
     dates: DateRange,
@@ -11,7 +20,13 @@ pub struct PlannerModel {
     standard_set_id: StandardSetId,
     jobs: Vec<Job>,
     business_drivers: Vec<BusinessDriver>,
-    business_driver_values: HashMap<BusinessDriverId, u32>,
+    business_driver_values: HashMap<BusinessDriverId, BusinessDriverValues>,
+    default_shift_category_id: Option<ShiftCategoryId>,
+    flow_plans: Vec<FlowPlan>,
+    environment_calendar: EnvironmentCalendar,
+    dynamic_spread_values: Vec<DynamicSpreadValue>,
+    dynamic_spread_history_weeks: u32,
+    business_driver_period_values: Vec<BusinessDriverPeriodValues>,
 }
 
 impl PlannerModel {
@@ -20,17 +35,65 @@ impl PlannerModel {
                standard_set_id: StandardSetId,
                jobs: Vec<Job>,
                business_drivers: Vec<BusinessDriver>,
-               business_driver_values: HashMap<BusinessDriverId, u32>) -> Self {
+               business_driver_values: Vec<BusinessDriverValues>) -> Self {
         Self {
             dates,
             location_id,
             standard_set_id,
             jobs,
             business_drivers,
-            business_driver_values,
+            business_driver_values: business_driver_values.into_iter()
+                .map(|values| (values.business_driver_id(), values))
+                .collect(),
+            default_shift_category_id: None,
+            flow_plans: Vec::new(),
+            environment_calendar: EnvironmentCalendar::default(),
+            dynamic_spread_values: Vec::new(),
+            dynamic_spread_history_weeks: 1,
+            business_driver_period_values: Vec::new(),
         }
     }
 
+    /// Replaces the values of each business driver in the list, e.g. with imported forecasts.
+    pub fn with_business_driver_values(mut self, business_driver_values: Vec<BusinessDriverValues>) -> Self {
+        for values in business_driver_values {
+            self.business_driver_values.insert(values.business_driver_id(), values);
+        }
+        self
+    }
+
+    pub fn with_default_shift_category_id(mut self, default_shift_category_id: ShiftCategoryId) -> Self {
+        self.default_shift_category_id = Some(default_shift_category_id);
+        self
+    }
+
+    pub fn with_flow_plans(mut self, flow_plans: Vec<FlowPlan>) -> Self {
+        self.flow_plans = flow_plans;
+        self
+    }
+
+    pub fn with_environment_calendar(mut self, environment_calendar: EnvironmentCalendar) -> Self {
+        self.environment_calendar = environment_calendar;
+        self
+    }
+
+    pub fn with_dynamic_spread_values(mut self, dynamic_spread_values: Vec<DynamicSpreadValue>) -> Self {
+        self.dynamic_spread_values = dynamic_spread_values;
+        self
+    }
+
+    /// How many weeks before the planned date dynamic spreads take their per-period values from,
+    /// 1 by default. With 0 they use the values of the planned date itself.
+    pub fn with_dynamic_spread_history_weeks(mut self, dynamic_spread_history_weeks: u32) -> Self {
+        self.dynamic_spread_history_weeks = dynamic_spread_history_weeks;
+        self
+    }
+
+    pub fn with_business_driver_period_values(mut self, business_driver_period_values: Vec<BusinessDriverPeriodValues>) -> Self {
+        self.business_driver_period_values = business_driver_period_values;
+        self
+    }
+
     pub fn dates(&self) -> DateRange {
         self.dates
     }
@@ -55,7 +118,134 @@ impl PlannerModel {
         &self.business_drivers
     }
 
-    pub fn business_driver_values(&self) -> &HashMap<BusinessDriverId, u32> {
+    pub fn business_driver_values(&self) -> &HashMap<BusinessDriverId, BusinessDriverValues> {
         &self.business_driver_values
     }
+
+    /// The value of the business driver on the date. A driver without a value for the date (or
+    /// without any values) has no volume that day, so the value falls back to 0.
+    pub fn value_for(&self, business_driver_id: BusinessDriverId, date: LocalDate) -> u32 {
+        self.business_driver_values.get(&business_driver_id)
+            .and_then(|values| values.value(date))
+            .unwrap_or(0)
+    }
+
+    /// Shift category assigned to generated shifts (the location's default shift category).
+    pub fn default_shift_category_id(&self) -> Option<ShiftCategoryId> {
+        self.default_shift_category_id
+    }
+
+    pub fn flow_plans(&self) -> &[FlowPlan] {
+        &self.flow_plans
+    }
+
+    pub fn flow_plan(&self, flow_plan_id: FlowPlanId) -> Option<&FlowPlan> {
+        self.flow_plans.iter().find(|flow_plan| flow_plan.id() == flow_plan_id)
+    }
+
+    pub fn environment_calendar(&self) -> &EnvironmentCalendar {
+        &self.environment_calendar
+    }
+
+    /// The environment of the location on the date, see [`EnvironmentCalendar`].
+    pub fn environment_for_date(&self, date: LocalDate) -> Environment {
+        self.environment_calendar.environment_for_date(date)
+    }
+
+    /// The environment of the business driver on the date, for looking up the values of its
+    /// standards.
+    pub fn environment_for(&self, business_driver_id: BusinessDriverId, date: LocalDate) -> Environment {
+        self.environment_calendar.environment_for(Some(business_driver_id), date)
+    }
+
+    /// The intraday values of the business driver on the date at the period length. Values held
+    /// at a shorter period length are aggregated to it.
+    pub fn period_values_for(&self, business_driver_id: BusinessDriverId, date: LocalDate, period_length: u32) -> Option<BusinessDriverPeriodValues> {
+        self.business_driver_period_values.iter()
+            .filter(|values| values.business_driver_id() == business_driver_id && values.date() == date)
+            .find_map(|values| values.aggregate(period_length))
+    }
+
+    pub fn dynamic_spread_values(&self) -> &[DynamicSpreadValue] {
+        &self.dynamic_spread_values
+    }
+
+    pub fn dynamic_spread_history_weeks(&self) -> u32 {
+        self.dynamic_spread_history_weeks
+    }
+
+    /// The per-period values shaping a dynamic spread of the business driver on the planned date:
+    /// those of the same day of week `dynamic_spread_history_weeks` earlier. Empty when there is no
+    /// history for that day.
+    pub fn dynamic_spread_history_for(&self, business_driver_id: BusinessDriverId, date: LocalDate) -> Vec<&DynamicSpreadValue> {
+        self.dynamic_spread_values_for(business_driver_id, date.minus_weeks(self.dynamic_spread_history_weeks as i64))
+    }
+
+    /// The per-period values of the business driver on the date. Actuals are used when there are
+    /// any for the date, otherwise the forecast values.
+    pub fn dynamic_spread_values_for(&self, business_driver_id: BusinessDriverId, date: LocalDate) -> Vec<&DynamicSpreadValue> {
+        let values_of_type = |value_type: ValueType| -> Vec<&DynamicSpreadValue> {
+            self.dynamic_spread_values.iter()
+                .filter(|value|
+                    value.value_type() == value_type &&
+                        value.business_driver_id() == business_driver_id &&
+                        value.stat_date() == date)
+                .collect()
+        };
+
+        let actuals = values_of_type(ValueType::ACTUALS);
+        if actuals.is_empty() {
+            values_of_type(ValueType::FORECAST)
+        } else {
+            actuals
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_for_uses_the_dated_value_and_falls_back_to_zero() {
+        let business_driver_id = BusinessDriverId::new();
+        let monday = LocalDate::new(2025, 1, 6);
+        let planner_model = PlannerModel::new(
+            DateRange::new(monday, monday.plus_days(6)),
+            LocationId::new(),
+            StandardSetId::new(),
+            vec![],
+            vec![],
+            vec![BusinessDriverValues::new(business_driver_id, HashMap::from([(monday, 120), (monday.plus_days(1), 80)]))],
+        );
+
+        assert_eq!(planner_model.value_for(business_driver_id, monday), 120);
+        assert_eq!(planner_model.value_for(business_driver_id, monday.plus_days(1)), 80);
+        assert_eq!(planner_model.value_for(business_driver_id, monday.plus_days(2)), 0);
+        assert_eq!(planner_model.value_for(BusinessDriverId::new(), monday), 0);
+    }
+
+    #[test]
+    fn period_values_for_aggregates_to_the_period_length() {
+        let business_driver_id = BusinessDriverId::new();
+        let monday = LocalDate::new(2025, 1, 6);
+        let planner_model = PlannerModel::new(
+            DateRange::new(monday, monday),
+            LocationId::new(),
+            StandardSetId::new(),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .with_business_driver_period_values(vec![
+            BusinessDriverPeriodValues::new(business_driver_id, monday, 15, vec![1.0, 2.0, 3.0]),
+        ]);
+
+        let values = planner_model.period_values_for(business_driver_id, monday, 30).unwrap();
+
+        assert_eq!(values.period_value(1), 3.0);
+        assert_eq!(values.period_value(2), 3.0);
+        assert!(planner_model.period_values_for(business_driver_id, monday, 20).is_none());
+        assert!(planner_model.period_values_for(business_driver_id, monday.plus_days(1), 30).is_none());
+    }
 }
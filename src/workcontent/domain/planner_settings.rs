@@ -2,6 +2,7 @@ use crate::workcontent::domain::meal_break::MealBreak;
 use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
 use crate::workcontent::domain::non_meal_break::NonMealBreak;
 use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::domain::planner_settings_resolver::PlannerSettingSources;
 use crate::workcontent::domain::standard_type::StandardType;
 use date_range_rs::DateRange;
 use joda_rs::LocalDate;
@@ -15,14 +16,18 @@ pub struct PlannerSettings {
     pub rounding_threshold_above_one: f64,
     pub meal_break: Option<MealBreak>,
     pub non_meal_break: Option<NonMealBreak>,
-    pub effective_dates: DateRange,
+    /// The ranges of the year the job is open, each recurring every year. See `applies_on`.
+    pub effective_dates: Vec<DateRange>,
     pub generate_long_shifts: bool,
     pub limit_shift_to_max_shift: bool,
     pub truncate_max_coverage: bool,
     pub non_flowed_distribution_method: NonFlowedDistributionMethod,
+    /// The layer each value came from, see `PlannerSettingsResolver`.
+    pub sources: PlannerSettingSources,
 }
 
 impl PlannerSettings {
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> PlannerSettings {
         Self {
             standard_type: StandardType::NONE,
@@ -33,28 +38,125 @@ impl PlannerSettings {
             rounding_threshold_above_one: 1.0,
             meal_break: None,
             non_meal_break: None,
-            effective_dates: DateRange::new(LocalDate::new(2021, 1, 1), LocalDate::new(2021, 12, 31)),
+            effective_dates: vec![DateRange::new(LocalDate::new(2021, 1, 1), LocalDate::new(2021, 12, 31))],
             generate_long_shifts: false,
             limit_shift_to_max_shift: false,
             truncate_max_coverage: false,
             non_flowed_distribution_method: NonFlowedDistributionMethod::VARYING,
+            sources: PlannerSettingSources::default(),
         }
     }
 
+    /// The dates of the planning range the job is open.
     pub fn dates(&self, planner_model: &PlannerModel) -> Vec<LocalDate> {
-        let mut dates: Vec<LocalDate> = Vec::new();
+        planner_model.dates().iter()
+            .filter(|date| self.applies_on(*date))
+            .collect()
+    }
 
-        for date in planner_model.dates().iter() {
-            let eff_dates = DateRange::new(
-                self.effective_dates.start_date().with_year(date.year()),
-                self.effective_dates.end_date().with_year(date.year())
-            );
+    /// Whether any effective range applies on the date. A range recurs every year by month and
+    /// day, whatever its years: Nov 1 to Feb 28 wraps the new year, and a range ending on Feb 29
+    /// ends on Feb 28 in other years. A range of a year or more applies every day.
+    pub fn applies_on(&self, date: LocalDate) -> bool {
+        self.effective_dates.iter().any(|range| recurs_on(range, date))
+    }
+}
 
-            if eff_dates.contains_date(date) {
-                dates.push(date.clone());
-            }
-        }
+fn recurs_on(range: &DateRange, date: LocalDate) -> bool {
+    let (start, end) = (range.start_date(), range.end_date());
+    if end.is_before(start) {
+        return false;
+    }
+    if !end.is_before(start.plus_years(1).minus_days(1)) {
+        return true;
+    }
+
+    let month_day = |date: LocalDate| (date.month_value(), date.day_of_month());
+    let (start, end, date) = (month_day(start), month_day(end), month_day(date));
+    if start <= end {
+        start <= date && date <= end
+    } else {
+        start <= date || date <= end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use super::*;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::standard_set::StandardSetId;
+
+    fn settings(ranges: &[(LocalDate, LocalDate)]) -> PlannerSettings {
+        let mut settings = PlannerSettings::default();
+        settings.effective_dates = ranges.iter()
+            .map(|&(start_date, end_date)| DateRange::new(start_date, end_date))
+            .collect();
+        settings
+    }
+
+    #[rstest]
+    #[case::inside((2025, 1, 15), true)]
+    #[case::last_day_of_the_year((2025, 12, 31), true)]
+    #[case::end((2026, 2, 28), true)]
+    #[case::after_the_end((2026, 3, 1), false)]
+    #[case::before_the_start((2025, 10, 31), false)]
+    #[case::another_year((2030, 11, 1), true)]
+    fn range_can_wrap_the_new_year(#[case] (year, month, day): (i32, i32, i32), #[case] applies: bool) {
+        let settings = settings(&[(LocalDate::new(2024, 11, 1), LocalDate::new(2025, 2, 28))]);
+
+        assert_eq!(settings.applies_on(LocalDate::new(year, month, day)), applies);
+    }
+
+    #[rstest]
+    #[case::leap_day((2024, 2, 29), true)]
+    #[case::day_before_in_another_year((2025, 2, 28), true)]
+    #[case::day_after_in_another_year((2025, 3, 1), false)]
+    fn range_ending_on_a_leap_day_ends_on_the_last_day_of_february(#[case] (year, month, day): (i32, i32, i32), #[case] applies: bool) {
+        let settings = settings(&[(LocalDate::new(2024, 2, 1), LocalDate::new(2024, 2, 29))]);
+
+        assert_eq!(settings.applies_on(LocalDate::new(year, month, day)), applies);
+    }
+
+    #[test]
+    fn any_range_of_the_job_applies() {
+        let winter_and_summer = settings(&[
+            (LocalDate::new(2025, 1, 1), LocalDate::new(2025, 1, 31)),
+            (LocalDate::new(2025, 7, 1), LocalDate::new(2025, 8, 31)),
+        ]);
+
+        assert!(winter_and_summer.applies_on(LocalDate::new(2026, 1, 31)));
+        assert!(winter_and_summer.applies_on(LocalDate::new(2026, 7, 1)));
+        assert!(!winter_and_summer.applies_on(LocalDate::new(2026, 3, 1)));
+        assert!(!settings(&[]).applies_on(LocalDate::new(2026, 3, 1)));
+    }
+
+    #[test]
+    fn range_of_a_year_or_more_applies_every_day() {
+        let from_march = settings(&[(LocalDate::new(2024, 3, 1), LocalDate::new(2025, 2, 28))]);
+        let several_years = settings(&[(LocalDate::new(2023, 6, 1), LocalDate::new(2025, 5, 31))]);
+
+        assert!(from_march.applies_on(LocalDate::new(2028, 2, 29)));
+        assert!(several_years.applies_on(LocalDate::new(2030, 1, 1)));
+    }
+
+    #[test]
+    fn dates_of_a_planning_range_over_several_years() {
+        let planner_model = PlannerModel::new(
+            DateRange::new(LocalDate::new(2024, 12, 30), LocalDate::new(2026, 1, 2)),
+            LocationId::new(),
+            StandardSetId::new(),
+            vec![],
+            vec![],
+            vec![],
+        );
+        let settings = settings(&[(LocalDate::new(2025, 12, 31), LocalDate::new(2026, 1, 1))]);
 
-        dates
+        assert_eq!(settings.dates(&planner_model), vec![
+            LocalDate::new(2024, 12, 31),
+            LocalDate::new(2025, 1, 1),
+            LocalDate::new(2025, 12, 31),
+            LocalDate::new(2026, 1, 1),
+        ]);
     }
 }
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use crate::workcontent::domain::meal_break::MealBreak;
+use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+use crate::workcontent::domain::non_meal_break::NonMealBreak;
+use crate::workcontent::domain::planner_settings::PlannerSettings;
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::domain::standard_type::StandardType;
+
+/// The layer a resolved planner setting came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SettingSource {
+    /// The built-in value of `PlannerSettings::default()`.
+    #[default]
+    Default,
+    /// The location's `PropertyPlannerSettings` for the standard set.
+    Property,
+    /// The job's `AssignmentPlannerSettings` for the standard set.
+    Assignment,
+}
+
+/// The planner settings of one layer. A value left `None` inherits the value of the layer below.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlannerSettingsLayer {
+    pub standard_type: Option<StandardType>,
+    pub period_length: Option<u32>,
+    pub min_shift_length: Option<f64>,
+    pub max_shift_length: Option<f64>,
+    pub rounding_threshold_below_one: Option<f64>,
+    pub rounding_threshold_above_one: Option<f64>,
+    pub meal_break_after: Option<f64>,
+    pub meal_break_length: Option<f64>,
+    pub non_meal_break_every: Option<f64>,
+    pub non_meal_break_length: Option<f64>,
+    pub generate_long_shifts: Option<bool>,
+    pub limit_shift_to_max_shift: Option<bool>,
+    pub truncate_max_coverage: Option<bool>,
+    pub non_flowed_distribution_method: Option<NonFlowedDistributionMethod>,
+}
+
+/// The layer each value of resolved planner settings came from. Breaks record their two values
+/// separately, as each can come from a different layer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlannerSettingSources {
+    pub standard_type: SettingSource,
+    pub period_length: SettingSource,
+    pub min_shift_length: SettingSource,
+    pub max_shift_length: SettingSource,
+    pub rounding_threshold_below_one: SettingSource,
+    pub rounding_threshold_above_one: SettingSource,
+    pub meal_break_after: SettingSource,
+    pub meal_break_length: SettingSource,
+    pub non_meal_break_every: SettingSource,
+    pub non_meal_break_length: SettingSource,
+    pub generate_long_shifts: SettingSource,
+    pub limit_shift_to_max_shift: SettingSource,
+    pub truncate_max_coverage: SettingSource,
+    pub non_flowed_distribution_method: SettingSource,
+}
+
+/// Resolves the planner settings of a location's jobs: the job's settings for the standard set
+/// override the location's, which override the defaults. A meal or non-meal break is only taken
+/// when both of its values resolve.
+#[derive(Debug, Clone, Default)]
+pub struct PlannerSettingsResolver {
+    property_settings: HashMap<StandardSetId, PlannerSettingsLayer>,
+}
+
+impl PlannerSettingsResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_property_settings(mut self, standard_set_id: StandardSetId, property_settings: PlannerSettingsLayer) -> Self {
+        self.property_settings.insert(standard_set_id, property_settings);
+        self
+    }
+
+    pub fn property_settings(&self, standard_set_id: StandardSetId) -> Option<&PlannerSettingsLayer> {
+        self.property_settings.get(&standard_set_id)
+    }
+
+    /// The settings of a job with the assignment settings for the standard set. Effective dates
+    /// are not layered and keep their default.
+    pub fn resolve(&self, standard_set_id: StandardSetId, assignment_settings: &PlannerSettingsLayer) -> PlannerSettings {
+        let no_property_settings = PlannerSettingsLayer::default();
+        let property_settings = self.property_settings.get(&standard_set_id).unwrap_or(&no_property_settings);
+        let layers = [
+            (assignment_settings, SettingSource::Assignment),
+            (property_settings, SettingSource::Property),
+        ];
+        let mut settings = PlannerSettings::default();
+        let mut sources = PlannerSettingSources::default();
+
+        (settings.standard_type, sources.standard_type) =
+            resolve(&layers, settings.standard_type, |layer| layer.standard_type);
+        (settings.period_length, sources.period_length) =
+            resolve(&layers, settings.period_length, |layer| layer.period_length);
+        (settings.min_shift_length, sources.min_shift_length) =
+            resolve(&layers, settings.min_shift_length, |layer| layer.min_shift_length);
+        (settings.max_shift_length, sources.max_shift_length) =
+            resolve(&layers, settings.max_shift_length, |layer| layer.max_shift_length);
+        (settings.rounding_threshold_below_one, sources.rounding_threshold_below_one) =
+            resolve(&layers, settings.rounding_threshold_below_one, |layer| layer.rounding_threshold_below_one);
+        (settings.rounding_threshold_above_one, sources.rounding_threshold_above_one) =
+            resolve(&layers, settings.rounding_threshold_above_one, |layer| layer.rounding_threshold_above_one);
+        (settings.generate_long_shifts, sources.generate_long_shifts) =
+            resolve(&layers, settings.generate_long_shifts, |layer| layer.generate_long_shifts);
+        (settings.limit_shift_to_max_shift, sources.limit_shift_to_max_shift) =
+            resolve(&layers, settings.limit_shift_to_max_shift, |layer| layer.limit_shift_to_max_shift);
+        (settings.truncate_max_coverage, sources.truncate_max_coverage) =
+            resolve(&layers, settings.truncate_max_coverage, |layer| layer.truncate_max_coverage);
+        (settings.non_flowed_distribution_method, sources.non_flowed_distribution_method) =
+            resolve(&layers, settings.non_flowed_distribution_method, |layer| layer.non_flowed_distribution_method);
+
+        let (meal_break_after, meal_break_length);
+        (meal_break_after, sources.meal_break_after) = resolve(&layers, None, |layer| layer.meal_break_after.map(Some));
+        (meal_break_length, sources.meal_break_length) = resolve(&layers, None, |layer| layer.meal_break_length.map(Some));
+        settings.meal_break = meal_break_after.zip(meal_break_length)
+            .map(|(break_after, break_length)| MealBreak { break_after, break_length });
+
+        let (non_meal_break_every, non_meal_break_length);
+        (non_meal_break_every, sources.non_meal_break_every) = resolve(&layers, None, |layer| layer.non_meal_break_every.map(Some));
+        (non_meal_break_length, sources.non_meal_break_length) = resolve(&layers, None, |layer| layer.non_meal_break_length.map(Some));
+        settings.non_meal_break = non_meal_break_every.zip(non_meal_break_length)
+            .map(|(break_every, break_length)| NonMealBreak { break_every, break_length });
+
+        settings.sources = sources;
+        settings
+    }
+}
+
+/// The value of the first layer that sets it, or the default.
+fn resolve<T>(layers: &[(&PlannerSettingsLayer, SettingSource)],
+              default: T,
+              value: impl Fn(&PlannerSettingsLayer) -> Option<T>) -> (T, SettingSource) {
+    layers.iter()
+        .find_map(|(layer, source)| value(layer).map(|value| (value, *source)))
+        .unwrap_or((default, SettingSource::Default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property_settings() -> PlannerSettingsLayer {
+        PlannerSettingsLayer {
+            period_length: Some(15),
+            min_shift_length: Some(3.0),
+            meal_break_after: Some(5.0),
+            meal_break_length: Some(0.5),
+            generate_long_shifts: Some(true),
+            ..PlannerSettingsLayer::default()
+        }
+    }
+
+    #[test]
+    fn assignment_settings_override_property_settings_which_override_the_defaults() {
+        let standard_set_id = StandardSetId::new();
+        let resolver = PlannerSettingsResolver::new().with_property_settings(standard_set_id, property_settings());
+        let assignment_settings = PlannerSettingsLayer {
+            standard_type: Some(StandardType::BASIC),
+            min_shift_length: Some(4.5),
+            meal_break_length: Some(0.75),
+            generate_long_shifts: Some(false),
+            ..PlannerSettingsLayer::default()
+        };
+
+        let settings = resolver.resolve(standard_set_id, &assignment_settings);
+
+        assert_eq!((settings.standard_type, settings.sources.standard_type), (StandardType::BASIC, SettingSource::Assignment));
+        assert_eq!((settings.min_shift_length, settings.sources.min_shift_length), (4.5, SettingSource::Assignment));
+        assert_eq!((settings.period_length, settings.sources.period_length), (15, SettingSource::Property));
+        assert_eq!((settings.max_shift_length, settings.sources.max_shift_length), (8.0, SettingSource::Default));
+        assert_eq!((settings.generate_long_shifts, settings.sources.generate_long_shifts), (false, SettingSource::Assignment));
+        assert_eq!(settings.meal_break.map(|meal_break| (meal_break.break_after, meal_break.break_length)), Some((5.0, 0.75)));
+        assert_eq!((settings.sources.meal_break_after, settings.sources.meal_break_length), (SettingSource::Property, SettingSource::Assignment));
+    }
+
+    #[test]
+    fn other_standard_sets_do_not_inherit_the_property_settings() {
+        let resolver = PlannerSettingsResolver::new().with_property_settings(StandardSetId::new(), property_settings());
+
+        let settings = resolver.resolve(StandardSetId::new(), &PlannerSettingsLayer::default());
+
+        assert_eq!(settings.period_length, PlannerSettings::default().period_length);
+        assert_eq!(settings.sources, PlannerSettingSources::default());
+        assert!(settings.meal_break.is_none());
+    }
+
+    #[test]
+    fn break_needs_both_values() {
+        let assignment_settings = PlannerSettingsLayer {
+            non_meal_break_every: Some(2.0),
+            ..PlannerSettingsLayer::default()
+        };
+
+        let settings = PlannerSettingsResolver::new().resolve(StandardSetId::new(), &assignment_settings);
+
+        assert!(settings.non_meal_break.is_none());
+        assert_eq!(settings.sources.non_meal_break_every, SettingSource::Assignment);
+    }
+}
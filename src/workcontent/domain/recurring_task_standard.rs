@@ -0,0 +1,211 @@
+use joda_rs::{DayOfWeek, LocalDate, LocalTime};
+use crate::workcontent::common::db_code::DbCode;
+use crate::id_type;
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::domain::job_shift::JobShiftId;
+use crate::workcontent::domain::standard_set::StandardSetId;
+
+id_type!(RecurringTaskStandardId, uuid_v4);
+
+/// `MonthlyEveryNthWeek` value meaning the last week of the month.
+pub const LAST_WEEK_OF_MONTH: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrequencyType {
+    DAILY,
+    WEEKLY,
+    MONTHLY,
+}
+
+impl DbCode for FrequencyType {
+    const ENUM_TYPE: &'static str = "FrequencyTypeEnum";
+    const VALUES: &'static [Self] = &[
+        FrequencyType::DAILY,
+        FrequencyType::WEEKLY,
+        FrequencyType::MONTHLY,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            FrequencyType::DAILY => "D",
+            FrequencyType::WEEKLY => "W",
+            FrequencyType::MONTHLY => "M",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthlyIntervalType {
+    /// Day N of the month, e.g. the 1st.
+    DayNOfEveryMonth,
+    /// The Nth week day of the month, e.g. the 2nd Tuesday.
+    NthDayOfEveryNMonths,
+}
+
+impl DbCode for MonthlyIntervalType {
+    const ENUM_TYPE: &'static str = "MonthlyIntervalEnum";
+    const VALUES: &'static [Self] = &[
+        MonthlyIntervalType::DayNOfEveryMonth,
+        MonthlyIntervalType::NthDayOfEveryNMonths,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            MonthlyIntervalType::DayNOfEveryMonth => "D",
+            MonthlyIntervalType::NthDayOfEveryNMonths => "N",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccurrenceType {
+    DuringShift,
+    SingleOccurrence,
+    MultipleOccurrences,
+}
+
+impl DbCode for OccurrenceType {
+    const ENUM_TYPE: &'static str = "OccurrenceEnum";
+    const VALUES: &'static [Self] = &[
+        OccurrenceType::DuringShift,
+        OccurrenceType::SingleOccurrence,
+        OccurrenceType::MultipleOccurrences,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            OccurrenceType::DuringShift => "S",
+            OccurrenceType::SingleOccurrence => "O",
+            OccurrenceType::MultipleOccurrences => "M",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationType {
+    FIXED,
+    VARIABLE,
+}
+
+impl DbCode for DurationType {
+    const ENUM_TYPE: &'static str = "DurationTypeEnum";
+    const VALUES: &'static [Self] = &[
+        DurationType::FIXED,
+        DurationType::VARIABLE,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            DurationType::FIXED => "F",
+            DurationType::VARIABLE => "V",
+        }
+    }
+}
+
+/// The hours of a variable duration recurring task when the business driver value is in the
+/// range: the base hours plus the additional hours for every `per_number_units` units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecurringVariableWork {
+    from_volume: i32,
+    to_volume: i32,
+    base_hours: f64,
+    additional_hours: f64,
+    per_number_units: u32,
+}
+
+impl RecurringVariableWork {
+    pub fn new(from_volume: i32, to_volume: i32, base_hours: f64, additional_hours: f64, per_number_units: u32) -> Self {
+        Self {
+            from_volume,
+            to_volume,
+            base_hours,
+            additional_hours,
+            per_number_units,
+        }
+    }
+    pub fn from_volume(&self) -> i32 {
+        self.from_volume
+    }
+    pub fn to_volume(&self) -> i32 {
+        self.to_volume
+    }
+    pub fn base_hours(&self) -> f64 {
+        self.base_hours
+    }
+    pub fn additional_hours(&self) -> f64 {
+        self.additional_hours
+    }
+    pub fn per_number_units(&self) -> u32 {
+        self.per_number_units
+    }
+    pub fn contains_value(&self, value: i32) -> bool {
+        value >= self.from_volume && value <= self.to_volume
+    }
+}
+
+/// A task done on a schedule, e.g. "deep clean every 2nd Tuesday". Only the fields for the
+/// standard's frequency and occurrence type are used.
+pub struct RecurringTaskStandard {
+    pub id: RecurringTaskStandardId,
+    pub job_id: JobId,
+    pub standard_set_id: StandardSetId,
+    pub name: String,
+    pub initial_date: LocalDate,
+    pub frequency_type: FrequencyType,
+    pub daily_interval: Option<u32>,
+    pub weekly_interval: Option<u32>,
+    pub weekly_days_of_week: Vec<DayOfWeek>,
+    pub monthly_interval_type: Option<MonthlyIntervalType>,
+    pub monthly_day_of_month: Option<u32>,
+    pub monthly_every_nth_week: Option<u32>,
+    pub monthly_day_of_week: Option<DayOfWeek>,
+    pub monthly_selected_months: Vec<u32>,
+    pub occurrence_type: OccurrenceType,
+    pub occurs_during_shift_id: Option<JobShiftId>,
+    pub occurs_at_time: Option<LocalTime>,
+    pub occurs_every_n_hours: Option<f64>,
+    pub occurs_starting_at_time: Option<LocalTime>,
+    pub occurs_ending_at_time: Option<LocalTime>,
+    pub duration_type: DurationType,
+    pub fixed_hours: Option<f64>,
+    pub business_driver_id: Option<BusinessDriverId>,
+    pub variable_work: Vec<RecurringVariableWork>,
+}
+
+impl RecurringTaskStandard {
+    /// A fixed duration task occurring once a day at midnight on the days of its frequency. The
+    /// remaining rule fields are set by the caller.
+    pub fn new(job_id: JobId,
+               standard_set_id: StandardSetId,
+               name: String,
+               initial_date: LocalDate,
+               frequency_type: FrequencyType) -> Self {
+        Self {
+            id: RecurringTaskStandardId::new(),
+            job_id,
+            standard_set_id,
+            name,
+            initial_date,
+            frequency_type,
+            daily_interval: None,
+            weekly_interval: None,
+            weekly_days_of_week: Vec::new(),
+            monthly_interval_type: None,
+            monthly_day_of_month: None,
+            monthly_every_nth_week: None,
+            monthly_day_of_week: None,
+            monthly_selected_months: Vec::new(),
+            occurrence_type: OccurrenceType::SingleOccurrence,
+            occurs_during_shift_id: None,
+            occurs_at_time: None,
+            occurs_every_n_hours: None,
+            occurs_starting_at_time: None,
+            occurs_ending_at_time: None,
+            duration_type: DurationType::FIXED,
+            fixed_hours: None,
+            business_driver_id: None,
+            variable_work: Vec::new(),
+        }
+    }
+}
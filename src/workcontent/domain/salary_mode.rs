@@ -1,5 +1,22 @@
+use crate::workcontent::common::db_code::DbCode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SalaryMode {
     WEEKLY,
     MONTHLY
 }
+
+impl DbCode for SalaryMode {
+    const ENUM_TYPE: &'static str = "SalaryModeEnum";
+    const VALUES: &'static [Self] = &[
+        SalaryMode::WEEKLY,
+        SalaryMode::MONTHLY,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            SalaryMode::WEEKLY => "W",
+            SalaryMode::MONTHLY => "M",
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use joda_rs::LocalTime;
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::distribution_method::DistributionMethod;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::flow_plan::FlowPlanId;
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::domain::job_shift::JobShiftId;
+use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::domain::task_standards::TaskStandardDetail;
+use crate::workcontent::domain::units::Units;
+use crate::workcontent::domain::work_type::WorkType;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShiftRelatedStandardValue {
+    environment: Environment,
+    value: f64,
+}
+
+impl ShiftRelatedStandardValue {
+    pub fn new(environment: Environment, value: f64) -> Self {
+        Self {
+            environment,
+            value,
+        }
+    }
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShiftRelatedRange {
+    from_volume: i32,
+    to_volume: i32,
+    values: Vec<ShiftRelatedStandardValue>,
+}
+
+impl ShiftRelatedRange {
+    pub fn new(from_volume: i32, to_volume: i32, values: Vec<ShiftRelatedStandardValue>) -> Self {
+        Self {
+            from_volume,
+            to_volume,
+            values,
+        }
+    }
+    pub fn from_volume(&self) -> i32 {
+        self.from_volume
+    }
+    pub fn to_volume(&self) -> i32 {
+        self.to_volume
+    }
+    pub fn values(&self) -> &[ShiftRelatedStandardValue] {
+        &self.values
+    }
+    pub fn contains_value(&self, value: i32) -> bool {
+        value >= self.from_volume && value <= self.to_volume
+    }
+    pub fn value_for_environment(&self, environment: Environment) -> Option<f64> {
+        self.values.iter()
+            .find(|value| value.environment.id() == environment.id())
+            .map(|value| value.value)
+    }
+}
+
+pub struct ShiftRelatedStandard {
+    pub job_id: JobId,
+    pub standard_set_id: StandardSetId,
+    pub shift_id: JobShiftId,
+    pub business_driver_id: BusinessDriverId,
+    pub name: String,
+    pub work_type: WorkType,
+    pub units: Units,
+    pub distribution_method: DistributionMethod,
+    pub non_flowed_distribution_method: Option<NonFlowedDistributionMethod>,
+    pub flow_plan_id: Option<FlowPlanId>,
+    pub ignore_retention: bool,
+    pub earliest_work_start_time: Option<LocalTime>,
+    pub latest_work_end_time: Option<LocalTime>,
+    pub ranges: Vec<ShiftRelatedRange>,
+    /// The tasks making up the work of a `WorkType::Task` standard.
+    pub task_standard_details: Vec<TaskStandardDetail>,
+}
+
+impl ShiftRelatedStandard {
+    /// The standard value of the range containing the business driver value, for the environment.
+    pub fn standard_value(&self, business_driver_value: i32, environment: Environment) -> Option<f64> {
+        self.ranges.iter()
+            .find(|range| range.contains_value(business_driver_value))
+            .and_then(|range| range.value_for_environment(environment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_standard(ranges: Vec<ShiftRelatedRange>) -> ShiftRelatedStandard {
+        ShiftRelatedStandard {
+            job_id: JobId::new(),
+            standard_set_id: StandardSetId::new(),
+            shift_id: JobShiftId::new(),
+            business_driver_id: BusinessDriverId::new(),
+            name: "Check-ins".to_string(),
+            work_type: WorkType::Variable,
+            units: Units::MinutesPerUnit,
+            distribution_method: DistributionMethod::Flowed,
+            non_flowed_distribution_method: None,
+            flow_plan_id: None,
+            ignore_retention: false,
+            earliest_work_start_time: None,
+            latest_work_end_time: None,
+            ranges,
+            task_standard_details: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn standard_value_uses_range_and_environment() {
+        let env1 = Environment::new(1);
+        let env2 = Environment::new(2);
+        let standard = make_standard(vec![
+            ShiftRelatedRange::new(0, 100, vec![
+                ShiftRelatedStandardValue::new(env1, 3.0),
+                ShiftRelatedStandardValue::new(env2, 4.0),
+            ]),
+            ShiftRelatedRange::new(101, 500, vec![
+                ShiftRelatedStandardValue::new(env1, 2.5),
+            ]),
+        ]);
+
+        assert_eq!(standard.standard_value(50, env1), Some(3.0));
+        assert_eq!(standard.standard_value(100, env2), Some(4.0));
+        assert_eq!(standard.standard_value(101, env1), Some(2.5));
+        assert_eq!(standard.standard_value(101, env2), None);
+        assert_eq!(standard.standard_value(501, env1), None);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::workcontent::common::db_code::DbCode;
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::domain::job_shift::JobShiftId;
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::domain::units::Units;
+
+/// Minutes covered by each entry of a fixed spread.
+pub const SPREAD_PERIOD_LENGTH: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadStandardType {
+    FIXED,
+    DYNAMIC,
+}
+
+impl DbCode for SpreadStandardType {
+    const ENUM_TYPE: &'static str = "SpreadStandardTypeEnum";
+    const VALUES: &'static [Self] = &[
+        SpreadStandardType::FIXED,
+        SpreadStandardType::DYNAMIC,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            SpreadStandardType::FIXED => "F",
+            SpreadStandardType::DYNAMIC => "D",
+        }
+    }
+}
+
+/// Fixed staffing values for an environment, one value for each 5 minute period starting at midnight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadStandardValue {
+    environment: Environment,
+    spread_values: Vec<u32>,
+}
+
+impl SpreadStandardValue {
+    pub fn new(environment: Environment, spread_values: Vec<u32>) -> Self {
+        Self {
+            environment,
+            spread_values,
+        }
+    }
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+    pub fn spread_values(&self) -> &[u32] {
+        &self.spread_values
+    }
+}
+
+/// The units of the business driver one staff member handles in a period, for an environment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamicSpreadStandard {
+    environment: Environment,
+    value: f64,
+}
+
+impl DynamicSpreadStandard {
+    pub fn new(environment: Environment, value: f64) -> Self {
+        Self {
+            environment,
+            value,
+        }
+    }
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadStandardRange {
+    from_volume: i32,
+    to_volume: i32,
+    values: Vec<SpreadStandardValue>,
+    dynamic_values: Vec<DynamicSpreadStandard>,
+}
+
+impl SpreadStandardRange {
+    pub fn new(from_volume: i32, to_volume: i32) -> Self {
+        Self {
+            from_volume,
+            to_volume,
+            values: Vec::new(),
+            dynamic_values: Vec::new(),
+        }
+    }
+
+    pub fn with_values(mut self, values: Vec<SpreadStandardValue>) -> Self {
+        self.values = values;
+        self
+    }
+
+    pub fn with_dynamic_values(mut self, dynamic_values: Vec<DynamicSpreadStandard>) -> Self {
+        self.dynamic_values = dynamic_values;
+        self
+    }
+
+    pub fn from_volume(&self) -> i32 {
+        self.from_volume
+    }
+    pub fn to_volume(&self) -> i32 {
+        self.to_volume
+    }
+    pub fn values(&self) -> &[SpreadStandardValue] {
+        &self.values
+    }
+    pub fn dynamic_values(&self) -> &[DynamicSpreadStandard] {
+        &self.dynamic_values
+    }
+    pub fn contains_value(&self, value: i32) -> bool {
+        value >= self.from_volume && value <= self.to_volume
+    }
+    pub fn values_for_environment(&self, environment: Environment) -> Option<&SpreadStandardValue> {
+        self.values.iter()
+            .find(|value| value.environment.id() == environment.id())
+    }
+    pub fn dynamic_value_for_environment(&self, environment: Environment) -> Option<f64> {
+        self.dynamic_values.iter()
+            .find(|value| value.environment.id() == environment.id())
+            .map(|value| value.value)
+    }
+}
+
+pub struct SpreadStandard {
+    pub job_id: JobId,
+    pub standard_set_id: StandardSetId,
+    pub shift_id: JobShiftId,
+    pub business_driver_id: BusinessDriverId,
+    pub spread_standard_type: SpreadStandardType,
+    pub units: Option<Units>,
+    pub ranges: Vec<SpreadStandardRange>,
+}
+
+impl SpreadStandard {
+    pub fn range_for(&self, business_driver_value: i32) -> Option<&SpreadStandardRange> {
+        self.ranges.iter()
+            .find(|range| range.contains_value(business_driver_value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_for_picks_range_containing_driver_value() {
+        let standard = SpreadStandard {
+            job_id: JobId::new(),
+            standard_set_id: StandardSetId::new(),
+            shift_id: JobShiftId::new(),
+            business_driver_id: BusinessDriverId::new(),
+            spread_standard_type: SpreadStandardType::FIXED,
+            units: None,
+            ranges: vec![
+                SpreadStandardRange::new(0, 99)
+                    .with_values(vec![SpreadStandardValue::new(Environment::new(1), vec![1])]),
+                SpreadStandardRange::new(100, 199)
+                    .with_values(vec![SpreadStandardValue::new(Environment::new(1), vec![2])]),
+            ],
+        };
+
+        assert_eq!(standard.range_for(150).unwrap().from_volume(), 100);
+        assert_eq!(standard.range_for(99).unwrap().from_volume(), 0);
+        assert!(standard.range_for(200).is_none());
+        let range = standard.range_for(0).unwrap();
+        assert_eq!(range.values_for_environment(Environment::new(1)).unwrap().spread_values(), &[1]);
+        assert!(range.values_for_environment(Environment::new(2)).is_none());
+    }
+}
@@ -1,7 +1,33 @@
+use crate::workcontent::common::db_code::DbCode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StandardType {
     NONE,
     BASIC,
+    EVENT,
     ADVANCED,
     SALARIED,
 }
+
+/// Basic standards are the schema's shift related standards and advanced standards its KBI related
+/// standards. Event related standards have no generator yet and generate no work.
+impl DbCode for StandardType {
+    const ENUM_TYPE: &'static str = "StandardTypeEnum";
+    const VALUES: &'static [Self] = &[
+        StandardType::NONE,
+        StandardType::BASIC,
+        StandardType::EVENT,
+        StandardType::ADVANCED,
+        StandardType::SALARIED,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            StandardType::NONE => "N",
+            StandardType::BASIC => "H",
+            StandardType::EVENT => "E",
+            StandardType::ADVANCED => "K",
+            StandardType::SALARIED => "S",
+        }
+    }
+}
@@ -41,14 +41,16 @@ impl TaskStandardRange {
 
 #[derive(Debug, Clone)]
 pub struct TaskStandardDetail {
+    name: String,
     reasonable_expectancy: f64,
     number_of_items: i32,
     ranges: Vec<(TaskStandardRange, Vec<TaskStandardFrequency>)>,
 }
 
 impl TaskStandardDetail {
-    pub fn new(reasonable_expectancy: f64, number_of_items: i32) -> Self {
+    pub fn new(name: String, reasonable_expectancy: f64, number_of_items: i32) -> Self {
         Self {
+            name,
             reasonable_expectancy,
             number_of_items,
             ranges: Vec::new(),
@@ -61,6 +63,9 @@ impl TaskStandardDetail {
         self.ranges = ranges;
         self
     }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
     pub fn reasonable_expectancy(&self) -> f64 {
         self.reasonable_expectancy
     }
@@ -1,3 +1,5 @@
+use crate::workcontent::common::db_code::DbCode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Units {
     Hours,
@@ -6,5 +8,34 @@ pub enum Units {
     MinutesPerUnit,
     UnitsPerHour,
     UnitsPerMinute,
-    UnitsPerShift
+    UnitsPerShift,
+    /// Units one person handles in a shift, so worked out like `UnitsPerShift`.
+    UnitsPerPerson,
+}
+
+impl DbCode for Units {
+    const ENUM_TYPE: &'static str = "UnitsEnum";
+    const VALUES: &'static [Self] = &[
+        Units::Hours,
+        Units::Minutes,
+        Units::HoursPerUnit,
+        Units::MinutesPerUnit,
+        Units::UnitsPerHour,
+        Units::UnitsPerMinute,
+        Units::UnitsPerShift,
+        Units::UnitsPerPerson,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            Units::Hours => "HR",
+            Units::Minutes => "MN",
+            Units::HoursPerUnit => "HU",
+            Units::MinutesPerUnit => "MU",
+            Units::UnitsPerHour => "UH",
+            Units::UnitsPerMinute => "UM",
+            Units::UnitsPerShift => "US",
+            Units::UnitsPerPerson => "UP",
+        }
+    }
 }
@@ -0,0 +1,22 @@
+use crate::workcontent::common::db_code::DbCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    FORECAST,
+    ACTUALS,
+}
+
+impl DbCode for ValueType {
+    const ENUM_TYPE: &'static str = "ValueTypeEnum";
+    const VALUES: &'static [Self] = &[
+        ValueType::FORECAST,
+        ValueType::ACTUALS,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            ValueType::FORECAST => "F",
+            ValueType::ACTUALS => "A",
+        }
+    }
+}
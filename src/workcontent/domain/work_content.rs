@@ -7,7 +7,7 @@ use crate::workcontent::domain::location::{Location, LocationId};
 
 id_type!(WorkContentId, uuid_v4);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct WorkContent {
     id: WorkContentId,
     job_id: JobId,
@@ -29,6 +29,7 @@ pub struct WorkContent {
 }
 
 impl WorkContent {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         job_id: JobId,
         property_id: LocationId,
@@ -68,6 +69,11 @@ impl WorkContent {
         }
     }
 
+    pub fn with_id(mut self, id: WorkContentId) -> Self {
+        self.id = id;
+        self
+    }
+
     pub fn id(&self) -> WorkContentId { self.id }
     pub fn job_id(&self) -> JobId { self.job_id }
     pub fn property_id(&self) -> LocationId { self.property_id }
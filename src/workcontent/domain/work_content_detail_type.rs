@@ -0,0 +1,25 @@
+use crate::workcontent::common::db_code::DbCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkContentDetailType {
+    NormalTask,
+    SetupTask,
+    TearDownTask,
+}
+
+impl DbCode for WorkContentDetailType {
+    const ENUM_TYPE: &'static str = "WorkContentDetailType";
+    const VALUES: &'static [Self] = &[
+        WorkContentDetailType::NormalTask,
+        WorkContentDetailType::SetupTask,
+        WorkContentDetailType::TearDownTask,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            WorkContentDetailType::NormalTask => "T",
+            WorkContentDetailType::SetupTask => "U",
+            WorkContentDetailType::TearDownTask => "D",
+        }
+    }
+}
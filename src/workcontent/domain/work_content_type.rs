@@ -0,0 +1,34 @@
+use crate::workcontent::common::db_code::DbCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkContentType {
+    EventRelated,
+    NonEventRelated,
+    MultiEventRelated,
+    EventSetup,
+    EventTearDown,
+    ManualTask,
+}
+
+impl DbCode for WorkContentType {
+    const ENUM_TYPE: &'static str = "WorkContentType";
+    const VALUES: &'static [Self] = &[
+        WorkContentType::EventRelated,
+        WorkContentType::NonEventRelated,
+        WorkContentType::MultiEventRelated,
+        WorkContentType::EventSetup,
+        WorkContentType::EventTearDown,
+        WorkContentType::ManualTask,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            WorkContentType::EventRelated => "E",
+            WorkContentType::NonEventRelated => "N",
+            WorkContentType::MultiEventRelated => "M",
+            WorkContentType::EventSetup => "U",
+            WorkContentType::EventTearDown => "D",
+            WorkContentType::ManualTask => "A",
+        }
+    }
+}
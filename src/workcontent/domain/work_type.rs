@@ -0,0 +1,37 @@
+use crate::workcontent::common::db_code::DbCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkType {
+    Daily,
+    Weekly,
+    Variable,
+    RecurringTask,
+    Staff,
+    ShareWith,
+    Task
+}
+
+impl DbCode for WorkType {
+    const ENUM_TYPE: &'static str = "WorkTypeEnum";
+    const VALUES: &'static [Self] = &[
+        WorkType::Daily,
+        WorkType::Weekly,
+        WorkType::Variable,
+        WorkType::RecurringTask,
+        WorkType::Staff,
+        WorkType::ShareWith,
+        WorkType::Task,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            WorkType::Daily => "D",
+            WorkType::Weekly => "W",
+            WorkType::Variable => "V",
+            WorkType::RecurringTask => "R",
+            WorkType::Staff => "S",
+            WorkType::ShareWith => "H",
+            WorkType::Task => "T",
+        }
+    }
+}
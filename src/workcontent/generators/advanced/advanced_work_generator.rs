@@ -1,19 +1,857 @@
-use std::any::Any;
-use crate::workcontent::domain::job;
+use joda_rs::{LocalDate, LocalDateTime};
+use crate::workcontent::common::date_times::plus_hours;
+use crate::workcontent::common::numbers::{round_hours, round_to_i32};
+use crate::workcontent::domain::distribution_method::DistributionMethod;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::flow_plan::FlowPattern;
 use crate::workcontent::domain::job::Job;
-use crate::workcontent::generators::work_generators::{WorkGenerator, WorkResults};
+use crate::workcontent::domain::job_shift::{JobShift, JobShiftDefinition};
+use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+use crate::workcontent::domain::planned_shift::PlannedShift;
+use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
 use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::domain::recurring_task_standard::{DurationType, RecurringTaskStandard};
+use crate::workcontent::domain::shift_related_standard::ShiftRelatedStandard;
+use crate::workcontent::domain::spread_standard::SpreadStandard;
+use crate::workcontent::domain::work_content::{WorkContent, WorkContentId};
+use crate::workcontent::domain::work_type::WorkType;
+use crate::workcontent::generators::advanced::recurrence_engine::{RecurrenceEngine, RecurrenceOccurrence};
+use crate::workcontent::generators::advanced::work_distributor::WorkDistributor;
+use crate::workcontent::generators::basic::basic_calculator::BasicCalculator;
+use crate::workcontent::generators::basic::basic_planned_shift_creator::BasicPlannedShiftCreator;
+use crate::workcontent::generators::basic::basic_work_content_creator::BasicWorkContentCreator;
+use crate::workcontent::generators::calculator::frequency_calculator::FrequencyCalculator;
+use crate::workcontent::generators::calculator::recurring_variable_work_calculator::RecurringVariableWorkCalculator;
+use crate::workcontent::generators::calculator::spread_calculator::SpreadCalculator;
+use crate::workcontent::generators::calculator::work_content_log::{WorkContentLog, WorkContentLogDetail};
+use crate::workcontent::generators::calculator::work_per_unit_calculator::{trim_f64, WorkPerUnitCalculator};
+use crate::workcontent::generators::work_generators::{WorkGenerator, WorkResults};
 
-pub struct AdvancedWorkGenerator;
+const MINUTES_PER_HOUR: f64 = 60.0;
+const MINUTES_PER_DAY: i64 = 1440;
+
+pub struct AdvancedWorkGenerator {
+    work_per_unit_calculator: WorkPerUnitCalculator,
+    work_distributor: WorkDistributor,
+    spread_calculator: SpreadCalculator,
+    recurrence_engine: RecurrenceEngine,
+    recurring_variable_work_calculator: RecurringVariableWorkCalculator,
+    frequency_calculator: FrequencyCalculator,
+    calculator: BasicCalculator,
+    work_content_creator: BasicWorkContentCreator,
+    planned_shift_creator: BasicPlannedShiftCreator,
+}
 
 impl AdvancedWorkGenerator {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            work_per_unit_calculator: WorkPerUnitCalculator::new(),
+            work_distributor: WorkDistributor::new(),
+            spread_calculator: SpreadCalculator::new(),
+            recurrence_engine: RecurrenceEngine::new(),
+            recurring_variable_work_calculator: RecurringVariableWorkCalculator::new(),
+            frequency_calculator: FrequencyCalculator::new(),
+            calculator: BasicCalculator::new(),
+            work_content_creator: BasicWorkContentCreator::new(),
+            planned_shift_creator: BasicPlannedShiftCreator::new(),
+        }
+    }
+
+    /// The planned shifts covering the work contents of a shift on the date, made up the way
+    /// basic jobs make theirs: full length shifts plus one for the remaining hours, from the shift
+    /// start. Every one of them covers all the work contents.
+    fn create_planned_shifts(&self,
+                             planner_model: &PlannerModel,
+                             job: &Job,
+                             shift: &JobShift,
+                             shift_definition: &JobShiftDefinition,
+                             date: LocalDate,
+                             work_contents: &[WorkContent]) -> Vec<PlannedShift> {
+        let work_hours: f64 = work_contents.iter().map(|work_content| work_content.calculated_hours()).sum();
+        let total_work_minutes = round_to_i32(work_hours * MINUTES_PER_HOUR);
+        if total_work_minutes <= 0 {
+            return Vec::new();
+        }
+
+        let calculation = self.calculator.calculate(job.planner_settings(), shift_definition.shift_length(), total_work_minutes);
+        let shift_work_content = self.work_content_creator.create_work_content(job, shift, shift_definition, date, &calculation);
+        let work_content_ids: Vec<WorkContentId> = work_contents.iter().map(|work_content| work_content.id()).collect();
+        self.planned_shift_creator.create_planned_shifts_from_work_contents(
+            std::slice::from_ref(&shift_work_content),
+            &calculation,
+            planner_model.default_shift_category_id(),
+        )
+            .into_iter()
+            .map(|planned_shift| planned_shift.with_work_content_ids(work_content_ids.clone()))
+            .collect()
+    }
+
+    /// A recurring task is not done in a job shift, so each occurrence gets a planned shift of
+    /// its own covering its work.
+    fn create_recurring_task_planned_shift(planner_model: &PlannerModel, work_content: &WorkContent) -> PlannedShift {
+        let start = work_content.calculated_start_date_time();
+        PlannedShift::new(
+            work_content.property_id(),
+            work_content.job_id(),
+            work_content.shift_type(),
+            start.to_local_date(),
+            start,
+            work_content.calculated_end_date_time(),
+        )
+        .with_date_shift_generated_from(work_content.shift_date())
+        .with_shift_category_id(planner_model.default_shift_category_id())
+        .with_work_content_ids(vec![work_content.id()])
+    }
+
+    /// Calculates the work for the standard on the date and distributes it over the periods of
+    /// the work window, creating one work content for each period that has work. A window that is
+    /// not a whole number of periods ends with a shorter period, ending at the window end. Standard values
+    /// are those of the environment of the standard's business driver. The log records how the
+    /// work was calculated.
+    fn generate_work_for_standard(&self,
+                                  planner_model: &PlannerModel,
+                                  job: &Job,
+                                  standard: &ShiftRelatedStandard,
+                                  shift_definition: &JobShiftDefinition,
+                                  date: LocalDate) -> (Vec<WorkContent>, Option<WorkContentLog>) {
+        let business_driver_value = planner_model.value_for(standard.business_driver_id, date) as i32;
+        let environment = planner_model.environment_for(standard.business_driver_id, date);
+
+        let details = self.calculate_work_for_standard(standard, business_driver_value, environment, shift_definition);
+        if details.is_empty() {
+            return (Vec::new(), None);
+        }
+        let log = WorkContentLog::new(job.id(), date, standard.name.clone(), details);
+
+        let period_length = job.planner_settings().period_length as i64;
+        let (window_start, window_end) = Self::work_window(standard, shift_definition, date);
+        let number_of_periods = ((window_end - window_start).to_minutes() + period_length - 1) / period_length;
+        let periods: Vec<(LocalDateTime, LocalDateTime)> = (0..number_of_periods)
+            .map(|period| {
+                let period_start = window_start.plus_minutes(period * period_length);
+                let period_end = period_start.plus_minutes(period_length);
+                (period_start, if period_end.is_after(window_end) { window_end } else { period_end })
+            })
+            .collect();
+
+        let minutes = self.distribute(planner_model, job, standard, date, environment, log.work_in_minutes(), &periods);
+
+        let work_contents = periods.iter()
+            .zip(minutes)
+            .filter(|(_, period_minutes)| *period_minutes > 0.0)
+            .map(|((period_start, period_end), period_minutes)| {
+                let hours = round_hours(period_minutes / MINUTES_PER_HOUR);
+
+                WorkContent::new(
+                    job.id(),
+                    job.property_id(),
+                    PlannedShiftType::Projected,
+                    date,
+                    window_start,
+                    *period_start,
+                    window_end,
+                    *period_start,
+                    *period_end,
+                    hours,
+                    hours,
+                    false,
+                    standard.name.clone(),
+                    0,
+                    0,
+                    *period_start,
+                )
+            })
+            .collect();
+
+        (work_contents, Some(log))
+    }
+
+    /// Task standards add up the frequency minutes of each of their tasks for the environment;
+    /// other standards calculate the work from their standard value.
+    fn calculate_work_for_standard(&self,
+                                   standard: &ShiftRelatedStandard,
+                                   business_driver_value: i32,
+                                   environment: Environment,
+                                   shift_definition: &JobShiftDefinition) -> Vec<(String, WorkContentLogDetail)> {
+        if standard.work_type == WorkType::Task {
+            return standard.task_standard_details.iter()
+                .filter_map(|task_standard_detail| {
+                    self.frequency_calculator
+                        .calculate_frequency_minutes(task_standard_detail, business_driver_value, environment)
+                        .map(|detail| (task_standard_detail.name().to_string(), detail))
+                })
+                .collect();
+        }
+
+        standard.standard_value(business_driver_value, environment)
+            .and_then(|standard_value| self.work_per_unit_calculator.calculate(
+                standard.units,
+                standard_value,
+                business_driver_value,
+                shift_definition.shift_length(),
+            ))
+            .map(|detail| vec![(standard.name.clone(), detail)])
+            .unwrap_or_default()
+    }
+
+    /// Creates one work content for each period of the shift's work window, from `hours_before` its
+    /// start to `hours_after` its end, that the spread standard staffs. Periods follow the day's
+    /// period grid from midnight, so the first and last may be cut short by the window; a window
+    /// past midnight takes the staffing of the same periods of the day. Dynamic spreads are shaped
+    /// by the driver's per-period history, see `PlannerModel::dynamic_spread_history_for`, and
+    /// staff nobody without one. The log, named after the shift, has the staff and minutes of each
+    /// period.
+    fn generate_work_for_spread_standard(&self,
+                                         planner_model: &PlannerModel,
+                                         job: &Job,
+                                         shift: &JobShift,
+                                         shift_definition: &JobShiftDefinition,
+                                         standard: &SpreadStandard,
+                                         date: LocalDate) -> (Vec<WorkContent>, Option<WorkContentLog>) {
+        let business_driver_value = planner_model.value_for(standard.business_driver_id, date) as i32;
+        let environment = planner_model.environment_for(standard.business_driver_id, date);
+        let period_length = job.planner_settings().period_length;
+        let historical_values = planner_model.dynamic_spread_history_for(standard.business_driver_id, date);
+
+        let Some(staffing) = self.spread_calculator.calculate(
+            standard,
+            business_driver_value,
+            environment,
+            period_length,
+            &historical_values,
+        ) else {
+            return (Vec::new(), None);
+        };
+
+        let period_length = period_length as i64;
+        let window_start = shift_definition.earliest_start_for(date);
+        let window_end = shift_definition.latest_end_for(date);
+        let minute_of_day = |date_time: LocalDateTime| (date_time.hour() * 60 + date_time.minute()) as i64;
+
+        let mut work_contents = Vec::new();
+        let mut details = Vec::new();
+        let mut grid_start = window_start.minus_minutes(minute_of_day(window_start) % period_length);
+        while grid_start.is_before(window_end) {
+            let period_start = if grid_start.is_before(window_start) { window_start } else { grid_start };
+            let grid_end = grid_start.plus_minutes(period_length);
+            let period_end = if grid_end.is_after(window_end) { window_end } else { grid_end };
+            let staff = staffing.get((minute_of_day(grid_start) / period_length) as usize).copied().unwrap_or(0.0);
+            grid_start = grid_end;
+            if staff <= 0.0 {
+                continue;
+            }
+
+            let minutes = (period_end - period_start).to_minutes();
+            let hours = round_hours(staff * minutes as f64 / MINUTES_PER_HOUR);
+            details.push((
+                format!("{:02}:{:02}", period_start.hour(), period_start.minute()),
+                WorkContentLogDetail::new(hours * MINUTES_PER_HOUR, format!("{} * {}", trim_f64(staff), minutes)),
+            ));
+            work_contents.push(WorkContent::new(
+                job.id(),
+                job.property_id(),
+                PlannedShiftType::Projected,
+                date,
+                period_start,
+                period_start,
+                period_end,
+                period_start,
+                period_end,
+                hours,
+                hours,
+                false,
+                shift.name().to_string(),
+                0,
+                0,
+                period_start,
+            ));
+        }
+
+        if work_contents.is_empty() {
+            return (work_contents, None);
+        }
+        (work_contents, Some(WorkContentLog::new(job.id(), date, shift.name().to_string(), details)))
+    }
+
+    /// The work content for one occurrence of a recurring task, with the log of its hours.
+    /// Variable duration tasks take their hours from the business driver value for the occurrence
+    /// date; a task without hours is skipped.
+    fn create_recurring_task_work_content(&self,
+                                          planner_model: &PlannerModel,
+                                          job: &Job,
+                                          standard: &RecurringTaskStandard,
+                                          occurrence: RecurrenceOccurrence) -> Option<(WorkContent, WorkContentLog)> {
+        let detail = match standard.duration_type {
+            DurationType::FIXED => {
+                let fixed_hours = standard.fixed_hours?;
+                WorkContentLogDetail::new(fixed_hours * MINUTES_PER_HOUR, format!("{} * {}", trim_f64(fixed_hours), MINUTES_PER_HOUR as i32))
+            }
+            DurationType::VARIABLE => {
+                let business_driver_value = standard.business_driver_id
+                    .map(|business_driver_id| planner_model.value_for(business_driver_id, occurrence.date()))
+                    .unwrap_or(0) as i32;
+                self.recurring_variable_work_calculator.calculate(&standard.variable_work, business_driver_value)?
+            }
+        };
+        let hours = detail.work_in_minutes() / MINUTES_PER_HOUR;
+        if hours <= 0.0 {
+            return None;
+        }
+        let log = WorkContentLog::new(job.id(), occurrence.date(), standard.name.clone(), vec![(standard.name.clone(), detail)]);
+
+        let start = occurrence.start_date_time();
+        let end = plus_hours(start, hours);
+
+        let work_content = WorkContent::new(
+            job.id(),
+            job.property_id(),
+            PlannedShiftType::Projected,
+            occurrence.date(),
+            start,
+            start,
+            end,
+            start,
+            end,
+            round_hours(hours),
+            round_hours(hours),
+            false,
+            standard.name.clone(),
+            0,
+            0,
+            start,
+        );
+        Some((work_content, log))
+    }
+
+    /// The window the work can be done in: the standard's earliest and latest work times when set,
+    /// otherwise the shift's start and end. A window ending at or before its start ends the next day.
+    fn work_window(standard: &ShiftRelatedStandard,
+                   shift_definition: &JobShiftDefinition,
+                   date: LocalDate) -> (LocalDateTime, LocalDateTime) {
+        let start = date.at_time(standard.earliest_work_start_time.unwrap_or(shift_definition.start_time()));
+        let mut end = date.at_time(standard.latest_work_end_time.unwrap_or(shift_definition.end_time()));
+        if !end.is_after(start) {
+            end = end.plus_days(1);
+        }
+        (start, end)
+    }
+
+    /// Distributes the work over the periods. Flowed work follows the flow pattern and work spread
+    /// evenly follows the length of each period, so a shorter last period gets its share of work.
+    #[allow(clippy::too_many_arguments)]
+    fn distribute(&self,
+                  planner_model: &PlannerModel,
+                  job: &Job,
+                  standard: &ShiftRelatedStandard,
+                  date: LocalDate,
+                  environment: Environment,
+                  work_in_minutes: f64,
+                  periods: &[(LocalDateTime, LocalDateTime)]) -> Vec<f64> {
+        let non_flowed_distribution_method = standard.non_flowed_distribution_method
+            .unwrap_or(job.planner_settings().non_flowed_distribution_method);
+
+        let method = match standard.distribution_method {
+            DistributionMethod::Flowed => {
+                let flow_pattern = standard.flow_plan_id
+                    .and_then(|flow_plan_id| planner_model.flow_plan(flow_plan_id))
+                    .and_then(|flow_plan| flow_plan.pattern_for(Some(environment), date.day_of_week()));
+
+                if let Some(flow_pattern) = flow_pattern {
+                    let period_length = job.planner_settings().period_length as i64;
+                    let weights: Vec<f64> = periods.iter()
+                        .map(|(period_start, period_end)| Self::flow_weight(flow_pattern, *period_start, (*period_end - *period_start).to_minutes(), period_length))
+                        .collect();
+                    return self.work_distributor.distribute_flowed(work_in_minutes, &weights);
+                }
+                NonFlowedDistributionMethod::EVEN
+            }
+            DistributionMethod::NonFlowed => non_flowed_distribution_method,
+            DistributionMethod::Opening => NonFlowedDistributionMethod::BEGINNING,
+            DistributionMethod::Closing => NonFlowedDistributionMethod::END,
+            DistributionMethod::FillGaps | DistributionMethod::ShareWith => NonFlowedDistributionMethod::EVEN,
+        };
+
+        match method {
+            NonFlowedDistributionMethod::EVEN | NonFlowedDistributionMethod::VARYING => {
+                let lengths: Vec<f64> = periods.iter()
+                    .map(|(period_start, period_end)| (*period_end - *period_start).to_minutes() as f64)
+                    .collect();
+                self.work_distributor.distribute_flowed(work_in_minutes, &lengths)
+            }
+            _ => self.work_distributor.distribute_non_flowed(work_in_minutes, periods.len(), method),
+        }
+    }
+
+    /// The flow pattern value for a period of `minutes`, in pattern periods of `period_length`. A
+    /// period starting off a pattern period boundary, as when the work window starts at 07:10,
+    /// takes the values of the pattern periods it overlaps in proportion to the minutes of
+    /// overlap; a period cut short by the window end takes only the minutes it has.
+    fn flow_weight(flow_pattern: &FlowPattern, period_start: LocalDateTime, minutes: i64, period_length: i64) -> f64 {
+        let start = (period_start.hour() * 60 + period_start.minute()) as i64;
+        let end = start + minutes;
+        let mut weight = 0.0;
+        let mut minute = start;
+        while minute < end {
+            let minute_of_day = minute % MINUTES_PER_DAY;
+            let period_no = minute_of_day / period_length + 1;
+            let pattern_period_end = (period_no * period_length).min(MINUTES_PER_DAY);
+            let next_minute = (minute + pattern_period_end - minute_of_day).min(end);
+            weight += flow_pattern.pattern_value(period_no as u32) * (next_minute - minute) as f64 / period_length as f64;
+            minute = next_minute;
+        }
+        weight
     }
 }
 
 impl WorkGenerator for AdvancedWorkGenerator {
     fn generate_work(&self, planner_model: &PlannerModel, job: &Job) -> WorkResults {
-        WorkResults::with_shifts(job.id(), Vec::new())
+        let mut work_contents: Vec<WorkContent> = Vec::new();
+        let mut work_content_logs: Vec<WorkContentLog> = Vec::new();
+        let mut shifts: Vec<PlannedShift> = Vec::new();
+        let dates = job.planner_settings().dates(planner_model);
+
+        for date in dates.iter().copied() {
+            let environment = planner_model.environment_for_date(date);
+
+            for shift in job.shifts_for_standard_set(planner_model.standard_set_id()) {
+                if let Some(shift_definition) = shift.shift_detail_for_date(Some(environment), date) {
+                    let mut shift_work_contents: Vec<WorkContent> = Vec::new();
+                    for standard in job.shift_related_standards_for_standard_set_and_shift(planner_model.standard_set_id(), shift) {
+                        let (standard_work_contents, log) =
+                            self.generate_work_for_standard(planner_model, job, standard, shift_definition, date);
+                        shift_work_contents.extend(standard_work_contents);
+                        work_content_logs.extend(log);
+                    }
+                    for standard in job.spread_standards_for_standard_set_and_shift(planner_model.standard_set_id(), shift) {
+                        let (standard_work_contents, log) =
+                            self.generate_work_for_spread_standard(planner_model, job, shift, shift_definition, standard, date);
+                        shift_work_contents.extend(standard_work_contents);
+                        work_content_logs.extend(log);
+                    }
+
+                    shifts.extend(self.create_planned_shifts(planner_model, job, shift, shift_definition, date, &shift_work_contents));
+                    work_contents.extend(shift_work_contents);
+                }
+            }
+        }
+
+        for standard in job.recurring_task_standards_for_standard_set(planner_model.standard_set_id()) {
+            for occurrence in self.recurrence_engine.occurrences(planner_model, standard, job, &dates) {
+                if let Some((work_content, log)) = self.create_recurring_task_work_content(planner_model, job, standard, occurrence) {
+                    shifts.push(Self::create_recurring_task_planned_shift(planner_model, &work_content));
+                    work_contents.push(work_content);
+                    work_content_logs.push(log);
+                }
+            }
+        }
+
+        WorkResults::with_shifts(job.id(), shifts)
+            .with_work_contents(work_contents)
+            .with_work_content_logs(work_content_logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::business_driver::BusinessDriverId;
+    use crate::workcontent::domain::business_driver_values::BusinessDriverValues;
+    use crate::workcontent::domain::environment_calendar::{EnvironmentCalendar, EnvironmentStat};
+    use crate::workcontent::domain::flow_plan::{FlowPattern, FlowPatternId, FlowPatternPeriod, FlowPlan, FlowPlanId};
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::job_shift::{JobShift, JobShiftId};
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planner_settings::PlannerSettings;
+    use crate::workcontent::domain::recurring_task_standard::{FrequencyType, RecurringVariableWork};
+    use crate::workcontent::domain::shift_related_standard::{ShiftRelatedRange, ShiftRelatedStandardValue};
+    use crate::workcontent::domain::dynamic_spread_value::DynamicSpreadValue;
+    use crate::workcontent::domain::spread_standard::{DynamicSpreadStandard, SpreadStandardRange, SpreadStandardType, SpreadStandardValue};
+    use crate::workcontent::domain::standard_set::StandardSetId;
+    use crate::workcontent::domain::standard_type::StandardType;
+    use crate::workcontent::domain::task_standards::{TaskStandardDetail, TaskStandardFrequency, TaskStandardRange};
+    use crate::workcontent::domain::units::Units;
+    use crate::workcontent::domain::value_type::ValueType;
+    use date_range_rs::DateRange;
+    use joda_rs::{DayOfWeek, LocalTime};
+    use std::collections::HashMap;
+
+    const MONDAY: (i32, i32, i32) = (2025, 1, 6);
+
+    fn monday() -> LocalDate {
+        LocalDate::new(MONDAY.0, MONDAY.1, MONDAY.2)
+    }
+
+    fn make_standard(standard_set_id: StandardSetId,
+                     shift_id: JobShiftId,
+                     business_driver_id: BusinessDriverId,
+                     distribution_method: DistributionMethod,
+                     flow_plan_id: Option<FlowPlanId>) -> ShiftRelatedStandard {
+        ShiftRelatedStandard {
+            job_id: JobId::new(),
+            standard_set_id,
+            shift_id,
+            business_driver_id,
+            name: "Check-ins".to_string(),
+            work_type: WorkType::Variable,
+            units: Units::MinutesPerUnit,
+            distribution_method,
+            non_flowed_distribution_method: None,
+            flow_plan_id,
+            ignore_retention: false,
+            earliest_work_start_time: Some(LocalTime::new(8, 0, 0)),
+            latest_work_end_time: Some(LocalTime::new(10, 0, 0)),
+            ranges: vec![ShiftRelatedRange::new(0, 1000, vec![ShiftRelatedStandardValue::new(Environment::new(1), 3.0)])],
+            task_standard_details: vec![],
+        }
+    }
+
+    fn advanced_settings() -> PlannerSettings {
+        let mut settings = PlannerSettings::default();
+        settings.standard_type = StandardType::ADVANCED;
+        settings.effective_dates = vec![DateRange::new(LocalDate::new(2025, 1, 1), LocalDate::new(2025, 12, 31))];
+        settings
+    }
+
+    fn make_model_and_job(distribution_method: DistributionMethod) -> (PlannerModel, Job) {
+        let standard_set_id = StandardSetId::new();
+        let business_driver_id = BusinessDriverId::new();
+
+        let shift = JobShift::new(
+            JobId::new(),
+            standard_set_id,
+            "Day".to_string(),
+            1,
+            vec![JobShiftDefinition::new(DayOfWeek::Monday, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), 0.0, 0.0, 0)],
+        );
+
+        let flow_pattern = FlowPattern::new(FlowPatternId::new(), "Morning".to_string(), vec![
+            // 30 minute periods: period 17 starts at 08:00
+            FlowPatternPeriod::new(17, 10.0),
+            FlowPatternPeriod::new(18, 20.0),
+            FlowPatternPeriod::new(19, 30.0),
+            FlowPatternPeriod::new(20, 40.0),
+        ]);
+        let flow_plan = FlowPlan::new(FlowPlanId::new(), "Front desk".to_string())
+            .with_day_of_week_patterns(HashMap::from([(DayOfWeek::Monday, flow_pattern)]));
+
+        let standard = make_standard(standard_set_id, *shift.id(), business_driver_id, distribution_method, Some(flow_plan.id()));
+
+        let job = Job::new(LocationId::new(), advanced_settings(), vec![shift], vec![])
+            .with_shift_related_standards(vec![standard]);
+
+        let planner_model = PlannerModel::new(
+            DateRange::new(monday(), monday().plus_days(1)),
+            LocationId::new(),
+            standard_set_id,
+            vec![],
+            vec![],
+            vec![BusinessDriverValues::new(business_driver_id, HashMap::from([(monday(), 100), (monday().plus_days(1), 100)]))],
+        )
+        .with_flow_plans(vec![flow_plan]);
+
+        (planner_model, job)
+    }
+
+    #[test]
+    fn flowed_standard_creates_work_content_per_period_following_flow_pattern() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        // 100 units * 3 minutes = 300 minutes over 08:00-10:00 in 10/20/30/40 proportions
+        let work_contents = results.work_contents();
+        assert_eq!(work_contents.len(), 4);
+        let hours: Vec<f64> = work_contents.iter().map(|wc| wc.calculated_hours()).collect();
+        assert_eq!(hours, vec![0.5, 1.0, 1.5, 2.0]);
+        assert_eq!(work_contents[0].calculated_start_date_time(), monday().at_time(LocalTime::new(8, 0, 0)));
+        assert_eq!(work_contents[3].calculated_end_date_time(), monday().at_time(LocalTime::new(10, 0, 0)));
+        assert!(work_contents.iter().all(|wc| wc.earliest_start_date_time() == monday().at_time(LocalTime::new(8, 0, 0))));
+        assert!(work_contents.iter().all(|wc| wc.latest_end_date_time() == monday().at_time(LocalTime::new(10, 0, 0))));
+    }
+
+    #[test]
+    fn planned_shifts_cover_the_work_of_each_shift() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        // 5 hours of work on Monday's 07:00 to 15:00 shift: one 5 hour shift from the shift start
+        let shifts = results.shifts().unwrap();
+        assert_eq!(shifts.len(), 1);
+        assert_eq!(shifts[0].start_date_time(), monday().at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(shifts[0].end_date_time(), monday().at_time(LocalTime::new(12, 0, 0)));
+        assert_eq!(shifts[0].date_shift_generated_from(), monday());
+        assert_eq!(shifts[0].job_id(), job.id());
+    }
+
+    #[test]
+    fn flowed_work_starting_off_a_period_boundary_follows_the_overlapped_pattern_periods() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        let flowed = &job.shift_related_standards()[0];
+        let mut standard = make_standard(flowed.standard_set_id, flowed.shift_id, flowed.business_driver_id, DistributionMethod::Flowed, flowed.flow_plan_id);
+        standard.earliest_work_start_time = Some(LocalTime::new(8, 15, 0));
+        standard.latest_work_end_time = Some(LocalTime::new(9, 15, 0));
+        let job = job.with_shift_related_standards(vec![standard]);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        // 08:15-08:45 is half period 17 (10) and half period 18 (20), 08:45-09:15 half 18 and
+        // half 19 (30): 300 minutes in 15/25 proportions
+        let hours: Vec<f64> = results.work_contents().iter().map(|wc| wc.calculated_hours()).collect();
+        assert_eq!(hours, vec![1.88, 3.13]);
+        assert_eq!(results.work_contents()[0].calculated_start_date_time(), monday().at_time(LocalTime::new(8, 15, 0)));
+    }
+
+    #[test]
+    fn a_window_ending_inside_a_period_ends_with_a_shorter_period() {
+        let generate = |non_flowed_distribution_method: Option<NonFlowedDistributionMethod>| {
+            let distribution_method = if non_flowed_distribution_method.is_some() { DistributionMethod::NonFlowed } else { DistributionMethod::Flowed };
+            let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+            let flowed = &job.shift_related_standards()[0];
+            let mut standard = make_standard(flowed.standard_set_id, flowed.shift_id, flowed.business_driver_id, distribution_method, flowed.flow_plan_id);
+            standard.latest_work_end_time = Some(LocalTime::new(9, 20, 0));
+            standard.non_flowed_distribution_method = non_flowed_distribution_method;
+            AdvancedWorkGenerator::new().generate_work(&planner_model, &job.with_shift_related_standards(vec![standard]))
+        };
+        let flowed = generate(None);
+        let even = generate(Some(NonFlowedDistributionMethod::EVEN));
+
+        // 08:00-08:30, 08:30-09:00 and 09:00-09:20, which has two thirds of period 19 (30)
+        let last = &flowed.work_contents()[2];
+        assert_eq!(last.calculated_start_date_time(), monday().at_time(LocalTime::new(9, 0, 0)));
+        assert_eq!(last.calculated_end_date_time(), monday().at_time(LocalTime::new(9, 20, 0)));
+        assert_eq!(last.calculated_end_date_time(), last.latest_end_date_time());
+        let hours = |results: &WorkResults| results.work_contents().iter().map(|wc| wc.calculated_hours()).collect::<Vec<_>>();
+        assert_eq!(hours(&flowed), vec![1.0, 2.0, 2.0]);
+        // 300 minutes in 30/30/20 proportions
+        assert_eq!(hours(&even), vec![1.88, 1.88, 1.25]);
+    }
+
+    #[test]
+    fn opening_standard_puts_all_work_in_first_period() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Opening);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        let work_contents = results.work_contents();
+        assert_eq!(work_contents.len(), 1);
+        assert_eq!(work_contents[0].calculated_hours(), 5.0);
+        assert_eq!(work_contents[0].calculated_start_date_time(), monday().at_time(LocalTime::new(8, 0, 0)));
+    }
+
+    #[test]
+    fn standard_values_are_for_the_environment_of_the_business_driver() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        let business_driver_id = job.shift_related_standards()[0].business_driver_id;
+        let planner_model = planner_model.with_environment_calendar(EnvironmentCalendar::new(vec![], vec![
+            EnvironmentStat::new(monday(), Some(business_driver_id), Some(Environment::new(2)), None),
+        ]));
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        assert!(results.work_contents().is_empty());
+    }
+
+    fn spread_standard(planner_model: &PlannerModel, job: &Job, spread_standard_type: SpreadStandardType, range: SpreadStandardRange) -> SpreadStandard {
+        SpreadStandard {
+            job_id: job.id(),
+            standard_set_id: planner_model.standard_set_id(),
+            shift_id: *job.shifts()[0].id(),
+            business_driver_id: *planner_model.business_driver_values().keys().next().unwrap(),
+            spread_standard_type,
+            units: None,
+            ranges: vec![range],
+        }
+    }
+
+    /// The 5 minute values of a fixed spread with the staff from each time to the next one.
+    fn spread_values(staffing: &[(u32, u32, u32)]) -> Vec<u32> {
+        let mut spread_values = vec![0; 288];
+        for &(from_hour, from_minute, staff) in staffing {
+            for value in &mut spread_values[((from_hour * 60 + from_minute) / 5) as usize..] {
+                *value = staff;
+            }
+        }
+        spread_values
+    }
+
+    #[test]
+    fn fixed_spread_standard_creates_work_content_for_staffed_periods_of_the_shift() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        // 2 staff from 06:30 to 07:30 and 1 staff from 14:45 to 15:30, around the 07:00 to 15:00 shift
+        let range = SpreadStandardRange::new(0, 1000).with_values(vec![SpreadStandardValue::new(
+            Environment::new(1),
+            spread_values(&[(6, 30, 2), (7, 30, 0), (14, 45, 1), (15, 30, 0)]),
+        )]);
+        let standard = spread_standard(&planner_model, &job, SpreadStandardType::FIXED, range);
+        let job = job.with_shift_related_standards(vec![]).with_spread_standards(vec![standard]);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        let work_contents = results.work_contents();
+        assert_eq!(work_contents.len(), 2);
+        assert_eq!(work_contents[0].calculated_hours(), 1.0);
+        assert_eq!(work_contents[0].calculated_start_date_time(), monday().at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(work_contents[1].calculated_hours(), 0.25);
+        assert_eq!(work_contents[1].calculated_start_date_time(), monday().at_time(LocalTime::new(14, 30, 0)));
+        assert_eq!(work_contents[1].calculated_end_date_time(), monday().at_time(LocalTime::new(15, 0, 0)));
+        let logs = results.work_content_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].description(), "Day");
+        assert_eq!(logs[0].details(), &[
+            ("07:00".to_string(), WorkContentLogDetail::new(60.0, "2 * 30".to_string())),
+            ("14:30".to_string(), WorkContentLogDetail::new(15.0, "0.5 * 30".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn spread_periods_are_cut_by_the_work_window() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        let shift = JobShift::new(
+            job.id(),
+            planner_model.standard_set_id(),
+            "Day".to_string(),
+            1,
+            vec![JobShiftDefinition::new(DayOfWeek::Monday, LocalTime::new(7, 15, 0), LocalTime::new(14, 0, 0), 0.0, 0.25, 0)],
+        );
+        let job = Job::new(job.property_id(), advanced_settings(), vec![shift], vec![]);
+        let range = SpreadStandardRange::new(0, 1000)
+            .with_values(vec![SpreadStandardValue::new(Environment::new(1), spread_values(&[(0, 0, 2)]))]);
+        let standard = spread_standard(&planner_model, &job, SpreadStandardType::FIXED, range);
+        let job = job.with_spread_standards(vec![standard]);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        // 07:15 to 14:15: a quarter hour period, 13 full periods and a quarter hour period
+        let work_contents = results.work_contents();
+        assert_eq!(work_contents.len(), 15);
+        assert_eq!(work_contents[0].calculated_start_date_time(), monday().at_time(LocalTime::new(7, 15, 0)));
+        assert_eq!(work_contents[0].calculated_hours(), 0.5);
+        assert_eq!(work_contents[14].calculated_end_date_time(), monday().at_time(LocalTime::new(14, 15, 0)));
+        assert_eq!(work_contents[14].calculated_hours(), 0.5);
+        assert_eq!(work_contents.iter().map(|wc| wc.calculated_hours()).sum::<f64>(), 14.0);
+    }
+
+    #[test]
+    fn dynamic_spread_follows_the_history_of_the_configured_week() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        let range = SpreadStandardRange::new(0, 1000)
+            .with_dynamic_values(vec![DynamicSpreadStandard::new(Environment::new(1), 10.0)]);
+        let standard = spread_standard(&planner_model, &job, SpreadStandardType::DYNAMIC, range);
+        let business_driver_id = standard.business_driver_id;
+        let job = job.with_shift_related_standards(vec![]).with_spread_standards(vec![standard]);
+        // 20 units in the 08:00 period one week and 40 units two weeks before Monday
+        let planner_model = planner_model.with_dynamic_spread_values(vec![
+            DynamicSpreadValue::new(ValueType::ACTUALS, business_driver_id, monday().minus_weeks(1), 17, 20),
+            DynamicSpreadValue::new(ValueType::ACTUALS, business_driver_id, monday().minus_weeks(2), 17, 40),
+        ]);
+
+        let last_week = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+        let planner_model = planner_model.with_dynamic_spread_history_weeks(2);
+        let two_weeks_ago = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+        let planner_model = planner_model.with_dynamic_spread_history_weeks(0);
+        let no_history = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        assert_eq!(last_week.work_contents().len(), 1);
+        assert_eq!(last_week.work_contents()[0].calculated_start_date_time(), monday().at_time(LocalTime::new(8, 0, 0)));
+        assert_eq!(last_week.work_contents()[0].calculated_hours(), 1.0);
+        assert_eq!(two_weeks_ago.work_contents()[0].calculated_hours(), 2.0);
+        assert!(no_history.work_contents().is_empty());
+        assert!(no_history.shifts().unwrap().is_empty());
+    }
+
+    #[test]
+    fn recurring_task_creates_work_content_per_occurrence() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        let mut standard = RecurringTaskStandard::new(
+            job.id(),
+            planner_model.standard_set_id(),
+            "Deep clean".to_string(),
+            monday(),
+            FrequencyType::DAILY,
+        );
+        standard.occurs_at_time = Some(LocalTime::new(22, 0, 0));
+        standard.fixed_hours = Some(1.5);
+        let job = job.with_shift_related_standards(vec![]).with_recurring_task_standards(vec![standard]);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        // the model covers Monday and Tuesday
+        let work_contents = results.work_contents();
+        assert_eq!(work_contents.len(), 2);
+        assert_eq!(work_contents[0].calculated_start_date_time(), monday().at_time(LocalTime::new(22, 0, 0)));
+        assert_eq!(work_contents[0].calculated_end_date_time(), monday().at_time(LocalTime::new(23, 30, 0)));
+        assert_eq!(work_contents[1].shift_date(), monday().plus_days(1));
+        assert!(work_contents.iter().all(|wc| wc.calculated_hours() == 1.5 && wc.description() == "Deep clean"));
+        let shifts = results.shifts().unwrap();
+        assert_eq!(shifts.len(), 2);
+        assert_eq!(shifts[0].start_date_time(), monday().at_time(LocalTime::new(22, 0, 0)));
+        assert_eq!(shifts[0].end_date_time(), monday().at_time(LocalTime::new(23, 30, 0)));
+        let logs = results.work_content_logs();
+        assert_eq!(logs.len(), 2);
+        assert_eq!((logs[0].shift_date(), logs[0].description()), (monday(), "Deep clean"));
+        assert_eq!(logs[0].details()[0].1, WorkContentLogDetail::new(90.0, "1.5 * 60".to_string()));
+    }
+
+    #[test]
+    fn variable_recurring_task_uses_business_driver_value() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Flowed);
+        let business_driver_id = *planner_model.business_driver_values().keys().next().unwrap();
+        let mut standard = RecurringTaskStandard::new(
+            job.id(),
+            planner_model.standard_set_id(),
+            "Turnover clean".to_string(),
+            monday(),
+            FrequencyType::WEEKLY,
+        );
+        standard.duration_type = DurationType::VARIABLE;
+        standard.business_driver_id = Some(business_driver_id);
+        // 100 units: 1 + 0.5 * (100 / 25) = 3 hours
+        standard.variable_work = vec![RecurringVariableWork::new(0, 1000, 1.0, 0.5, 25)];
+        let job = job.with_shift_related_standards(vec![]).with_recurring_task_standards(vec![standard]);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        let work_contents = results.work_contents();
+        assert_eq!(work_contents.len(), 1);
+        assert_eq!(work_contents[0].calculated_hours(), 3.0);
+        assert_eq!(work_contents[0].calculated_end_date_time(), monday().at_time(LocalTime::new(3, 0, 0)));
+    }
+
+    #[test]
+    fn task_standard_sums_frequency_minutes_and_logs_each_task() {
+        let (planner_model, job) = make_model_and_job(DistributionMethod::Opening);
+        let env = Environment::new(1);
+        let mut standard = make_standard(
+            planner_model.standard_set_id(),
+            *job.shifts()[0].id(),
+            *planner_model.business_driver_values().keys().next().unwrap(),
+            DistributionMethod::Opening,
+            None,
+        );
+        standard.work_type = WorkType::Task;
+        standard.task_standard_details = vec![
+            // 2.5 minutes * 4 items, 3 times = 30 minutes
+            TaskStandardDetail::new("Vacuum".to_string(), 2.5, 4)
+                .with_ranges(vec![(TaskStandardRange::new(0, 1000), vec![TaskStandardFrequency::new(env, 3)])]),
+            // 1.5 minutes * 10 items, twice = 30 minutes
+            TaskStandardDetail::new("Dust".to_string(), 1.5, 10)
+                .with_ranges(vec![(TaskStandardRange::new(0, 1000), vec![TaskStandardFrequency::new(env, 2)])]),
+            // no frequency for the environment
+            TaskStandardDetail::new("Windows".to_string(), 5.0, 2)
+                .with_ranges(vec![(TaskStandardRange::new(0, 1000), vec![TaskStandardFrequency::new(Environment::new(2), 1)])]),
+        ];
+        let job = job.with_shift_related_standards(vec![standard]);
+
+        let results = AdvancedWorkGenerator::new().generate_work(&planner_model, &job);
+
+        assert_eq!(results.work_contents().len(), 1);
+        assert_eq!(results.work_contents()[0].calculated_hours(), 1.0);
+        let logs = results.work_content_logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].work_in_minutes(), 60.0);
+        let tasks: Vec<&str> = logs[0].details().iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(tasks, vec!["Vacuum", "Dust"]);
+        assert_eq!(logs[0].details()[0].1.formula(), "10.00 * 3");
     }
 }
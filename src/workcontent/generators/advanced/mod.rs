@@ -1 +1,3 @@
 pub mod advanced_work_generator;
+mod work_distributor;
+mod recurrence_engine;
@@ -0,0 +1,282 @@
+use joda_rs::{DayOfWeek, LocalDate, LocalDateTime, LocalTime};
+use crate::workcontent::common::numbers::round_to_i32;
+use crate::workcontent::domain::job::Job;
+use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::domain::recurring_task_standard::{FrequencyType, MonthlyIntervalType, OccurrenceType, RecurringTaskStandard, LAST_WEEK_OF_MONTH};
+
+const MINUTES_PER_HOUR: f64 = 60.0;
+const MINUTES_PER_DAY: i64 = 1440;
+const DAYS_PER_WEEK: i64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecurrenceOccurrence {
+    date: LocalDate,
+    start_date_time: LocalDateTime,
+}
+
+impl RecurrenceOccurrence {
+    pub fn new(date: LocalDate, start_date_time: LocalDateTime) -> Self {
+        Self {
+            date,
+            start_date_time,
+        }
+    }
+    pub fn date(&self) -> LocalDate {
+        self.date
+    }
+    pub fn start_date_time(&self) -> LocalDateTime {
+        self.start_date_time
+    }
+}
+
+pub struct RecurrenceEngine;
+
+impl RecurrenceEngine {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Every occurrence of the recurring task on the dates, in date and time order.
+    pub fn occurrences(&self, planner_model: &PlannerModel, standard: &RecurringTaskStandard, job: &Job, dates: &[LocalDate]) -> Vec<RecurrenceOccurrence> {
+        dates.iter()
+            .filter(|date| Self::occurs_on(standard, **date))
+            .flat_map(|date| {
+                Self::start_times(planner_model, standard, job, *date)
+                    .into_iter()
+                    .map(|start_date_time| RecurrenceOccurrence::new(*date, start_date_time))
+            })
+            .collect()
+    }
+
+    fn occurs_on(standard: &RecurringTaskStandard, date: LocalDate) -> bool {
+        if date.is_before(standard.initial_date) {
+            return false;
+        }
+
+        match standard.frequency_type {
+            FrequencyType::DAILY => {
+                let interval = standard.daily_interval.unwrap_or(1).max(1) as i64;
+                days_between(standard.initial_date, date) % interval == 0
+            }
+            FrequencyType::WEEKLY => {
+                let interval = standard.weekly_interval.unwrap_or(1).max(1) as i64;
+                let weeks = days_between(week_start(standard.initial_date), week_start(date)) / DAYS_PER_WEEK;
+                let on_day = if standard.weekly_days_of_week.is_empty() {
+                    date.day_of_week() == standard.initial_date.day_of_week()
+                } else {
+                    standard.weekly_days_of_week.contains(&date.day_of_week())
+                };
+                weeks % interval == 0 && on_day
+            }
+            FrequencyType::MONTHLY => {
+                let in_selected_month = standard.monthly_selected_months.is_empty()
+                    || standard.monthly_selected_months.contains(&(date.month_value() as u32));
+                in_selected_month && Self::occurs_on_day_of_month(standard, date)
+            }
+        }
+    }
+
+    /// Day N of the month falls on the last day for shorter months; the Nth week day of the month
+    /// treats week 5 as the last one.
+    fn occurs_on_day_of_month(standard: &RecurringTaskStandard, date: LocalDate) -> bool {
+        match standard.monthly_interval_type.unwrap_or(MonthlyIntervalType::DayNOfEveryMonth) {
+            MonthlyIntervalType::DayNOfEveryMonth => {
+                let day_of_month = standard.monthly_day_of_month
+                    .unwrap_or(standard.initial_date.day_of_month() as u32) as i32;
+                date.day_of_month() == day_of_month.min(date.length_of_month())
+            }
+            MonthlyIntervalType::NthDayOfEveryNMonths => {
+                let day_of_week = standard.monthly_day_of_week.unwrap_or(standard.initial_date.day_of_week());
+                let nth_week = standard.monthly_every_nth_week.unwrap_or(1);
+                date == nth_day_of_week_in_month(date, day_of_week, nth_week)
+            }
+        }
+    }
+
+    fn start_times(planner_model: &PlannerModel, standard: &RecurringTaskStandard, job: &Job, date: LocalDate) -> Vec<LocalDateTime> {
+        match standard.occurrence_type {
+            OccurrenceType::SingleOccurrence => {
+                vec![date.at_time(standard.occurs_at_time.unwrap_or(LocalTime::new(0, 0, 0)))]
+            }
+            OccurrenceType::DuringShift => {
+                job.shifts().iter()
+                    .filter(|shift| Some(*shift.id()) == standard.occurs_during_shift_id)
+                    .filter_map(|shift| shift.shift_detail_for_date(Some(planner_model.environment_for_date(date)), date))
+                    .map(|shift_definition| shift_definition.start_for(date))
+                    .collect()
+            }
+            OccurrenceType::MultipleOccurrences => Self::interval_start_times(standard, date),
+        }
+    }
+
+    /// Start times every N hours from the starting time up to and including the ending time. An
+    /// ending time at or before the starting time ends on the next day; without one the
+    /// occurrences run to the end of the day.
+    fn interval_start_times(standard: &RecurringTaskStandard, date: LocalDate) -> Vec<LocalDateTime> {
+        let start = date.at_time(standard.occurs_starting_at_time.unwrap_or(LocalTime::new(0, 0, 0)));
+        let every_minutes = standard.occurs_every_n_hours
+            .map(|hours| round_to_i32(hours * MINUTES_PER_HOUR) as i64)
+            .unwrap_or(0);
+        if every_minutes <= 0 {
+            return vec![start];
+        }
+
+        let last_minute = match standard.occurs_ending_at_time {
+            Some(ending_time) => {
+                let mut end = date.at_time(ending_time);
+                if !end.is_after(start) {
+                    end = end.plus_days(1);
+                }
+                (end - start).to_minutes()
+            }
+            None => (date.plus_days(1).at_start_of_day() - start).to_minutes() - 1,
+        };
+
+        (0..=last_minute / every_minutes)
+            .map(|occurrence| start.plus_minutes(occurrence * every_minutes))
+            .collect()
+    }
+}
+
+fn days_between(from: LocalDate, to: LocalDate) -> i64 {
+    (to.at_start_of_day() - from.at_start_of_day()).to_minutes() / MINUTES_PER_DAY
+}
+
+fn week_start(date: LocalDate) -> LocalDate {
+    date.previous_or_same(DayOfWeek::Monday)
+}
+
+fn nth_day_of_week_in_month(date: LocalDate, day_of_week: DayOfWeek, nth_week: u32) -> LocalDate {
+    if nth_week >= LAST_WEEK_OF_MONTH {
+        return date.last_in_month(day_of_week);
+    }
+    date.first_in_month(day_of_week).plus_weeks(nth_week.max(1) as i64 - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::job_shift::{JobShift, JobShiftDefinition};
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planner_settings::PlannerSettings;
+    use crate::workcontent::domain::standard_set::StandardSetId;
+    use date_range_rs::DateRange;
+
+    fn dates(from: LocalDate, to: LocalDate) -> Vec<LocalDate> {
+        DateRange::new(from, to).iter().collect()
+    }
+
+    fn planner_model(dates: &[LocalDate]) -> PlannerModel {
+        PlannerModel::new(DateRange::new(dates[0], dates[dates.len() - 1]), LocationId::new(), StandardSetId::new(), vec![], vec![], vec![])
+    }
+
+    fn make_standard(initial_date: LocalDate, frequency_type: FrequencyType) -> RecurringTaskStandard {
+        RecurringTaskStandard::new(JobId::new(), StandardSetId::new(), "Deep clean".to_string(), initial_date, frequency_type)
+    }
+
+    fn occurrence_dates(standard: &RecurringTaskStandard, from: LocalDate, to: LocalDate) -> Vec<LocalDate> {
+        RecurrenceEngine::new()
+            .occurrences(&planner_model(&dates(from, to)), standard, &Job::test(), &dates(from, to))
+            .iter()
+            .map(|occurrence| occurrence.date())
+            .collect()
+    }
+
+    #[test]
+    fn daily_interval_counts_from_initial_date() {
+        let mut standard = make_standard(LocalDate::new(2025, 1, 2), FrequencyType::DAILY);
+        standard.daily_interval = Some(3);
+
+        let dates = occurrence_dates(&standard, LocalDate::new(2025, 1, 1), LocalDate::new(2025, 1, 10));
+
+        assert_eq!(dates, vec![LocalDate::new(2025, 1, 2), LocalDate::new(2025, 1, 5), LocalDate::new(2025, 1, 8)]);
+    }
+
+    #[test]
+    fn every_second_tuesday() {
+        let mut standard = make_standard(LocalDate::new(2025, 1, 7), FrequencyType::WEEKLY);
+        standard.weekly_interval = Some(2);
+        standard.weekly_days_of_week = vec![DayOfWeek::Tuesday];
+
+        let dates = occurrence_dates(&standard, LocalDate::new(2025, 1, 1), LocalDate::new(2025, 2, 5));
+
+        assert_eq!(dates, vec![LocalDate::new(2025, 1, 7), LocalDate::new(2025, 1, 21), LocalDate::new(2025, 2, 4)]);
+    }
+
+    #[test]
+    fn first_of_selected_months() {
+        let mut standard = make_standard(LocalDate::new(2025, 1, 1), FrequencyType::MONTHLY);
+        standard.monthly_interval_type = Some(MonthlyIntervalType::DayNOfEveryMonth);
+        standard.monthly_day_of_month = Some(1);
+        standard.monthly_selected_months = vec![1, 3];
+
+        let dates = occurrence_dates(&standard, LocalDate::new(2025, 1, 1), LocalDate::new(2025, 4, 30));
+
+        assert_eq!(dates, vec![LocalDate::new(2025, 1, 1), LocalDate::new(2025, 3, 1)]);
+    }
+
+    #[test]
+    fn day_of_month_past_month_end_falls_on_last_day() {
+        let mut standard = make_standard(LocalDate::new(2025, 1, 1), FrequencyType::MONTHLY);
+        standard.monthly_day_of_month = Some(31);
+
+        let dates = occurrence_dates(&standard, LocalDate::new(2025, 2, 1), LocalDate::new(2025, 3, 31));
+
+        assert_eq!(dates, vec![LocalDate::new(2025, 2, 28), LocalDate::new(2025, 3, 31)]);
+    }
+
+    #[test]
+    fn second_and_last_week_day_of_month() {
+        let mut standard = make_standard(LocalDate::new(2025, 1, 1), FrequencyType::MONTHLY);
+        standard.monthly_interval_type = Some(MonthlyIntervalType::NthDayOfEveryNMonths);
+        standard.monthly_day_of_week = Some(DayOfWeek::Tuesday);
+        standard.monthly_every_nth_week = Some(2);
+
+        let second = occurrence_dates(&standard, LocalDate::new(2025, 1, 1), LocalDate::new(2025, 1, 31));
+        standard.monthly_every_nth_week = Some(LAST_WEEK_OF_MONTH);
+        let last = occurrence_dates(&standard, LocalDate::new(2025, 1, 1), LocalDate::new(2025, 1, 31));
+
+        assert_eq!(second, vec![LocalDate::new(2025, 1, 14)]);
+        assert_eq!(last, vec![LocalDate::new(2025, 1, 28)]);
+    }
+
+    #[test]
+    fn multiple_occurrences_every_n_hours() {
+        let date = LocalDate::new(2025, 1, 6);
+        let mut standard = make_standard(date, FrequencyType::DAILY);
+        standard.occurrence_type = OccurrenceType::MultipleOccurrences;
+        standard.occurs_every_n_hours = Some(4.0);
+        standard.occurs_starting_at_time = Some(LocalTime::new(6, 0, 0));
+        standard.occurs_ending_at_time = Some(LocalTime::new(14, 0, 0));
+
+        let occurrences = RecurrenceEngine::new().occurrences(&planner_model(&[date]), &standard, &Job::test(), &[date]);
+
+        let starts: Vec<LocalDateTime> = occurrences.iter().map(|occurrence| occurrence.start_date_time()).collect();
+        assert_eq!(starts, vec![
+            date.at_time(LocalTime::new(6, 0, 0)),
+            date.at_time(LocalTime::new(10, 0, 0)),
+            date.at_time(LocalTime::new(14, 0, 0)),
+        ]);
+    }
+
+    #[test]
+    fn during_shift_starts_with_the_shift() {
+        let date = LocalDate::new(2025, 1, 6);
+        let shift = JobShift::new(
+            JobId::new(),
+            StandardSetId::new(),
+            "Day".to_string(),
+            1,
+            vec![JobShiftDefinition::new(DayOfWeek::Monday, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), 0.0, 0.0, 0)],
+        );
+        let mut standard = make_standard(date, FrequencyType::DAILY);
+        standard.occurrence_type = OccurrenceType::DuringShift;
+        standard.occurs_during_shift_id = Some(*shift.id());
+        let job = Job::new(LocationId::new(), PlannerSettings::default(), vec![shift], vec![]);
+
+        let occurrences = RecurrenceEngine::new().occurrences(&planner_model(&[date]), &standard, &job, &[date, date.plus_days(1)]);
+
+        assert_eq!(occurrences, vec![RecurrenceOccurrence::new(date, date.at_time(LocalTime::new(7, 0, 0)))]);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::workcontent::common::numbers::round_raw_hours;
+use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+
+pub struct WorkDistributor;
+
+impl WorkDistributor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Distributes the work over the periods in proportion to the weight of each period.
+    /// Falls back to an even distribution when the periods have no weight.
+    pub fn distribute_flowed(&self, work_in_minutes: f64, weights: &[f64]) -> Vec<f64> {
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            return self.distribute_non_flowed(work_in_minutes, weights.len(), NonFlowedDistributionMethod::EVEN);
+        }
+
+        let minutes = weights.iter()
+            .map(|weight| round_raw_hours(work_in_minutes * weight / total_weight))
+            .collect();
+
+        Self::assign_rounding_difference(work_in_minutes, minutes)
+    }
+
+    /// Distributes the work over the periods without a flow pattern. BEGINNING, MIDDLE and END put
+    /// all the work in the first, middle or last period. EVEN and VARYING spread it evenly.
+    pub fn distribute_non_flowed(&self,
+                                 work_in_minutes: f64,
+                                 number_of_periods: usize,
+                                 method: NonFlowedDistributionMethod) -> Vec<f64> {
+        let mut minutes = vec![0.0; number_of_periods];
+        if number_of_periods == 0 {
+            return minutes;
+        }
+
+        match method {
+            NonFlowedDistributionMethod::BEGINNING => minutes[0] = work_in_minutes,
+            NonFlowedDistributionMethod::MIDDLE => minutes[(number_of_periods - 1) / 2] = work_in_minutes,
+            NonFlowedDistributionMethod::END => minutes[number_of_periods - 1] = work_in_minutes,
+            NonFlowedDistributionMethod::EVEN | NonFlowedDistributionMethod::VARYING => {
+                let per_period = round_raw_hours(work_in_minutes / number_of_periods as f64);
+                minutes.iter_mut().for_each(|period_minutes| *period_minutes = per_period);
+                return Self::assign_rounding_difference(work_in_minutes, minutes);
+            }
+        }
+
+        minutes
+    }
+
+    /// Adds any difference caused by rounding to the last period with work, so the periods always
+    /// add up to the total work.
+    fn assign_rounding_difference(work_in_minutes: f64, mut minutes: Vec<f64>) -> Vec<f64> {
+        let difference = round_raw_hours(work_in_minutes - minutes.iter().sum::<f64>());
+        if difference != 0.0
+            && let Some(last) = minutes.iter_mut().rev().find(|period_minutes| **period_minutes > 0.0) {
+            *last = round_raw_hours(*last + difference);
+        }
+        minutes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn flowed_distributes_by_weight() {
+        let minutes = WorkDistributor::new().distribute_flowed(120.0, &[10.0, 30.0, 0.0, 60.0]);
+        assert_eq!(minutes, vec![12.0, 36.0, 0.0, 72.0]);
+    }
+
+    #[test]
+    fn flowed_keeps_total_when_rounding() {
+        let minutes = WorkDistributor::new().distribute_flowed(100.0, &[1.0, 1.0, 1.0]);
+        assert_eq!(minutes, vec![33.33, 33.33, 33.34]);
+    }
+
+    #[test]
+    fn flowed_without_weights_is_even() {
+        let minutes = WorkDistributor::new().distribute_flowed(90.0, &[0.0, 0.0, 0.0]);
+        assert_eq!(minutes, vec![30.0, 30.0, 30.0]);
+    }
+
+    #[rstest]
+    #[case(NonFlowedDistributionMethod::BEGINNING, vec![60.0, 0.0, 0.0, 0.0])]
+    #[case(NonFlowedDistributionMethod::MIDDLE, vec![0.0, 60.0, 0.0, 0.0])]
+    #[case(NonFlowedDistributionMethod::END, vec![0.0, 0.0, 0.0, 60.0])]
+    #[case(NonFlowedDistributionMethod::EVEN, vec![15.0, 15.0, 15.0, 15.0])]
+    #[case(NonFlowedDistributionMethod::VARYING, vec![15.0, 15.0, 15.0, 15.0])]
+    fn non_flowed_distribution(#[case] method: NonFlowedDistributionMethod, #[case] expected: Vec<f64>) {
+        let minutes = WorkDistributor::new().distribute_non_flowed(60.0, 4, method);
+        assert_eq!(minutes, expected);
+    }
+
+    #[test]
+    fn non_flowed_with_no_periods_is_empty() {
+        let minutes = WorkDistributor::new().distribute_non_flowed(60.0, 0, NonFlowedDistributionMethod::EVEN);
+        assert!(minutes.is_empty());
+    }
+}
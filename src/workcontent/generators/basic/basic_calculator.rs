@@ -6,6 +6,7 @@ use crate::workcontent::domain::planner_settings::PlannerSettings;
 const MINUTES_PER_HOUR: f64 = 60.0;
 
 pub struct BasicCalculationResult {
+    pub shift_length: f64,
     pub number_of_full_time_shifts: i32,
     pub remaining_work_hours: f64,
     pub work_hours_to_cover_breaks: f64,
@@ -28,7 +29,7 @@ impl BasicCalculator {
         let break_hours_per_full_shift = shift_length - productive_full_shift_hours;
 
         let total_work_hours = round_hours(total_work_minutes as f64 / MINUTES_PER_HOUR);
-        let full_time_shifts = truncate((total_work_hours / productive_full_shift_hours) as f64);
+        let full_time_shifts = truncate(total_work_hours / productive_full_shift_hours);
         let paid_breaks_for_full_time_shifts = full_time_shifts as f64 * break_hours_per_full_shift;
 
         let remaining_work_hours = self.get_remaining_work_hours(
@@ -49,6 +50,7 @@ impl BasicCalculator {
         };
 
         BasicCalculationResult {
+            shift_length,
             number_of_full_time_shifts: full_time_shifts,
             remaining_work_hours: remaining_work_hours + paid_breaks_for_remaining_work,
             work_hours_to_cover_breaks: paid_breaks_for_full_time_shifts + paid_breaks_for_remaining_work,
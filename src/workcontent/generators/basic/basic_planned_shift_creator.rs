@@ -1,24 +1,154 @@
+use joda_rs::LocalDateTime;
+use crate::workcontent::common::date_times::{minus_hours, plus_hours};
+use crate::workcontent::domain::planned_shift::PlannedShift;
+use crate::workcontent::domain::shift_category::ShiftCategoryId;
+use crate::workcontent::domain::work_content::WorkContent;
+use crate::workcontent::generators::basic::basic_calculator::BasicCalculationResult;
+
 pub struct BasicPlannedShiftCreator;
 
 impl BasicPlannedShiftCreator {
     pub fn new() -> Self { Self }
 
-    pub fn create_planned_shifts_from_work_contents(&self, work_contents: &[String]) -> Vec<String> {
-        let mut planned_shifts = Vec::with_capacity(work_contents.len());
+    /// Creates the planned shifts for each work content: one full length shift for each full time
+    /// shift in the calculation, plus one shift for the remaining work hours (if any). Each shift
+    /// covers the work content it was created for.
+    pub fn create_planned_shifts_from_work_contents(&self,
+                                                    work_contents: &[WorkContent],
+                                                    calculation: &BasicCalculationResult,
+                                                    shift_category_id: Option<ShiftCategoryId>) -> Vec<PlannedShift> {
+        let mut planned_shifts = Vec::new();
 
         for wc in work_contents {
-            planned_shifts.push(Self::create_planned_shift(wc));
+            for _ in 0..calculation.number_of_full_time_shifts {
+                planned_shifts.push(Self::create_planned_shift(wc, calculation.shift_length, shift_category_id));
+            }
+
+            if calculation.remaining_work_hours > 0.0 {
+                planned_shifts.push(Self::create_planned_shift(wc, calculation.remaining_work_hours, shift_category_id));
+            }
         }
 
         planned_shifts
     }
 
-    fn create_planned_shift(work_content_marker: &str) -> String {
-        format!("PLANNED:{}", work_content_marker)
+    fn create_planned_shift(work_content: &WorkContent,
+                            hours: f64,
+                            shift_category_id: Option<ShiftCategoryId>) -> PlannedShift {
+        let (start, end) = Self::shift_window(work_content, hours);
+
+        PlannedShift::new(
+            work_content.property_id(),
+            work_content.job_id(),
+            work_content.shift_type(),
+            start.to_local_date(),
+            start,
+            end,
+        )
+        .with_date_shift_generated_from(work_content.shift_date())
+        .with_shift_category_id(shift_category_id)
+        .with_work_content_ids(vec![work_content.id()])
+    }
+
+    /// Shifts start at the preferred start. A shift that would run past the latest end is moved
+    /// earlier to end at the latest end, but never earlier than the earliest start.
+    fn shift_window(work_content: &WorkContent, hours: f64) -> (LocalDateTime, LocalDateTime) {
+        let mut start = work_content.preferred_start_date_time();
+
+        if plus_hours(start, hours).is_after(work_content.latest_end_date_time()) {
+            start = minus_hours(work_content.latest_end_date_time(), hours);
+            if start.is_before(work_content.earliest_start_date_time()) {
+                start = work_content.earliest_start_date_time();
+            }
+        }
+
+        (start, plus_hours(start, hours))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::BasicPlannedShiftCreator;
+    use super::*;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+    use joda_rs::{LocalDate, LocalTime};
+    use rust_decimal::Decimal;
+
+    fn make_work_content(date: LocalDate, earliest: LocalTime, preferred: LocalTime, latest: LocalTime) -> WorkContent {
+        let earliest = date.at_time(earliest);
+        let preferred = date.at_time(preferred);
+        let latest = date.at_time(latest);
+        WorkContent::new(
+            JobId::new(), LocationId::new(), PlannedShiftType::Projected, date,
+            earliest, preferred, latest, preferred, latest, 0.0, 0.0, false, String::new(), 0, 0, preferred,
+        )
+    }
+
+    fn calculation(full_shifts: i32, remaining_work_hours: f64) -> BasicCalculationResult {
+        BasicCalculationResult {
+            shift_length: 8.0,
+            number_of_full_time_shifts: full_shifts,
+            remaining_work_hours,
+            work_hours_to_cover_breaks: 0.0,
+        }
+    }
+
+    #[test]
+    fn creates_full_shifts_and_remainder_anchored_at_preferred_start() {
+        let date = LocalDate::new(2025, 1, 6);
+        let work_content = make_work_content(date, LocalTime::new(6, 0, 0), LocalTime::new(7, 0, 0), LocalTime::new(16, 0, 0));
+
+        let planned_shifts = BasicPlannedShiftCreator::new()
+            .create_planned_shifts_from_work_contents(std::slice::from_ref(&work_content), &calculation(2, 4.5), None);
+
+        assert_eq!(planned_shifts.len(), 3);
+        for planned_shift in &planned_shifts[..2] {
+            assert_eq!(planned_shift.start_date_time(), date.at_time(LocalTime::new(7, 0, 0)));
+            assert_eq!(planned_shift.end_date_time(), date.at_time(LocalTime::new(15, 0, 0)));
+            assert_eq!(planned_shift.duration(), Decimal::from(8));
+        }
+        assert_eq!(planned_shifts[2].start_date_time(), date.at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(planned_shifts[2].end_date_time(), date.at_time(LocalTime::new(11, 30, 0)));
+        assert_eq!(planned_shifts[2].duration(), Decimal::new(45, 1));
+        assert!(planned_shifts.iter().all(|ps| ps.job_id() == work_content.job_id()));
+    }
+
+    #[test]
+    fn no_remainder_shift_when_no_remaining_work() {
+        let date = LocalDate::new(2025, 1, 6);
+        let work_content = make_work_content(date, LocalTime::new(6, 0, 0), LocalTime::new(7, 0, 0), LocalTime::new(16, 0, 0));
+
+        let planned_shifts = BasicPlannedShiftCreator::new()
+            .create_planned_shifts_from_work_contents(&[work_content], &calculation(1, 0.0), None);
+
+        assert_eq!(planned_shifts.len(), 1);
+    }
+
+    #[test]
+    fn shift_is_moved_earlier_to_end_at_latest_end() {
+        let date = LocalDate::new(2025, 1, 6);
+        let work_content = make_work_content(date, LocalTime::new(5, 0, 0), LocalTime::new(7, 0, 0), LocalTime::new(13, 0, 0));
+
+        let planned_shifts = BasicPlannedShiftCreator::new()
+            .create_planned_shifts_from_work_contents(&[work_content], &calculation(1, 0.0), None);
+
+        assert_eq!(planned_shifts[0].start_date_time(), date.at_time(LocalTime::new(5, 0, 0)));
+        assert_eq!(planned_shifts[0].end_date_time(), date.at_time(LocalTime::new(13, 0, 0)));
+    }
+
+    #[test]
+    fn sets_date_generated_from_and_shift_category() {
+        let date = LocalDate::new(2025, 1, 6);
+        let work_content = make_work_content(date, LocalTime::new(0, 0, 0), LocalTime::new(22, 0, 0), LocalTime::new(23, 0, 0));
+        let shift_category_id = ShiftCategoryId::new();
+
+        let planned_shifts = BasicPlannedShiftCreator::new()
+            .create_planned_shifts_from_work_contents(&[work_content], &calculation(0, 4.0), Some(shift_category_id));
+
+        assert_eq!(planned_shifts.len(), 1);
+        assert_eq!(planned_shifts[0].date_shift_generated_from(), date);
+        assert_eq!(planned_shifts[0].shift_date(), date);
+        assert_eq!(planned_shifts[0].shift_category_id(), Some(shift_category_id));
+    }
 }
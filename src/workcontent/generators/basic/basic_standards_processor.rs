@@ -1,10 +1,198 @@
-pub struct BasicStandardsProcessor;
+use joda_rs::LocalDate;
+use crate::workcontent::common::numbers::round_to_i32;
+use crate::workcontent::domain::basic_standard::BasicStandard;
+use crate::workcontent::domain::job::Job;
+use crate::workcontent::domain::job_shift::JobShift;
+use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::generators::calculator::work_content_log::{WorkContentLog, WorkContentLogDetail};
+use crate::workcontent::generators::calculator::work_per_unit_calculator::WorkPerUnitCalculator;
+
+pub struct BasicStandardsProcessor {
+    work_per_unit_calculator: WorkPerUnitCalculator,
+}
 
 impl BasicStandardsProcessor {
-    pub fn new() -> Self { Self }
+    pub fn new() -> Self {
+        Self {
+            work_per_unit_calculator: WorkPerUnitCalculator::new(),
+        }
+    }
+
+    /// Total minutes of work required by the job's basic standards for the shift on the date, with
+    /// the log of how the work of each standard was calculated. There is no log when no standard
+    /// has work.
+    pub fn process_standards(&self,
+                             planner_model: &PlannerModel,
+                             job: &Job,
+                             shift: &JobShift,
+                             date: LocalDate) -> (i32, Option<WorkContentLog>) {
+        let details = self.calculate_work_for_standards(planner_model, job, shift, date);
+        if details.is_empty() {
+            return (0, None);
+        }
+
+        let log = WorkContentLog::new(job.id(), date, shift.name().to_string(), details);
+        (round_to_i32(log.work_in_minutes()), Some(log))
+    }
+
+    fn calculate_work_for_standards(&self,
+                                    planner_model: &PlannerModel,
+                                    job: &Job,
+                                    shift: &JobShift,
+                                    date: LocalDate) -> Vec<(String, WorkContentLogDetail)> {
+        let shift_length = shift.shift_detail_for_date(Some(planner_model.environment_for_date(date)), date)
+            .map(|shift_definition| shift_definition.shift_length())
+            .unwrap_or(0.0);
 
-    pub fn process_standards(&self) {
+        job.basic_standards_for_standard_set_and_shift(planner_model.standard_set_id(), shift)
+            .into_iter()
+            .filter_map(|standard| self.calculate_work_for_standard(planner_model, standard, date, shift_length)
+                .map(|detail| (Self::detail_name(planner_model, standard), detail)))
+            .collect()
+    }
+
+    /// Standards are logged by the name of their business driver, or by their units without one.
+    fn detail_name(planner_model: &PlannerModel, standard: &BasicStandard) -> String {
+        standard.business_driver_id
+            .and_then(|business_driver_id| planner_model.business_drivers().iter()
+                .find(|business_driver| business_driver.id() == business_driver_id))
+            .map(|business_driver| business_driver.name().to_string())
+            .unwrap_or_else(|| format!("{:?}", standard.units))
+    }
+
+    fn calculate_work_for_standard(&self,
+                                   planner_model: &PlannerModel,
+                                   standard: &BasicStandard,
+                                   date: LocalDate,
+                                   shift_length: f64) -> Option<WorkContentLogDetail> {
+        let business_driver_value = standard.business_driver_id
+            .map(|business_driver_id| planner_model.value_for(business_driver_id, date))
+            .unwrap_or(0);
 
+        self.work_per_unit_calculator.calculate(
+            standard.units,
+            standard.standard_value,
+            business_driver_value as i32,
+            shift_length,
+        )
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::business_driver::{BusinessDriver, BusinessDriverId};
+    use crate::workcontent::domain::business_driver_values::BusinessDriverValues;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::job_shift::JobShiftDefinition;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planner_settings::PlannerSettings;
+    use crate::workcontent::domain::standard_set::StandardSetId;
+    use crate::workcontent::domain::units::Units;
+    use date_range_rs::DateRange;
+    use joda_rs::{DayOfWeek, LocalTime};
+    use std::collections::HashMap;
+
+    fn make_shift(standard_set_id: StandardSetId) -> JobShift {
+        JobShift::new(
+            JobId::new(),
+            standard_set_id,
+            "Day".to_string(),
+            1,
+            vec![JobShiftDefinition::new(DayOfWeek::Monday, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), 0.0, 0.0, 0)],
+        )
+    }
+
+    fn make_standard(standard_set_id: StandardSetId, shift: &JobShift, business_driver_id: Option<BusinessDriverId>, units: Units, standard_value: f64) -> BasicStandard {
+        BasicStandard {
+            job_id: JobId::new(),
+            standard_set_id,
+            shift_id: *shift.id(),
+            business_driver_id,
+            units,
+            standard_value,
+        }
+    }
+
+    #[test]
+    fn sums_work_for_all_standards_of_the_shift() {
+        let standard_set_id = StandardSetId::new();
+        let business_driver_id = BusinessDriverId::new();
+        let shift = make_shift(standard_set_id);
+        let standards = vec![
+            // 120 rooms at 30 minutes per room = 3600 minutes
+            make_standard(standard_set_id, &shift, Some(business_driver_id), Units::MinutesPerUnit, 30.0),
+            // fixed 2 hours = 120 minutes
+            make_standard(standard_set_id, &shift, None, Units::Hours, 2.0),
+            // belongs to another standard set
+            make_standard(StandardSetId::new(), &shift, None, Units::Hours, 100.0),
+        ];
+        let job = Job::new(LocationId::new(), PlannerSettings::default(), vec![shift], vec![])
+            .with_basic_standards(standards);
+        let planner_model = PlannerModel::new(
+            DateRange::new(LocalDate::new(2025, 1, 6), LocalDate::new(2025, 1, 6)),
+            LocationId::new(),
+            standard_set_id,
+            vec![],
+            vec![BusinessDriver::new(business_driver_id, LocationId::new()).with_name("Occupied rooms".to_string())],
+            vec![BusinessDriverValues::new(business_driver_id, HashMap::from([(LocalDate::new(2025, 1, 6), 120)]))],
+        );
+
+        let (minutes, log) = BasicStandardsProcessor::new()
+            .process_standards(&planner_model, &job, &job.shifts()[0], LocalDate::new(2025, 1, 6));
+
+        assert_eq!(minutes, 3720);
+        let log = log.unwrap();
+        assert_eq!((log.job_id(), log.shift_date(), log.description()), (job.id(), LocalDate::new(2025, 1, 6), "Day"));
+        assert_eq!(log.details(), &[
+            ("Occupied rooms".to_string(), WorkContentLogDetail::new(3600.0, "30 * 120".to_string())),
+            ("Hours".to_string(), WorkContentLogDetail::new(120.0, "2 * 60".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn units_per_shift_uses_shift_length_for_the_date() {
+        let standard_set_id = StandardSetId::new();
+        let business_driver_id = BusinessDriverId::new();
+        let shift = make_shift(standard_set_id);
+        let standards = vec![
+            // 100 units / 50 units per shift * 8 hour shift * 60 = 960 minutes
+            make_standard(standard_set_id, &shift, Some(business_driver_id), Units::UnitsPerShift, 50.0),
+        ];
+        let job = Job::new(LocationId::new(), PlannerSettings::default(), vec![shift], vec![])
+            .with_basic_standards(standards);
+        let planner_model = PlannerModel::new(
+            DateRange::new(LocalDate::new(2025, 1, 6), LocalDate::new(2025, 1, 6)),
+            LocationId::new(),
+            standard_set_id,
+            vec![],
+            vec![],
+            vec![BusinessDriverValues::new(business_driver_id, HashMap::from([(LocalDate::new(2025, 1, 6), 100)]))],
+        );
+
+        let (minutes, _) = BasicStandardsProcessor::new()
+            .process_standards(&planner_model, &job, &job.shifts()[0], LocalDate::new(2025, 1, 6));
+
+        assert_eq!(minutes, 960);
+    }
+
+    #[test]
+    fn no_standards_means_no_work() {
+        let standard_set_id = StandardSetId::new();
+        let job = Job::new(LocationId::new(), PlannerSettings::default(), vec![make_shift(standard_set_id)], vec![]);
+        let planner_model = PlannerModel::new(
+            DateRange::new(LocalDate::new(2025, 1, 6), LocalDate::new(2025, 1, 6)),
+            LocationId::new(),
+            standard_set_id,
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        let (minutes, log) = BasicStandardsProcessor::new()
+            .process_standards(&planner_model, &job, &job.shifts()[0], LocalDate::new(2025, 1, 6));
+
+        assert_eq!(minutes, 0);
+        assert!(log.is_none());
+    }
+}
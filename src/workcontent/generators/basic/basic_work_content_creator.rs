@@ -0,0 +1,112 @@
+use joda_rs::LocalDate;
+use crate::workcontent::common::date_times::plus_hours;
+use crate::workcontent::domain::job::Job;
+use crate::workcontent::domain::job_shift::{JobShift, JobShiftDefinition};
+use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+use crate::workcontent::domain::work_content::WorkContent;
+use crate::workcontent::generators::basic::basic_calculator::BasicCalculationResult;
+
+pub struct BasicWorkContentCreator;
+
+impl BasicWorkContentCreator {
+    pub fn new() -> Self { Self }
+
+    /// Creates the work content covering all work for the shift on the date. The window runs from
+    /// `hours_before` the shift start to `hours_after` the shift end, the next day for overnight
+    /// shifts, with the shift start as the preferred start.
+    pub fn create_work_content(&self,
+                               job: &Job,
+                               shift: &JobShift,
+                               shift_definition: &JobShiftDefinition,
+                               date: LocalDate,
+                               calculation: &BasicCalculationResult) -> WorkContent {
+        let start = shift_definition.start_for(date);
+        let total_hours = calculation.number_of_full_time_shifts as f64 * calculation.shift_length
+            + calculation.remaining_work_hours;
+        let longest_shift = if calculation.number_of_full_time_shifts > 0 {
+            calculation.shift_length
+        } else {
+            calculation.remaining_work_hours
+        };
+
+        WorkContent::new(
+            job.id(),
+            job.property_id(),
+            PlannedShiftType::Projected,
+            date,
+            shift_definition.earliest_start_for(date),
+            start,
+            shift_definition.latest_end_for(date),
+            start,
+            plus_hours(start, longest_shift),
+            total_hours,
+            total_hours,
+            false,
+            shift.name().to_string(),
+            0,
+            0,
+            start,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::{DayOfWeek, LocalTime};
+    use crate::workcontent::domain::standard_set::StandardSetId;
+
+    fn make_shift() -> JobShift {
+        JobShift::new(
+            crate::workcontent::domain::job::JobId::new(),
+            StandardSetId::new(),
+            "Day".to_string(),
+            1,
+            vec![JobShiftDefinition::new(DayOfWeek::Monday, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), 1.0, 2.0, 0)],
+        )
+    }
+
+    #[test]
+    fn work_content_covers_full_shifts_plus_remainder() {
+        let creator = BasicWorkContentCreator::new();
+        let job = Job::test();
+        let shift = make_shift();
+        let date = LocalDate::new(2025, 1, 6);
+        let calculation = BasicCalculationResult {
+            shift_length: 8.0,
+            number_of_full_time_shifts: 2,
+            remaining_work_hours: 4.5,
+            work_hours_to_cover_breaks: 0.0,
+        };
+
+        let work_content = creator.create_work_content(&job, &shift, &shift.shift_definitions()[0], date, &calculation);
+
+        assert_eq!(work_content.calculated_hours(), 20.5);
+        assert_eq!(work_content.adjusted_hours(), 20.5);
+        assert_eq!(work_content.calculated_start_date_time(), date.at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(work_content.calculated_end_date_time(), date.at_time(LocalTime::new(15, 0, 0)));
+    }
+
+    #[test]
+    fn work_window_uses_hours_before_and_after() {
+        let creator = BasicWorkContentCreator::new();
+        let job = Job::test();
+        let shift = make_shift();
+        let date = LocalDate::new(2025, 1, 6);
+        let calculation = BasicCalculationResult {
+            shift_length: 8.0,
+            number_of_full_time_shifts: 1,
+            remaining_work_hours: 0.0,
+            work_hours_to_cover_breaks: 0.0,
+        };
+
+        let work_content = creator.create_work_content(&job, &shift, &shift.shift_definitions()[0], date, &calculation);
+
+        assert_eq!(work_content.job_id(), job.id());
+        assert_eq!(work_content.shift_date(), date);
+        assert_eq!(work_content.earliest_start_date_time(), date.at_time(LocalTime::new(6, 0, 0)));
+        assert_eq!(work_content.preferred_start_date_time(), date.at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(work_content.latest_end_date_time(), date.at_time(LocalTime::new(17, 0, 0)));
+        assert_eq!(work_content.description(), "Day");
+    }
+}
@@ -1,39 +1,208 @@
 use joda_rs::LocalDate;
 use crate::workcontent::domain::job::Job;
-use crate::workcontent::domain::job_shift::JobShift;
+use crate::workcontent::domain::job_shift::{JobShift, JobShiftDefinition};
 use crate::workcontent::domain::planned_shift::PlannedShift;
 use crate::workcontent::domain::planner_model::PlannerModel;
 use crate::workcontent::domain::standard_type::StandardType::BASIC;
+use crate::workcontent::domain::work_content::WorkContent;
+use crate::workcontent::generators::basic::basic_calculator::BasicCalculator;
+use crate::workcontent::generators::basic::basic_planned_shift_creator::BasicPlannedShiftCreator;
 use crate::workcontent::generators::basic::basic_standards_processor::BasicStandardsProcessor;
+use crate::workcontent::generators::basic::basic_work_content_creator::BasicWorkContentCreator;
+use crate::workcontent::generators::calculator::work_content_log::WorkContentLog;
 use crate::workcontent::generators::work_generators::{WorkGenerator, WorkResults};
 
 pub struct BasicWorkGenerator {
-    standards_processor: BasicStandardsProcessor
+    standards_processor: BasicStandardsProcessor,
+    calculator: BasicCalculator,
+    work_content_creator: BasicWorkContentCreator,
+    planned_shift_creator: BasicPlannedShiftCreator,
 }
 
 impl BasicWorkGenerator {
     pub fn new() -> Self {
         Self {
-            standards_processor: BasicStandardsProcessor::new()
+            standards_processor: BasicStandardsProcessor::new(),
+            calculator: BasicCalculator::new(),
+            work_content_creator: BasicWorkContentCreator::new(),
+            planned_shift_creator: BasicPlannedShiftCreator::new(),
         }
     }
 
-    fn has_shift_on_date(shift: &JobShift, date: LocalDate) -> bool {
-        shift.shift_detail_for_date(date).is_some()
+    fn generate_work_for_shift(&self,
+                               planner_model: &PlannerModel,
+                               job: &Job,
+                               shift: &JobShift,
+                               shift_definition: &JobShiftDefinition,
+                               date: LocalDate,
+                               total_work_minutes: i32) -> (WorkContent, Vec<PlannedShift>) {
+        let calculation = self.calculator.calculate(
+            job.planner_settings(),
+            shift_definition.shift_length(),
+            total_work_minutes,
+        );
+
+        let work_content = self.work_content_creator.create_work_content(job, shift, shift_definition, date, &calculation);
+        let planned_shifts = self.planned_shift_creator.create_planned_shifts_from_work_contents(
+            std::slice::from_ref(&work_content),
+            &calculation,
+            planner_model.default_shift_category_id(),
+        );
+
+        (work_content, planned_shifts)
     }
 }
 
 impl WorkGenerator for BasicWorkGenerator {
     fn generate_work(&self, planner_model: &PlannerModel, job: &Job) -> WorkResults {
-        let shifts: Vec<PlannedShift> = Vec::new();
+        let mut work_contents: Vec<WorkContent> = Vec::new();
+        let mut shifts: Vec<PlannedShift> = Vec::new();
+        let mut work_content_logs: Vec<WorkContentLog> = Vec::new();
 
         for date in job.planner_settings().dates(planner_model) {
             for shift in job.shifts_for_standard_set(planner_model.standard_set_id()) {
-                if Self::has_shift_on_date(shift, date) {
+                if let Some(shift_definition) = shift.shift_detail_for_date(Some(planner_model.environment_for_date(date)), date) {
+                    let (total_work_minutes, log) = self.standards_processor.process_standards(planner_model, job, shift, date);
+                    let (work_content, planned_shifts) =
+                        self.generate_work_for_shift(planner_model, job, shift, shift_definition, date, total_work_minutes);
+
+                    work_contents.push(work_content);
+                    shifts.extend(planned_shifts);
+                    work_content_logs.extend(log);
                 }
             }
         }
 
         WorkResults::with_shifts(job.id(), shifts)
+            .with_work_contents(work_contents)
+            .with_work_content_logs(work_content_logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::basic_standard::BasicStandard;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planner_settings::PlannerSettings;
+    use crate::workcontent::domain::shift_category::ShiftCategoryId;
+    use crate::workcontent::domain::standard_set::StandardSetId;
+    use crate::workcontent::domain::units::Units;
+    use date_range_rs::DateRange;
+    use joda_rs::{DayOfWeek, LocalTime};
+    use rust_decimal::Decimal;
+
+    fn make_job(standard_set_id: StandardSetId) -> Job {
+        let mut settings = PlannerSettings::default();
+        settings.standard_type = BASIC;
+        settings.effective_dates = vec![DateRange::new(LocalDate::new(2025, 1, 1), LocalDate::new(2025, 12, 31))];
+
+        let shift = JobShift::new(
+            JobId::new(),
+            standard_set_id,
+            "Day".to_string(),
+            1,
+            vec![JobShiftDefinition::new(DayOfWeek::Monday, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), 0.0, 0.0, 0)],
+        );
+
+        Job::new(LocationId::new(), settings, vec![shift], vec![])
+    }
+
+    fn make_planner_model(standard_set_id: StandardSetId) -> PlannerModel {
+        PlannerModel::new(
+            DateRange::new(LocalDate::new(2025, 1, 1), LocalDate::new(2025, 1, 7)),
+            LocationId::new(),
+            standard_set_id,
+            vec![],
+            vec![],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn generate_work_for_shift_creates_full_and_remainder_shifts() {
+        let generator = BasicWorkGenerator::new();
+        let standard_set_id = StandardSetId::new();
+        let shift_category_id = ShiftCategoryId::new();
+        let planner_model = make_planner_model(standard_set_id).with_default_shift_category_id(shift_category_id);
+        let job = make_job(standard_set_id);
+        let shift = &job.shifts()[0];
+        let date = LocalDate::new(2025, 1, 6);
+
+        // 20 hours of work on an 8 hour shift -> 2 full shifts and a 4 hour remainder
+        let (work_content, planned_shifts) =
+            generator.generate_work_for_shift(&planner_model, &job, shift, &shift.shift_definitions()[0], date, 20 * 60);
+
+        assert_eq!(work_content.calculated_hours(), 20.0);
+        assert_eq!(work_content.shift_date(), date);
+        assert_eq!(planned_shifts.len(), 3);
+        assert_eq!(planned_shifts.iter().map(|ps| ps.duration()).sum::<Decimal>(), Decimal::from(20));
+        assert!(planned_shifts.iter().all(|ps| ps.date_shift_generated_from() == date));
+        assert!(planned_shifts.iter().all(|ps| ps.shift_category_id() == Some(shift_category_id)));
+    }
+
+    #[test]
+    fn basic_generator_returns_shifts_for_job() {
+        let generator = BasicWorkGenerator::new();
+        let standard_set_id = StandardSetId::new();
+        let planner_model = make_planner_model(standard_set_id);
+        let job = make_job(standard_set_id);
+        let standard = BasicStandard {
+            job_id: job.id(),
+            standard_set_id,
+            shift_id: *job.shifts()[0].id(),
+            business_driver_id: None,
+            units: Units::Hours,
+            standard_value: 20.0,
+        };
+        let job = job.with_basic_standards(vec![standard]);
+
+        let results = generator.generate_work(&planner_model, &job);
+
+        // 20 hours on the 07:00 to 15:00 Monday shift: two full shifts and a 4 hour remainder
+        let monday = LocalDate::new(2025, 1, 6);
+        let shifts = results.shifts().unwrap();
+        assert_eq!(results.job_id(), job.id());
+        assert!(results.labor_data().is_none());
+        assert_eq!(shifts.len(), 3);
+        for shift in &shifts[..2] {
+            assert_eq!(shift.start_date_time(), monday.at_time(LocalTime::new(7, 0, 0)));
+            assert_eq!(shift.end_date_time(), monday.at_time(LocalTime::new(15, 0, 0)));
+            assert_eq!(shift.duration(), Decimal::from(8));
+        }
+        assert_eq!(shifts[2].start_date_time(), monday.at_time(LocalTime::new(7, 0, 0)));
+        assert_eq!(shifts[2].end_date_time(), monday.at_time(LocalTime::new(11, 0, 0)));
+        assert_eq!(shifts[2].duration(), Decimal::from(4));
+    }
+
+    #[test]
+    fn basic_generator_creates_shifts_from_basic_standards() {
+        let generator = BasicWorkGenerator::new();
+        let standard_set_id = StandardSetId::new();
+        let planner_model = make_planner_model(standard_set_id);
+        let job = make_job(standard_set_id);
+        let standard = BasicStandard {
+            job_id: job.id(),
+            standard_set_id,
+            shift_id: *job.shifts()[0].id(),
+            business_driver_id: None,
+            units: Units::Hours,
+            standard_value: 12.0,
+        };
+        let job = job.with_basic_standards(vec![standard]);
+
+        let results = generator.generate_work(&planner_model, &job);
+
+        // The shift only runs on Mondays: one 8 hour shift plus a 4 hour remainder on 2025-01-06
+        let shifts = results.shifts().unwrap();
+        assert_eq!(shifts.len(), 2);
+        assert!(shifts.iter().all(|ps| ps.shift_date() == LocalDate::new(2025, 1, 6)));
+        assert_eq!(shifts[0].duration(), Decimal::from(8));
+        assert_eq!(shifts[1].duration(), Decimal::from(4));
+        assert_eq!(results.work_contents().len(), 1);
+        assert_eq!(results.work_contents()[0].calculated_hours(), 12.0);
+        assert_eq!(results.work_content_logs().len(), 1);
+        assert_eq!(results.work_content_logs()[0].work_in_minutes(), 720.0);
     }
 }
@@ -1,4 +1,5 @@
 pub mod basic_work_generator;
-mod basic_planned_shift_creator;
+pub(crate) mod basic_planned_shift_creator;
 mod basic_standards_processor;
+pub(crate) mod basic_work_content_creator;
 pub mod basic_calculator;
@@ -3,7 +3,7 @@ use crate::workcontent::domain::environment::Environment;
 use crate::workcontent::domain::task_standards::{TaskStandardDetail, TaskStandardFrequency, TaskStandardRange};
 use crate::workcontent::generators::calculator::work_content_log::WorkContentLogDetail;
 
-struct FrequencyCalculator;
+pub struct FrequencyCalculator;
 
 impl FrequencyCalculator {
     pub fn new() -> Self {
@@ -49,7 +49,7 @@ mod tests {
     use super::*;
 
     fn make_detail(re: f64, items: i32, ranges: Vec<(TaskStandardRange, Vec<TaskStandardFrequency>)>) -> TaskStandardDetail {
-        TaskStandardDetail::new(re, items).with_ranges(ranges)
+        TaskStandardDetail::new("Vacuum".to_string(), re, items).with_ranges(ranges)
     }
 
     #[test]
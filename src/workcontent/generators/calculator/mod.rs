@@ -1,3 +1,5 @@
 pub mod work_per_unit_calculator;
 pub mod frequency_calculator;
 pub mod work_content_log;
+pub mod spread_calculator;
+pub mod recurring_variable_work_calculator;
@@ -0,0 +1,94 @@
+use crate::workcontent::common::numbers::round_raw_hours;
+use crate::workcontent::domain::recurring_task_standard::RecurringVariableWork;
+use crate::workcontent::generators::calculator::work_content_log::WorkContentLogDetail;
+use crate::workcontent::generators::calculator::work_per_unit_calculator::trim_f64;
+
+const MINUTES_PER_HOUR: f64 = 60.0;
+
+pub struct RecurringVariableWorkCalculator;
+
+impl RecurringVariableWorkCalculator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Minutes of work for the range containing the business driver value: the base hours plus
+    /// the additional hours for each full `per_number_units` units of the driver value.
+    pub fn calculate(
+        &self,
+        variable_work: &[RecurringVariableWork],
+        business_driver_value: i32,
+    ) -> Option<WorkContentLogDetail> {
+        let range = variable_work.iter()
+            .find(|range| range.contains_value(business_driver_value))?;
+
+        if range.per_number_units() == 0 {
+            let work_in_minutes = round_raw_hours(range.base_hours() * MINUTES_PER_HOUR);
+            return Some(WorkContentLogDetail::new(
+                work_in_minutes,
+                format!(
+                    "{} * {}",
+                    trim_f64(range.base_hours()),
+                    MINUTES_PER_HOUR as i32
+                ),
+            ));
+        }
+
+        let number_of_units = business_driver_value.max(0) / range.per_number_units() as i32;
+        let work_in_minutes = round_raw_hours(
+            (range.base_hours() + range.additional_hours() * number_of_units as f64) * MINUTES_PER_HOUR,
+        );
+
+        Some(WorkContentLogDetail::new(
+            work_in_minutes,
+            format!(
+                "({} + {} * ({} / {})) * {}",
+                trim_f64(range.base_hours()),
+                trim_f64(range.additional_hours()),
+                business_driver_value,
+                range.per_number_units(),
+                MINUTES_PER_HOUR as i32
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn variable_work() -> Vec<RecurringVariableWork> {
+        vec![
+            RecurringVariableWork::new(0, 99, 1.0, 0.5, 10),
+            RecurringVariableWork::new(100, 500, 2.0, 0.25, 20),
+        ]
+    }
+
+    #[rstest]
+    #[case(45, 180.0, "(1 + 0.5 * (45 / 10)) * 60")]
+    #[case(0, 60.0, "(1 + 0.5 * (0 / 10)) * 60")]
+    #[case(130, 210.0, "(2 + 0.25 * (130 / 20)) * 60")]
+    fn base_plus_additional_hours_per_units(#[case] driver_value: i32, #[case] expected_minutes: f64, #[case] expected_formula: &str) {
+        let detail = RecurringVariableWorkCalculator::new().calculate(&variable_work(), driver_value).unwrap();
+
+        assert_eq!(detail.work_in_minutes(), expected_minutes);
+        assert_eq!(detail.formula(), expected_formula);
+    }
+
+    #[test]
+    fn no_range_for_driver_value_is_none() {
+        assert!(RecurringVariableWorkCalculator::new().calculate(&variable_work(), 501).is_none());
+        assert!(RecurringVariableWorkCalculator::new().calculate(&[], 10).is_none());
+    }
+
+    #[test]
+    fn zero_units_uses_base_hours_only() {
+        let detail = RecurringVariableWorkCalculator::new()
+            .calculate(&[RecurringVariableWork::new(0, 10, 1.5, 3.0, 0)], 5)
+            .unwrap();
+
+        assert_eq!(detail.work_in_minutes(), 90.0);
+        assert_eq!(detail.formula(), "1.5 * 60");
+    }
+}
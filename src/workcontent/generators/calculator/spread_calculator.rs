@@ -0,0 +1,156 @@
+use crate::workcontent::common::numbers::round_raw_hours;
+use crate::workcontent::domain::dynamic_spread_value::DynamicSpreadValue;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::spread_standard::{SpreadStandard, SpreadStandardRange, SpreadStandardType, SPREAD_PERIOD_LENGTH};
+
+const MINUTES_PER_DAY: u32 = 1440;
+
+pub struct SpreadCalculator;
+
+impl SpreadCalculator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Staffing for each period of the day (period 1 starts at midnight), taken from the range
+    /// of the standard that contains the business driver value. Fixed spreads average their 5
+    /// minute values over each period; dynamic spreads divide the historical driver value of each
+    /// period by the units one staff member handles.
+    pub fn calculate(
+        &self,
+        spread_standard: &SpreadStandard,
+        business_driver_value: i32,
+        environment: Environment,
+        period_length: u32,
+        historical_values: &[&DynamicSpreadValue],
+    ) -> Option<Vec<f64>> {
+        if period_length == 0 {
+            return None;
+        }
+
+        let range = spread_standard.range_for(business_driver_value)?;
+
+        match spread_standard.spread_standard_type {
+            SpreadStandardType::FIXED => Self::fixed_spread(range, environment, period_length),
+            SpreadStandardType::DYNAMIC => Self::dynamic_spread(range, environment, period_length, historical_values),
+        }
+    }
+
+    fn fixed_spread(range: &SpreadStandardRange, environment: Environment, period_length: u32) -> Option<Vec<f64>> {
+        let spread_values = range.values_for_environment(environment)?.spread_values();
+        let values_per_period = (period_length / SPREAD_PERIOD_LENGTH).max(1) as usize;
+
+        let staffing = (0..Self::number_of_periods(period_length) as usize)
+            .map(|period| {
+                let total: u32 = spread_values.iter()
+                    .skip(period * values_per_period)
+                    .take(values_per_period)
+                    .sum();
+                round_raw_hours(total as f64 / values_per_period as f64)
+            })
+            .collect();
+
+        Some(staffing)
+    }
+
+    fn dynamic_spread(range: &SpreadStandardRange,
+                      environment: Environment,
+                      period_length: u32,
+                      historical_values: &[&DynamicSpreadValue]) -> Option<Vec<f64>> {
+        let units_per_staff = range.dynamic_value_for_environment(environment)?;
+        if units_per_staff <= 0.0 {
+            return None;
+        }
+
+        let staffing = (1..=Self::number_of_periods(period_length))
+            .map(|period_no| {
+                let value: u32 = historical_values.iter()
+                    .filter(|value| value.period_no() == period_no)
+                    .map(|value| value.value())
+                    .sum();
+                round_raw_hours(value as f64 / units_per_staff)
+            })
+            .collect();
+
+        Some(staffing)
+    }
+
+    fn number_of_periods(period_length: u32) -> u32 {
+        MINUTES_PER_DAY.div_ceil(period_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::business_driver::BusinessDriverId;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::job_shift::JobShiftId;
+    use crate::workcontent::domain::spread_standard::{DynamicSpreadStandard, SpreadStandardValue};
+    use crate::workcontent::domain::standard_set::StandardSetId;
+    use crate::workcontent::domain::value_type::ValueType;
+    use joda_rs::LocalDate;
+
+    fn make_standard(spread_standard_type: SpreadStandardType, ranges: Vec<SpreadStandardRange>) -> SpreadStandard {
+        SpreadStandard {
+            job_id: JobId::new(),
+            standard_set_id: StandardSetId::new(),
+            shift_id: JobShiftId::new(),
+            business_driver_id: BusinessDriverId::new(),
+            spread_standard_type,
+            units: None,
+            ranges,
+        }
+    }
+
+    #[test]
+    fn fixed_spread_averages_five_minute_values_per_period() {
+        let env = Environment::new(1);
+        let standard = make_standard(SpreadStandardType::FIXED, vec![
+            SpreadStandardRange::new(0, 99)
+                .with_values(vec![SpreadStandardValue::new(env, vec![9, 9, 9])]),
+            SpreadStandardRange::new(100, 1000)
+                .with_values(vec![SpreadStandardValue::new(env, vec![1, 2, 3, 4, 4, 4])]),
+        ]);
+
+        let staffing = SpreadCalculator::new().calculate(&standard, 150, env, 15, &[]).unwrap();
+
+        assert_eq!(staffing.len(), 96);
+        assert_eq!(staffing[0], 2.0);
+        assert_eq!(staffing[1], 4.0);
+        assert!(staffing[2..].iter().all(|staff| *staff == 0.0));
+    }
+
+    #[test]
+    fn fixed_spread_without_values_for_environment_is_none() {
+        let standard = make_standard(SpreadStandardType::FIXED, vec![
+            SpreadStandardRange::new(0, 1000)
+                .with_values(vec![SpreadStandardValue::new(Environment::new(1), vec![1])]),
+        ]);
+
+        assert!(SpreadCalculator::new().calculate(&standard, 10, Environment::new(2), 30, &[]).is_none());
+        assert!(SpreadCalculator::new().calculate(&standard, 2000, Environment::new(1), 30, &[]).is_none());
+    }
+
+    #[test]
+    fn dynamic_spread_uses_historical_values_per_period() {
+        let env = Environment::new(1);
+        let standard = make_standard(SpreadStandardType::DYNAMIC, vec![
+            SpreadStandardRange::new(0, 1000)
+                .with_dynamic_values(vec![DynamicSpreadStandard::new(env, 20.0)]),
+        ]);
+        let date = LocalDate::new(2024, 12, 30);
+        let history = [
+            DynamicSpreadValue::new(ValueType::ACTUALS, standard.business_driver_id, date, 1, 40),
+            DynamicSpreadValue::new(ValueType::ACTUALS, standard.business_driver_id, date, 3, 25),
+        ];
+        let history: Vec<&DynamicSpreadValue> = history.iter().collect();
+
+        let staffing = SpreadCalculator::new().calculate(&standard, 500, env, 60, &history).unwrap();
+
+        assert_eq!(staffing.len(), 24);
+        assert_eq!(staffing[0], 2.0);
+        assert_eq!(staffing[1], 0.0);
+        assert_eq!(staffing[2], 1.25);
+    }
+}
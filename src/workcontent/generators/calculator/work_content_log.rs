@@ -1,4 +1,7 @@
-#[derive(Debug, Default, Clone)]
+use joda_rs::LocalDate;
+use crate::workcontent::domain::job::JobId;
+
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct WorkContentLogDetail {
     work_in_minutes: f64,
     formula: String,
@@ -19,6 +22,44 @@ impl WorkContentLogDetail {
     }
 }
 
+/// How the work of one standard on a date was calculated: one detail for each task or standard
+/// value that contributed to it, with the name it was calculated for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkContentLog {
+    job_id: JobId,
+    shift_date: LocalDate,
+    description: String,
+    details: Vec<(String, WorkContentLogDetail)>,
+}
+
+impl WorkContentLog {
+    pub fn new(job_id: JobId, shift_date: LocalDate, description: String, details: Vec<(String, WorkContentLogDetail)>) -> Self {
+        Self {
+            job_id,
+            shift_date,
+            description,
+            details,
+        }
+    }
+    pub fn job_id(&self) -> JobId {
+        self.job_id
+    }
+    pub fn shift_date(&self) -> LocalDate {
+        self.shift_date
+    }
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    pub fn details(&self) -> &[(String, WorkContentLogDetail)] {
+        &self.details
+    }
+    pub fn work_in_minutes(&self) -> f64 {
+        self.details.iter()
+            .map(|(_, detail)| detail.work_in_minutes())
+            .sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +90,15 @@ mod tests {
         assert!(dbg.contains("WorkContentLogDetail"));
         assert!(!dbg.is_empty());
     }
+
+    #[test]
+    fn log_sums_minutes_of_its_details() {
+        let log = WorkContentLog::new(JobId::new(), LocalDate::new(2025, 1, 6), "Room clean".to_string(), vec![
+            ("Vacuum".to_string(), WorkContentLogDetail::new(12.5, "2.50 * 5".to_string())),
+            ("Dust".to_string(), WorkContentLogDetail::new(7.5, "2.50 * 3".to_string())),
+        ]);
+
+        assert_eq!(log.work_in_minutes(), 20.0);
+        assert_eq!(log.details()[1].0, "Dust");
+    }
 }
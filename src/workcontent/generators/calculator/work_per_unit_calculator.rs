@@ -89,10 +89,10 @@ impl WorkPerUnitCalculator {
                 let work_in_minutes = round_raw_hours(standard_value);
                 Some(WorkContentLogDetail::new(
                     work_in_minutes,
-                    format!("{}", trim_f64(standard_value))
+                    trim_f64(standard_value)
                 ))
             }
-            Units::UnitsPerShift => {
+            Units::UnitsPerShift | Units::UnitsPerPerson => {
                 let work_in_minutes = round_raw_hours(
                     business_driver_value as f64 / standard_value * shift_length * MINUTES_PER_HOUR,
                 );
@@ -111,7 +111,7 @@ impl WorkPerUnitCalculator {
     }
 }
 
-fn trim_f64(v: f64) -> String {
+pub(crate) fn trim_f64(v: f64) -> String {
     // Formats f64 similar to Java's default toString without trailing zeros when possible
     // Keep reasonable precision to avoid scientific notation for typical minute values.
     let s = format!("{}", v);
@@ -183,6 +183,14 @@ mod tests {
         assert_eq!(result.formula(), "100 / 10 * 7.5 * 60");
     }
 
+    #[test]
+    fn units_per_person_are_handled_in_one_shift() {
+        let calc = WorkPerUnitCalculator::new();
+        let result = calc.calculate(Units::UnitsPerPerson, 16.0, 120, 8.0).unwrap();
+        assert_eq!(result.work_in_minutes(), 3600.0);
+        assert_eq!(result.formula(), "120 / 16 * 8 * 60");
+    }
+
     #[test]
     fn zero_standard_value_returns_none() {
         let calc = WorkPerUnitCalculator::new();
@@ -1,6 +1,6 @@
 mod none;
-mod basic;
+pub(crate) mod basic;
 mod advanced;
 mod salaried;
 pub mod work_generators;
-mod calculator;
+pub(crate) mod calculator;
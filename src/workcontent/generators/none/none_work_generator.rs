@@ -25,7 +25,6 @@ mod tests {
     use crate::workcontent::domain::location::LocationId;
     use crate::workcontent::domain::standard_set::StandardSetId;
     use date_range_rs::DateRange;
-    use std::collections::HashMap;
     use joda_rs::LocalDate;
 
     fn make_planner_model() -> PlannerModel {
@@ -34,7 +33,7 @@ mod tests {
         let dates = DateRange::new(start, end);
         let location_id = LocationId::new();
         let standard_set_id = StandardSetId::new();
-        PlannerModel::new(dates, location_id, standard_set_id, vec![], vec![], HashMap::new())
+        PlannerModel::new(dates, location_id, standard_set_id, vec![], vec![], vec![])
     }
 
     #[test]
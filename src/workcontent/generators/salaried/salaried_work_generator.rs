@@ -49,7 +49,7 @@ impl SalariedWorkGenerator {
         shift: &JobShift,
         date: LocalDate,
     ) -> f64 {
-        let shift_detail = shift.shift_detail_for_date(date);
+        let shift_detail = shift.shift_detail_for_date(Some(planner_model.environment_for_date(date)), date);
 
         if shift_detail.is_some() {
             self.calc_work_for_shift_detail(planner_model, job, shift, date)
@@ -93,7 +93,6 @@ mod tests {
     use crate::workcontent::domain::job_shift::JobShift;
     use date_range_rs::DateRange;
     use joda_rs::LocalDate;
-    use std::collections::HashMap;
 
     fn make_planner_model() -> PlannerModel {
         let start = LocalDate::new(2025, 1, 1);
@@ -101,7 +100,7 @@ mod tests {
         let dates = DateRange::new(start, end);
         let location_id = LocationId::new();
         let standard_set_id = StandardSetId::new();
-        PlannerModel::new(dates, location_id, standard_set_id, vec![], vec![], HashMap::new())
+        PlannerModel::new(dates, location_id, standard_set_id, vec![], vec![], vec![])
     }
 
     #[test]
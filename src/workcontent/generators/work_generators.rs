@@ -5,15 +5,20 @@ use crate::workcontent::domain::planned_shift::PlannedShift;
 use crate::workcontent::domain::planner_model::PlannerModel;
 use crate::workcontent::domain::salaried_standard::SalariedStandard;
 use crate::workcontent::domain::standard_type::StandardType;
+use crate::workcontent::domain::work_content::WorkContent;
 use crate::workcontent::generators::advanced::advanced_work_generator::AdvancedWorkGenerator;
 use crate::workcontent::generators::basic::basic_work_generator::BasicWorkGenerator;
+use crate::workcontent::generators::calculator::work_content_log::WorkContentLog;
 use crate::workcontent::generators::none::none_work_generator::NoneWorkGenerator;
 use crate::workcontent::generators::salaried::salaried_work_generator::SalariedWorkGenerator;
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct WorkResults {
     job_id: JobId,
     shifts: Option<Vec<PlannedShift>>,
     labor_data: Option<Vec<LaborData>>,
+    work_contents: Vec<WorkContent>,
+    work_content_logs: Vec<WorkContentLog>,
 }
 
 impl WorkResults {
@@ -22,6 +27,8 @@ impl WorkResults {
             job_id,
             shifts: Some(shifts),
             labor_data: None,
+            work_contents: Vec::new(),
+            work_content_logs: Vec::new(),
         }
     }
 
@@ -30,9 +37,21 @@ impl WorkResults {
             job_id,
             shifts: None,
             labor_data: Some(labor_data),
+            work_contents: Vec::new(),
+            work_content_logs: Vec::new(),
         }
     }
 
+    pub fn with_work_contents(mut self, work_contents: Vec<WorkContent>) -> Self {
+        self.work_contents = work_contents;
+        self
+    }
+
+    pub fn with_work_content_logs(mut self, work_content_logs: Vec<WorkContentLog>) -> Self {
+        self.work_content_logs = work_content_logs;
+        self
+    }
+
     pub fn job_id(&self) -> JobId {
         self.job_id
     }
@@ -44,6 +63,14 @@ impl WorkResults {
     pub fn labor_data(&self) -> Option<&Vec<LaborData>> {
         self.labor_data.as_ref()
     }
+
+    pub fn work_contents(&self) -> &[WorkContent] {
+        &self.work_contents
+    }
+
+    pub fn work_content_logs(&self) -> &[WorkContentLog] {
+        &self.work_content_logs
+    }
 }
 
 pub trait WorkGenerator {
@@ -71,7 +98,7 @@ impl WorkGeneratorKind {
 impl From<StandardType> for WorkGeneratorKind {
     fn from(standard_type: StandardType) -> Self {
         match standard_type {
-            StandardType::NONE => WorkGeneratorKind::None(NoneWorkGenerator::new()),
+            StandardType::NONE | StandardType::EVENT => WorkGeneratorKind::None(NoneWorkGenerator::new()),
             StandardType::BASIC => WorkGeneratorKind::Basic(BasicWorkGenerator::new()),
             StandardType::ADVANCED => WorkGeneratorKind::Advanced(AdvancedWorkGenerator::new()),
             StandardType::SALARIED => WorkGeneratorKind::Salaried(SalariedWorkGenerator::new()),
@@ -80,6 +107,7 @@ impl From<StandardType> for WorkGeneratorKind {
 }
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use crate::workcontent::domain::standard_type::StandardType;
     use crate::workcontent::generators::advanced::advanced_work_generator::AdvancedWorkGenerator;
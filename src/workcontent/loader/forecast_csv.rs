@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use csv::{ReaderBuilder, StringRecord};
+use joda_rs::LocalDate;
+use uuid::Uuid;
+use crate::workcontent::domain::business_driver::{BusinessDriver, BusinessDriverId};
+use crate::workcontent::domain::business_driver_values::BusinessDriverValues;
+use crate::workcontent::loader::json_node::parse_date;
+use crate::workcontent::loader::load_error::LoadError;
+
+const DATE: &str = "date";
+const DRIVER: &str = "driver";
+const VALUE: &str = "value";
+
+/// Reads daily business driver forecasts from CSV with a `date,driver,value` header, in any
+/// column order. Dates are `yyyy-MM-dd`, drivers are given by id or by name (ignoring case) and
+/// values are non-negative whole numbers. Errors give the row and column of the bad cell, the
+/// header being row 1.
+#[derive(Default)]
+pub struct ForecastCsvReader;
+
+impl ForecastCsvReader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn read_file(&self, path: impl AsRef<Path>, business_drivers: &[BusinessDriver]) -> Result<Vec<BusinessDriverValues>, LoadError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|error| LoadError::io(path, error))?;
+        self.read(file, business_drivers)
+    }
+
+    /// The values of each driver that has rows, in the order the drivers first appear.
+    pub fn read(&self, source: impl Read, business_drivers: &[BusinessDriver]) -> Result<Vec<BusinessDriverValues>, LoadError> {
+        let mut reader = ReaderBuilder::new().flexible(true).from_reader(source);
+        let header = reader.headers().map_err(csv_error)?.clone();
+        let date_column = header_column(&header, DATE)?;
+        let driver_column = header_column(&header, DRIVER)?;
+        let value_column = header_column(&header, VALUE)?;
+
+        let mut driver_ids: Vec<BusinessDriverId> = Vec::new();
+        let mut values: HashMap<BusinessDriverId, HashMap<LocalDate, u32>> = HashMap::new();
+        let mut record = StringRecord::new();
+
+        while reader.read_record(&mut record).map_err(csv_error)? {
+            let row = record.position().map_or(0, |position| position.line() as usize);
+            if record.iter().all(|field| field.trim().is_empty()) {
+                continue;
+            }
+            let cell = |column: usize| -> Result<&str, LoadError> {
+                record.get(column)
+                    .map(str::trim)
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| LoadError::invalid_cell(row, column + 1, format!("missing {}", header[column].trim())))
+            };
+
+            let date_text = cell(date_column)?;
+            let date = parse_date(date_text)
+                .ok_or_else(|| LoadError::invalid_cell(row, date_column + 1, format!("expected a yyyy-MM-dd date but was \"{}\"", date_text)))?;
+            let driver_id = business_driver_id(cell(driver_column)?, business_drivers)
+                .map_err(|message| LoadError::invalid_cell(row, driver_column + 1, message))?;
+            let value_text = cell(value_column)?;
+            let value = parse_value(value_text)
+                .ok_or_else(|| LoadError::invalid_cell(row, value_column + 1, format!("expected a non-negative whole number but was \"{}\"", value_text)))?;
+
+            if !values.contains_key(&driver_id) {
+                driver_ids.push(driver_id);
+            }
+            if values.entry(driver_id).or_default().insert(date, value).is_some() {
+                return Err(LoadError::invalid_cell(row, date_column + 1, format!("a second value for {} on {}", driver_id, date_text)));
+            }
+        }
+
+        Ok(driver_ids.into_iter()
+            .map(|driver_id| BusinessDriverValues::new(driver_id, values.remove(&driver_id).unwrap_or_default()))
+            .collect())
+    }
+}
+
+fn header_column(header: &StringRecord, name: &str) -> Result<usize, LoadError> {
+    header.iter()
+        .position(|field| field.trim().eq_ignore_ascii_case(name))
+        .ok_or_else(|| LoadError::invalid_cell(1, header.len() + 1, format!("missing {} column", name)))
+}
+
+fn business_driver_id(driver: &str, business_drivers: &[BusinessDriver]) -> Result<BusinessDriverId, String> {
+    if let Ok(uuid) = Uuid::parse_str(driver) {
+        return business_drivers.iter()
+            .find(|business_driver| *business_driver.id().as_uuid() == uuid)
+            .map(BusinessDriver::id)
+            .ok_or_else(|| format!("no business driver with id {}", driver));
+    }
+
+    let mut matches = business_drivers.iter().filter(|business_driver| business_driver.name().eq_ignore_ascii_case(driver));
+    match (matches.next(), matches.next()) {
+        (Some(business_driver), None) => Ok(business_driver.id()),
+        (Some(_), Some(_)) => Err(format!("more than one business driver is named \"{}\"", driver)),
+        (None, _) => Err(format!("no business driver named \"{}\"", driver)),
+    }
+}
+
+fn parse_value(value: &str) -> Option<u32> {
+    let value: f64 = value.parse().ok()?;
+    if value.fract() != 0.0 || value < 0.0 || value > u32::MAX as f64 {
+        return None;
+    }
+    Some(value as u32)
+}
+
+fn csv_error(error: csv::Error) -> LoadError {
+    let row = error.position().map_or(1, |position| position.line() as usize);
+    match error.kind() {
+        csv::ErrorKind::Io(error) => LoadError::Io {
+            file: "CSV".to_string(),
+            message: error.to_string(),
+        },
+        csv::ErrorKind::Utf8 { err, .. } => LoadError::invalid_cell(row, err.field() + 1, "invalid UTF-8"),
+        _ => LoadError::invalid_cell(row, 1, error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use date_range_rs::DateRange;
+    use rstest::rstest;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planner_model::PlannerModel;
+    use crate::workcontent::domain::standard_set::StandardSetId;
+
+    const ROOMS_ID: &str = "5a1d4a0e-7c1b-4c5e-9a57-0c2b8b6f4d21";
+    const COVERS_ID: &str = "9e0c6f35-2b8d-4e1a-8f4b-3d7a6c5e2f10";
+
+    fn business_drivers() -> Vec<BusinessDriver> {
+        let location_id = LocationId::new();
+        vec![
+            BusinessDriver::new(BusinessDriverId::from(Uuid::parse_str(ROOMS_ID).unwrap()), location_id)
+                .with_name("Occupied rooms".to_string()),
+            BusinessDriver::new(BusinessDriverId::from(Uuid::parse_str(COVERS_ID).unwrap()), location_id)
+                .with_name("Covers".to_string()),
+        ]
+    }
+
+    fn read(csv: &str) -> Result<Vec<BusinessDriverValues>, LoadError> {
+        ForecastCsvReader::new().read(csv.as_bytes(), &business_drivers())
+    }
+
+    #[test]
+    fn values_are_read_for_drivers_given_by_id_or_name() {
+        let drivers = business_drivers();
+
+        let values = read(&format!("date,driver,value\n\
+            2025-01-06,{ROOMS_ID},120\n\
+            2025-01-06,covers,45\n\
+            \n\
+            2025-01-07,\"Occupied rooms\",80.0\n")).unwrap();
+
+        assert_eq!(values, vec![
+            BusinessDriverValues::new(drivers[0].id(), HashMap::from([(LocalDate::new(2025, 1, 6), 120), (LocalDate::new(2025, 1, 7), 80)])),
+            BusinessDriverValues::new(drivers[1].id(), HashMap::from([(LocalDate::new(2025, 1, 6), 45)])),
+        ]);
+    }
+
+    #[test]
+    fn columns_are_found_by_their_header() {
+        let values = read("Value, Driver, Date\n45,Covers,2025-01-06\n").unwrap();
+
+        assert_eq!(values[0].value(LocalDate::new(2025, 1, 6)), Some(45));
+    }
+
+    #[rstest]
+    #[case("date,driver\n", "row 1, column 3: missing value column")]
+    #[case("date,driver,value\n2025-01-06,Covers,45\n06/01/2025,Covers,45\n", "row 3, column 1: expected a yyyy-MM-dd date but was \"06/01/2025\"")]
+    #[case("date,driver,value\n2025-01-06,Arrivals,45\n", "row 2, column 2: no business driver named \"Arrivals\"")]
+    #[case("date,driver,value\n2025-01-06,Covers,-4\n", "row 2, column 3: expected a non-negative whole number but was \"-4\"")]
+    #[case("date,driver,value\n2025-01-06,Covers\n", "row 2, column 3: missing value")]
+    #[case("date,driver,value\n2025-01-06,Covers,4\n2025-01-06,Covers,5\n", "row 3, column 1: a second value for")]
+    fn bad_cells_are_reported_by_row_and_column(#[case] csv: &str, #[case] expected: &str) {
+        let error = read(csv).unwrap_err();
+
+        assert!(error.to_string().starts_with(expected), "{}", error);
+    }
+
+    #[test]
+    fn imported_values_replace_the_model_values_of_their_drivers() {
+        let drivers = business_drivers();
+        let monday = LocalDate::new(2025, 1, 6);
+        let covers_id = drivers[1].id();
+        let model = PlannerModel::new(DateRange::new(monday, monday), LocationId::new(), StandardSetId::new(), vec![], vec![], vec![
+            BusinessDriverValues::new(covers_id, HashMap::from([(monday, 10), (monday.plus_days(1), 12)])),
+        ]);
+
+        let model = model.with_business_driver_values(read("date,driver,value\n2025-01-06,Covers,45\n").unwrap());
+
+        assert_eq!(model.value_for(covers_id, monday), 45);
+        assert_eq!(model.value_for(covers_id, monday.plus_days(1)), 0);
+    }
+}
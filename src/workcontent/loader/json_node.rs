@@ -0,0 +1,294 @@
+use std::str::FromStr;
+use joda_rs::{DayOfWeek, LocalDate, LocalDateTime, LocalTime};
+use rust_decimal::Decimal;
+use serde_json::Value;
+use uuid::Uuid;
+use crate::workcontent::loader::load_error::LoadError;
+
+/// A value in a JSON document together with its path from the root, so that every conversion
+/// error can name the field it came from.
+#[derive(Debug, Clone)]
+pub(crate) struct JsonNode<'a> {
+    value: &'a Value,
+    path: String,
+}
+
+impl<'a> JsonNode<'a> {
+    pub fn root(value: &'a Value) -> Self {
+        Self {
+            value,
+            path: "$".to_string(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> LoadError {
+        LoadError::invalid_field(&self.path, message)
+    }
+
+    pub fn has_field(&self, name: &str) -> bool {
+        self.optional_field(name).is_some()
+    }
+
+    pub fn field(&self, name: &str) -> Result<JsonNode<'a>, LoadError> {
+        self.optional_field(name)
+            .ok_or_else(|| LoadError::invalid_field(&format!("{}.{}", self.path, name), "missing field"))
+    }
+
+    /// The field, or `None` when it is missing, null or an empty string.
+    pub fn optional_field(&self, name: &str) -> Option<JsonNode<'a>> {
+        match self.value.get(name) {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) if s.trim().is_empty() => None,
+            Some(value) => Some(JsonNode {
+                value,
+                path: format!("{}.{}", self.path, name),
+            }),
+        }
+    }
+
+    pub fn array(&self) -> Result<Vec<JsonNode<'a>>, LoadError> {
+        let values = self.value.as_array().ok_or_else(|| self.error("expected an array"))?;
+        Ok(values.iter()
+            .enumerate()
+            .map(|(index, value)| JsonNode {
+                value,
+                path: format!("{}[{}]", self.path, index),
+            })
+            .collect())
+    }
+
+    /// The elements of an array field, or none when the field is missing.
+    pub fn optional_array(&self, name: &str) -> Result<Vec<JsonNode<'a>>, LoadError> {
+        match self.optional_field(name) {
+            Some(node) => node.array(),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub fn entries(&self) -> Result<Vec<(&'a str, JsonNode<'a>)>, LoadError> {
+        let values = self.value.as_object().ok_or_else(|| self.error("expected an object"))?;
+        Ok(values.iter()
+            .map(|(name, value)| (name.as_str(), JsonNode {
+                value,
+                path: format!("{}.{}", self.path, name),
+            }))
+            .collect())
+    }
+
+    pub fn as_str(&self) -> Result<&'a str, LoadError> {
+        self.value.as_str().ok_or_else(|| self.error("expected a string"))
+    }
+
+    /// A number, or a string holding a number.
+    pub fn as_f64(&self) -> Result<f64, LoadError> {
+        match self.value {
+            Value::Number(number) => number.as_f64().ok_or_else(|| self.error("expected a number")),
+            Value::String(s) => s.trim().parse::<f64>().map_err(|_| self.error(format!("expected a number but was \"{}\"", s))),
+            _ => Err(self.error("expected a number")),
+        }
+    }
+
+    pub fn as_i32(&self) -> Result<i32, LoadError> {
+        let value = self.as_f64()?;
+        if value.fract() != 0.0 || value < i32::MIN as f64 || value > i32::MAX as f64 {
+            return Err(self.error(format!("expected a whole number but was {}", value)));
+        }
+        Ok(value as i32)
+    }
+
+    pub fn as_u32(&self) -> Result<u32, LoadError> {
+        let value = self.as_f64()?;
+        if value.fract() != 0.0 || value < 0.0 || value > u32::MAX as f64 {
+            return Err(self.error(format!("expected a non-negative whole number but was {}", value)));
+        }
+        Ok(value as u32)
+    }
+
+    pub fn as_bool(&self) -> Result<bool, LoadError> {
+        self.value.as_bool().ok_or_else(|| self.error("expected true or false"))
+    }
+
+    pub fn as_uuid(&self) -> Result<Uuid, LoadError> {
+        let s = self.as_str()?;
+        Uuid::parse_str(s.trim()).map_err(|_| self.error(format!("expected a UUID but was \"{}\"", s)))
+    }
+
+    /// A date written as `yyyy-MM-dd`.
+    pub fn as_date(&self) -> Result<LocalDate, LoadError> {
+        let s = self.as_str()?;
+        parse_date(s).ok_or_else(|| self.error(format!("expected a date (yyyy-MM-dd) but was \"{}\"", s)))
+    }
+
+    /// A time of day written as `HH:mm` or `HH:mm:ss`.
+    pub fn as_time(&self) -> Result<LocalTime, LoadError> {
+        let s = self.as_str()?;
+        parse_time(s).ok_or_else(|| self.error(format!("expected a time (HH:mm) but was \"{}\"", s)))
+    }
+
+    /// A date and time written as `yyyy-MM-ddTHH:mm` or `yyyy-MM-ddTHH:mm:ss`.
+    pub fn as_date_time(&self) -> Result<LocalDateTime, LoadError> {
+        let s = self.as_str()?;
+        parse_date_time(s).ok_or_else(|| self.error(format!("expected a date and time (yyyy-MM-ddTHH:mm:ss) but was \"{}\"", s)))
+    }
+
+    /// A decimal written as a string, so that no precision is lost, or as a number.
+    pub fn as_decimal(&self) -> Result<Decimal, LoadError> {
+        let decimal = match self.value {
+            Value::String(s) => Decimal::from_str(s.trim()).ok(),
+            Value::Number(number) => Decimal::from_str(&number.to_string()).ok(),
+            _ => None,
+        };
+        decimal.ok_or_else(|| self.error(format!("expected a decimal but was {}", self.value)))
+    }
+
+    /// A day of week written as `MON`, `MONDAY` or `1` (Monday) to `7` (Sunday).
+    pub fn as_day_of_week(&self) -> Result<DayOfWeek, LoadError> {
+        let day_of_week = match self.value {
+            Value::Number(_) => DayOfWeek::try_of(self.as_i32()?).ok(),
+            Value::String(s) => parse_day_of_week(s),
+            _ => None,
+        };
+        day_of_week.ok_or_else(|| self.error(format!("expected a day of week but was {}", self.value)))
+    }
+
+    /// Matches a string against enum names, ignoring case and separators, e.g. `units_per_hour`,
+    /// `UnitsPerHour` and `UNITS-PER-HOUR` all match `UnitsPerHour`.
+    pub fn as_enum<T: Copy>(&self, values: &[(&str, T)]) -> Result<T, LoadError> {
+        let s = self.as_str()?;
+        let normalized = normalize(s);
+        values.iter()
+            .find(|(name, _)| normalize(name) == normalized)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| {
+                let names: Vec<&str> = values.iter().map(|(name, _)| *name).collect();
+                self.error(format!("expected one of {} but was \"{}\"", names.join(", "), s))
+            })
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+pub(crate) fn parse_date(s: &str) -> Option<LocalDate> {
+    let mut parts = s.trim().split('-');
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month: i32 = parts.next()?.parse().ok()?;
+    let day: i32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || !(1..=12).contains(&month) || day < 1 {
+        return None;
+    }
+    if day > LocalDate::new(year, month, 1).length_of_month() {
+        return None;
+    }
+    Some(LocalDate::new(year, month, day))
+}
+
+pub(crate) fn parse_time(s: &str) -> Option<LocalTime> {
+    let parts: Vec<&str> = s.trim().split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+    let hour: i32 = parts[0].parse().ok()?;
+    let minute: i32 = parts[1].parse().ok()?;
+    let second: i32 = match parts.get(2) {
+        Some(second) => second.parse().ok()?,
+        None => 0,
+    };
+    if !(0..24).contains(&hour) || !(0..60).contains(&minute) || !(0..60).contains(&second) {
+        return None;
+    }
+    Some(LocalTime::new(hour, minute, second))
+}
+
+pub(crate) fn parse_date_time(s: &str) -> Option<LocalDateTime> {
+    let (date, time) = s.trim().split_once('T')?;
+    Some(parse_date(date)?.at_time(parse_time(time)?))
+}
+
+pub(crate) fn parse_day_of_week(s: &str) -> Option<DayOfWeek> {
+    let normalized = normalize(s);
+    if let Ok(value) = normalized.parse::<i32>() {
+        return DayOfWeek::try_of(value).ok();
+    }
+    (1..=7)
+        .map(DayOfWeek::of)
+        .find(|day_of_week| {
+            let name = day_of_week.to_string();
+            normalized == name || (normalized.len() == 3 && name.starts_with(&normalized))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    #[test]
+    fn errors_name_the_json_path() {
+        let value = json!({"jobs": [{"settings": {"periodLength": "thirty"}}]});
+        let root = JsonNode::root(&value);
+
+        let jobs = root.field("jobs").unwrap().array().unwrap();
+        let error = jobs[0].field("settings").unwrap().field("periodLength").unwrap().as_u32().unwrap_err();
+        let missing = jobs[0].field("shifts").unwrap_err();
+
+        assert_eq!(error, LoadError::invalid_field("$.jobs[0].settings.periodLength", "expected a number but was \"thirty\""));
+        assert_eq!(missing, LoadError::invalid_field("$.jobs[0].shifts", "missing field"));
+    }
+
+    #[test]
+    fn empty_strings_are_missing_values() {
+        let value = json!({"id": "", "name": "Front desk"});
+        let root = JsonNode::root(&value);
+
+        assert!(root.optional_field("id").is_none());
+        assert_eq!(root.field("name").unwrap().as_str().unwrap(), "Front desk");
+    }
+
+    #[rstest]
+    #[case("2024-02-29", Some(LocalDate::new(2024, 2, 29)))]
+    #[case("2025-02-29", None)]
+    #[case("2025-13-01", None)]
+    #[case("01/02/2025", None)]
+    fn parses_dates(#[case] s: &str, #[case] expected: Option<LocalDate>) {
+        assert_eq!(parse_date(s), expected);
+    }
+
+    #[rstest]
+    #[case("07:30", Some(LocalTime::new(7, 30, 0)))]
+    #[case("23:59:59", Some(LocalTime::new(23, 59, 59)))]
+    #[case("24:00", None)]
+    #[case("7", None)]
+    fn parses_times(#[case] s: &str, #[case] expected: Option<LocalTime>) {
+        assert_eq!(parse_time(s), expected);
+    }
+
+    #[test]
+    fn parses_date_times_and_decimals() {
+        let value = json!({"start": "2025-01-06T07:30:00", "duration": "7.50", "hours": 7.5, "bad": "2025-01-06 07:30"});
+        let root = JsonNode::root(&value);
+
+        assert_eq!(root.field("start").unwrap().as_date_time().unwrap(), LocalDate::new(2025, 1, 6).at_time(LocalTime::new(7, 30, 0)));
+        assert_eq!(root.field("duration").unwrap().as_decimal().unwrap().to_string(), "7.50");
+        assert_eq!(root.field("hours").unwrap().as_decimal().unwrap(), Decimal::from_str("7.5").unwrap());
+        assert!(root.field("bad").unwrap().as_date_time().is_err());
+    }
+
+    #[rstest]
+    #[case("MON", Some(DayOfWeek::Monday))]
+    #[case("sunday", Some(DayOfWeek::Sunday))]
+    #[case("3", Some(DayOfWeek::Wednesday))]
+    #[case("MO", None)]
+    fn parses_days_of_week(#[case] s: &str, #[case] expected: Option<DayOfWeek>) {
+        assert_eq!(parse_day_of_week(s), expected);
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt;
+use std::path::Path;
+
+/// Why a document could not be loaded. Field errors name the JSON path of the bad value,
+/// e.g. `$.jobs[0].config.configSet1.settings.periodLength`, and cell errors the 1-based row and
+/// column of the bad CSV value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    Io { file: String, message: String },
+    Syntax { line: usize, column: usize, message: String },
+    InvalidField { path: String, message: String },
+    InvalidCell { row: usize, column: usize, message: String },
+}
+
+impl LoadError {
+    pub fn io(file: &Path, error: std::io::Error) -> Self {
+        LoadError::Io {
+            file: file.display().to_string(),
+            message: error.to_string(),
+        }
+    }
+
+    pub fn invalid_field(path: &str, message: impl Into<String>) -> Self {
+        LoadError::InvalidField {
+            path: path.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_cell(row: usize, column: usize, message: impl Into<String>) -> Self {
+        LoadError::InvalidCell {
+            row,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io { file, message } => write!(f, "could not read {}: {}", file, message),
+            LoadError::Syntax { message, .. } => write!(f, "invalid JSON: {}", message),
+            LoadError::InvalidField { path, message } => write!(f, "{}: {}", path, message),
+            LoadError::InvalidCell { row, column, message } => write!(f, "row {}, column {}: {}", row, column, message),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(error: serde_json::Error) -> Self {
+        LoadError::Syntax {
+            line: error.line(),
+            column: error.column(),
+            message: error.to_string(),
+        }
+    }
+}
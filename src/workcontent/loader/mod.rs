@@ -0,0 +1,5 @@
+pub mod load_error;
+pub mod model_loader;
+pub mod run_options;
+pub mod forecast_csv;
+pub(crate) mod json_node;
@@ -0,0 +1,1165 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use date_range_rs::DateRange;
+use joda_rs::{DayOfWeek, LocalDate};
+use serde_json::Value;
+use crate::workcontent::domain::basic_standard::BasicStandard;
+use crate::workcontent::domain::business_driver::{BusinessDriver, BusinessDriverId};
+use crate::workcontent::domain::business_driver_values::BusinessDriverValues;
+use crate::workcontent::domain::distribution_method::DistributionMethod;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::environment_calendar::{EnvironmentCalendar, EnvironmentDefinition, EnvironmentStat};
+use crate::workcontent::domain::flow_plan::FlowPlanId;
+use crate::workcontent::domain::job::{Job, JobId};
+use crate::workcontent::domain::job_shift::{JobShift, JobShiftDefinition, JobShiftId};
+use crate::workcontent::domain::location::LocationId;
+use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::domain::planner_settings::PlannerSettings;
+use crate::workcontent::domain::planner_settings_resolver::{PlannerSettingsLayer, PlannerSettingsResolver};
+use crate::workcontent::domain::salaried_standard::SalariedStandard;
+use crate::workcontent::domain::salary_mode::SalaryMode;
+use crate::workcontent::domain::shift_related_standard::{ShiftRelatedRange, ShiftRelatedStandard, ShiftRelatedStandardValue};
+use crate::workcontent::domain::spread_standard::{SpreadStandard, SpreadStandardRange, SpreadStandardType, SpreadStandardValue};
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::domain::standard_type::StandardType;
+use crate::workcontent::domain::units::Units;
+use crate::workcontent::domain::work_type::WorkType;
+use crate::workcontent::loader::json_node::JsonNode;
+use crate::workcontent::loader::load_error::LoadError;
+use crate::workcontent::loader::run_options::RunOptions;
+
+const MINUTES_PER_HOUR: f64 = 60.0;
+
+const STANDARD_TYPES: [(&str, StandardType); 5] = [
+    ("NONE", StandardType::NONE),
+    ("BASIC", StandardType::BASIC),
+    ("EVENT", StandardType::EVENT),
+    ("ADVANCED", StandardType::ADVANCED),
+    ("SALARIED", StandardType::SALARIED),
+];
+
+const NON_FLOWED_DISTRIBUTION_METHODS: [(&str, NonFlowedDistributionMethod); 5] = [
+    ("BEGINNING", NonFlowedDistributionMethod::BEGINNING),
+    ("MIDDLE", NonFlowedDistributionMethod::MIDDLE),
+    ("END", NonFlowedDistributionMethod::END),
+    ("EVEN", NonFlowedDistributionMethod::EVEN),
+    ("VARYING", NonFlowedDistributionMethod::VARYING),
+];
+
+const SALARY_MODES: [(&str, SalaryMode); 2] = [
+    ("WEEKLY", SalaryMode::WEEKLY),
+    ("MONTHLY", SalaryMode::MONTHLY),
+];
+
+const UNITS: [(&str, Units); 8] = [
+    ("Hours", Units::Hours),
+    ("Minutes", Units::Minutes),
+    ("HoursPerUnit", Units::HoursPerUnit),
+    ("MinutesPerUnit", Units::MinutesPerUnit),
+    ("UnitsPerHour", Units::UnitsPerHour),
+    ("UnitsPerMinute", Units::UnitsPerMinute),
+    ("UnitsPerShift", Units::UnitsPerShift),
+    ("UnitsPerPerson", Units::UnitsPerPerson),
+];
+
+const WORK_TYPES: [(&str, WorkType); 7] = [
+    ("Daily", WorkType::Daily),
+    ("Weekly", WorkType::Weekly),
+    ("Variable", WorkType::Variable),
+    ("RecurringTask", WorkType::RecurringTask),
+    ("Staff", WorkType::Staff),
+    ("ShareWith", WorkType::ShareWith),
+    ("Task", WorkType::Task),
+];
+
+const DISTRIBUTION_METHODS: [(&str, DistributionMethod); 6] = [
+    ("Flowed", DistributionMethod::Flowed),
+    ("FillGaps", DistributionMethod::FillGaps),
+    ("NonFlowed", DistributionMethod::NonFlowed),
+    ("Opening", DistributionMethod::Opening),
+    ("Closing", DistributionMethod::Closing),
+    ("ShareWith", DistributionMethod::ShareWith),
+];
+
+const SPREAD_STANDARD_TYPES: [(&str, SpreadStandardType); 2] = [
+    ("FIXED", SpreadStandardType::FIXED),
+    ("DYNAMIC", SpreadStandardType::DYNAMIC),
+];
+
+/// Reads the `resources/model.json` document: the location, its business drivers with their
+/// dated values, its environments and environment calendar, and its jobs with one or more config
+/// sets of settings, shifts, open dates and standards.
+///
+/// Config sets are either nested in `config.<name>` objects (`model.json`, version 1) or kept in
+/// job level arrays keyed by `configSetId` (`model2.json`, version 2, where open dates are called
+/// `effectiveDates`). A top level `"version"` picks the layout for every job; without it, jobs
+/// with a `settings` array use the flat layout.
+///
+/// Only the config set whose `settings.configSetId` is the planned standard set is loaded for a
+/// job, and jobs without one are left out of the model. Every config set is still checked, so a
+/// bad field is reported wherever it is. Standard values in ranges are for an `environmentId` or
+/// a `day` (`"day": "MON"`), which is loaded as the system environment of that day of week in the
+/// `environments`, see `EnvironmentCalendar::system_environment`. Shift definitions are for a `dayOfWeek`, an `environmentId` or, with neither, every
+/// day of the week. Coverage is not used by the generators and is not read.
+///
+/// The location's `plannerSettings`, one for each `configSetId`, are the defaults of its jobs'
+/// settings for that config set: a setting a job leaves out is inherited from them. Open dates
+/// recur every year, see `PlannerSettings::applies_on`; a config set without any is open all year.
+#[derive(Default)]
+pub struct ModelLoader;
+
+impl ModelLoader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn load_file(&self, path: impl AsRef<Path>, dates: DateRange, standard_set_id: StandardSetId) -> Result<PlannerModel, LoadError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|error| LoadError::io(path, error))?;
+        self.load(&json, dates, standard_set_id)
+    }
+
+    pub fn load(&self, json: &str, dates: DateRange, standard_set_id: StandardSetId) -> Result<PlannerModel, LoadError> {
+        self.load_jobs(json, dates, standard_set_id, |_| true)
+    }
+
+    /// Checks every field of the document, whatever the dates and config set of a run.
+    pub fn validate(&self, json: &str) -> Result<(), LoadError> {
+        let any_date = LocalDate::new(2000, 1, 1);
+        self.load(json, DateRange::new(any_date, any_date), StandardSetId::new()).map(|_| ())
+    }
+
+    /// Loads the model for a run: the run's dates and config set, and only the jobs it lists. The
+    /// document must be for the run's location, and every listed job must be in it for the config
+    /// set.
+    pub fn load_for_run(&self, json: &str, options: &RunOptions) -> Result<PlannerModel, LoadError> {
+        let planner_model = self.load_jobs(json, options.dates(), options.standard_set_id(), |job_id| options.includes_job(job_id))?;
+        let environment_calendar = planner_model.environment_calendar().clone().with_source(options.environment_source());
+        let planner_model = planner_model.with_environment_calendar(environment_calendar);
+
+        if *planner_model.location_id() != options.location_id() {
+            return Err(LoadError::invalid_field(
+                "$.location.id",
+                format!("expected location {} from the run options but was {}", options.location_id(), planner_model.location_id()),
+            ));
+        }
+        for (position, job_id) in options.job_ids().iter().enumerate() {
+            if !planner_model.jobs().iter().any(|job| job.id() == *job_id) {
+                return Err(LoadError::invalid_field(
+                    &options.job_id_path(position),
+                    format!("job {} is not in the model for config set {}", job_id, options.standard_set_id()),
+                ));
+            }
+        }
+
+        Ok(planner_model)
+    }
+
+    fn load_jobs(&self,
+                 json: &str,
+                 dates: DateRange,
+                 standard_set_id: StandardSetId,
+                 includes_job: impl Fn(JobId) -> bool) -> Result<PlannerModel, LoadError> {
+        let value: Value = serde_json::from_str(json)?;
+        let root = JsonNode::root(&value);
+
+        let location = root.field("location")?;
+        let location_id = LocationId::from(location.field("id")?.as_uuid()?);
+        let mut resolver = PlannerSettingsResolver::new();
+        for node in location.optional_array("plannerSettings")? {
+            let standard_set_id = StandardSetId::from(node.field("configSetId")?.as_uuid()?);
+            resolver = resolver.with_property_settings(standard_set_id, Self::settings(&node)?);
+        }
+
+        let mut business_drivers = Vec::new();
+        let mut business_driver_values = Vec::new();
+        for node in root.optional_array("businessDrivers")? {
+            let (business_driver, values) = Self::business_driver(&node, location_id)?;
+            business_drivers.push(business_driver);
+            business_driver_values.push(values);
+        }
+
+        let environment_calendar = Self::environment_calendar(&root)?;
+
+        let layout = match root.optional_field("version") {
+            Some(version) => Some(Self::layout(&version)?),
+            None => None,
+        };
+
+        let mut jobs = Vec::new();
+        for node in root.optional_array("jobs")? {
+            if let Some(job) = Self::job(&node, layout, location_id, standard_set_id, &resolver, &environment_calendar)?
+                && includes_job(job.id()) {
+                jobs.push(job);
+            }
+        }
+
+        Ok(PlannerModel::new(dates, location_id, standard_set_id, jobs, business_drivers, business_driver_values)
+            .with_environment_calendar(environment_calendar))
+    }
+
+    /// The `environments` of the location and the `environmentCalendar` of forecast and actual
+    /// environments for each date, for a business driver or for the whole location.
+    fn environment_calendar(root: &JsonNode) -> Result<EnvironmentCalendar, LoadError> {
+        let mut environments = Vec::new();
+        for node in root.optional_array("environments")? {
+            let mut environment = EnvironmentDefinition::new(
+                Environment::new(node.field("id")?.as_i32()?),
+                node.field("name")?.as_str()?.to_string(),
+            );
+            if let Some(day_of_week) = node.optional_field("dayOfWeek") {
+                environment = environment.with_day_of_week(day_of_week.as_day_of_week()?);
+            }
+            if let Some(ignore_day_of_week) = node.optional_field("ignoreDayOfWeek") {
+                environment = environment.with_ignore_day_of_week(ignore_day_of_week.as_bool()?);
+            }
+            if let Some(system_environment) = node.optional_field("systemEnvironment") {
+                environment = environment.with_system_environment(system_environment.as_bool()?);
+            }
+            environments.push(environment);
+        }
+
+        let optional_environment = |node: &JsonNode, name: &str| match node.optional_field(name) {
+            Some(field) => field.as_i32().map(|id| Some(Environment::new(id))),
+            None => Ok(None),
+        };
+        let mut stats = Vec::new();
+        for node in root.optional_array("environmentCalendar")? {
+            let business_driver_id = match node.optional_field("businessDriverId") {
+                Some(field) => Some(BusinessDriverId::from(field.as_uuid()?)),
+                None => None,
+            };
+            stats.push(EnvironmentStat::new(
+                node.field("date")?.as_date()?,
+                business_driver_id,
+                optional_environment(&node, "forecastEnvironmentId")?,
+                optional_environment(&node, "actualEnvironmentId")?,
+            ));
+        }
+
+        Ok(EnvironmentCalendar::new(environments, stats))
+    }
+
+    fn business_driver(node: &JsonNode, location_id: LocationId) -> Result<(BusinessDriver, BusinessDriverValues), LoadError> {
+        let id = BusinessDriverId::from(node.field("id")?.as_uuid()?);
+        let name = match node.optional_field("name") {
+            Some(name) => name.as_str()?.to_string(),
+            None => String::new(),
+        };
+
+        let mut values = HashMap::new();
+        for value in node.optional_array("values")? {
+            values.insert(value.field("date")?.as_date()?, value.field("value")?.as_u32()?);
+        }
+
+        Ok((BusinessDriver::new(id, location_id).with_name(name), BusinessDriverValues::new(id, values)))
+    }
+
+    fn job(node: &JsonNode,
+           layout: Option<ModelLayout>,
+           location_id: LocationId,
+           standard_set_id: StandardSetId,
+           resolver: &PlannerSettingsResolver,
+           environment_calendar: &EnvironmentCalendar) -> Result<Option<Job>, LoadError> {
+        let job_id = JobId::from(node.field("id")?.as_uuid()?);
+
+        let layout = match layout {
+            Some(layout) => layout,
+            None if node.has_field("settings") => ModelLayout::Flat,
+            None => ModelLayout::Nested,
+        };
+        let config_set_nodes = match layout {
+            ModelLayout::Nested => Self::nested_config_sets(node)?,
+            ModelLayout::Flat => Self::flat_config_sets(node)?,
+        };
+
+        let mut config_sets = Vec::new();
+        for nodes in config_set_nodes {
+            config_sets.push(Self::config_set(nodes, job_id, resolver, environment_calendar)?);
+        }
+
+        let Some(config_set) = config_sets.into_iter()
+            .find(|config_set| config_set.standard_set_id == standard_set_id) else {
+            return Ok(None);
+        };
+
+        Ok(Some(Job::new(location_id, config_set.settings, config_set.shifts, config_set.salaried_standards)
+            .with_id(job_id)
+            .with_basic_standards(config_set.basic_standards)
+            .with_shift_related_standards(config_set.shift_related_standards)
+            .with_spread_standards(config_set.spread_standards)))
+    }
+
+    /// `config.<name>` objects, each holding the settings, shifts, open dates and standards of
+    /// one config set.
+    fn nested_config_sets<'a>(node: &JsonNode<'a>) -> Result<Vec<ConfigSetNodes<'a>>, LoadError> {
+        let mut config_sets = Vec::new();
+        for (_, config_set) in node.field("config")?.entries()? {
+            let settings = config_set.field("settings")?;
+            let standard_set_id = StandardSetId::from(settings.field("configSetId")?.as_uuid()?);
+            let standards = config_set.optional_field("standards");
+            let standards_for = |name: &str| match &standards {
+                Some(standards) => standards.optional_array(name),
+                None => Ok(Vec::new()),
+            };
+
+            config_sets.push(ConfigSetNodes {
+                standard_set_id,
+                settings,
+                effective_dates: config_set.optional_array("openDates")?,
+                shifts: config_set.optional_array("shifts")?,
+                salaried: standards_for("salaried")?,
+                basic: standards_for("basic")?,
+                advanced: standards_for("advanced")?,
+                spread: standards_for("spread")?,
+            });
+        }
+        Ok(config_sets)
+    }
+
+    /// Job level `settings`, `shifts`, `effectiveDates` and `standards.<type>` arrays whose
+    /// entries name their config set with `configSetId`. Each entry of `settings` is a config set.
+    fn flat_config_sets<'a>(node: &JsonNode<'a>) -> Result<Vec<ConfigSetNodes<'a>>, LoadError> {
+        let mut config_sets = Vec::new();
+        for settings in node.field("settings")?.array()? {
+            config_sets.push(ConfigSetNodes {
+                standard_set_id: StandardSetId::from(settings.field("configSetId")?.as_uuid()?),
+                settings,
+                effective_dates: Vec::new(),
+                shifts: Vec::new(),
+                salaried: Vec::new(),
+                basic: Vec::new(),
+                advanced: Vec::new(),
+                spread: Vec::new(),
+            });
+        }
+
+        for effective_dates in node.optional_array("effectiveDates")? {
+            Self::config_set_for_entry(&mut config_sets, &effective_dates)?.effective_dates.push(effective_dates);
+        }
+        for shift in node.optional_array("shifts")? {
+            Self::config_set_for_entry(&mut config_sets, &shift)?.shifts.push(shift);
+        }
+        if let Some(standards) = node.optional_field("standards") {
+            for standard in standards.optional_array("salaried")? {
+                Self::config_set_for_entry(&mut config_sets, &standard)?.salaried.push(standard);
+            }
+            for standard in standards.optional_array("basic")? {
+                Self::config_set_for_entry(&mut config_sets, &standard)?.basic.push(standard);
+            }
+            for standard in standards.optional_array("advanced")? {
+                Self::config_set_for_entry(&mut config_sets, &standard)?.advanced.push(standard);
+            }
+            for standard in standards.optional_array("spread")? {
+                Self::config_set_for_entry(&mut config_sets, &standard)?.spread.push(standard);
+            }
+        }
+
+        Ok(config_sets)
+    }
+
+    fn config_set_for_entry<'a, 'b>(config_sets: &'b mut [ConfigSetNodes<'a>], entry: &JsonNode) -> Result<&'b mut ConfigSetNodes<'a>, LoadError> {
+        let field = entry.field("configSetId")?;
+        let standard_set_id = StandardSetId::from(field.as_uuid()?);
+        config_sets.iter_mut()
+            .find(|config_set| config_set.standard_set_id == standard_set_id)
+            .ok_or_else(|| field.error(format!("no settings for config set {}", standard_set_id)))
+    }
+
+    fn config_set(nodes: ConfigSetNodes,
+                  job_id: JobId,
+                  resolver: &PlannerSettingsResolver,
+                  environment_calendar: &EnvironmentCalendar) -> Result<ConfigSet, LoadError> {
+        let standard_set_id = nodes.standard_set_id;
+        let mut settings = resolver.resolve(standard_set_id, &Self::settings(&nodes.settings)?);
+
+        if !nodes.effective_dates.is_empty() {
+            settings.effective_dates = Vec::new();
+            for effective_dates in &nodes.effective_dates {
+                settings.effective_dates.push(Self::effective_dates(effective_dates)?);
+            }
+        }
+
+        let mut shifts = Vec::new();
+        for shift in &nodes.shifts {
+            shifts.push(Self::shift(shift, job_id, standard_set_id)?);
+        }
+
+        let mut config_set = ConfigSet {
+            standard_set_id,
+            settings,
+            shifts,
+            salaried_standards: Vec::new(),
+            basic_standards: Vec::new(),
+            shift_related_standards: Vec::new(),
+            spread_standards: Vec::new(),
+        };
+
+        for standard in &nodes.salaried {
+            let salaried_standard = Self::salaried_standard(standard, job_id, standard_set_id, &config_set.shifts)?;
+            config_set.salaried_standards.push(salaried_standard);
+        }
+        for standard in &nodes.basic {
+            config_set.basic_standards.push(Self::basic_standard(standard, job_id, standard_set_id)?);
+        }
+        for standard in &nodes.advanced {
+            config_set.shift_related_standards.push(Self::shift_related_standard(standard, job_id, standard_set_id, environment_calendar)?);
+        }
+        for standard in &nodes.spread {
+            config_set.spread_standards.push(Self::spread_standard(standard, job_id, standard_set_id, environment_calendar)?);
+        }
+
+        Ok(config_set)
+    }
+
+    /// The settings given in a job's config set or in the location's `plannerSettings`. Settings
+    /// left out inherit the location's settings for the config set, then the defaults. Break
+    /// lengths are given in minutes and kept in hours, like every other length of the settings.
+    fn settings(node: &JsonNode) -> Result<PlannerSettingsLayer, LoadError> {
+        let optional_f64 = |name: &str| match node.optional_field(name) {
+            Some(field) => field.as_f64().map(Some),
+            None => Ok(None),
+        };
+        let optional_minutes_as_hours = |name: &str| optional_f64(name).map(|minutes| minutes.map(|minutes| minutes / MINUTES_PER_HOUR));
+        let optional_bool = |name: &str| match node.optional_field(name) {
+            Some(field) => field.as_bool().map(Some),
+            None => Ok(None),
+        };
+
+        let mut settings = PlannerSettingsLayer {
+            min_shift_length: optional_f64("minShift")?,
+            max_shift_length: optional_f64("maxShift")?,
+            rounding_threshold_below_one: optional_f64("roundingThresholdBelowOne")?,
+            rounding_threshold_above_one: optional_f64("roundingThresholdAboveOne")?,
+            meal_break_after: optional_f64("mealBreakAfter")?,
+            meal_break_length: optional_minutes_as_hours("mealBreakLength")?,
+            non_meal_break_every: optional_f64("nonMealBreakEvery")?,
+            non_meal_break_length: optional_minutes_as_hours("nonMealBreakLength")?,
+            generate_long_shifts: optional_bool("generateLongShifts")?,
+            limit_shift_to_max_shift: optional_bool("limitShiftToMaxShift")?,
+            truncate_max_coverage: optional_bool("truncateMaxCoverage")?,
+            ..PlannerSettingsLayer::default()
+        };
+        if let Some(field) = node.optional_field("standardType") {
+            settings.standard_type = Some(field.as_enum(&STANDARD_TYPES)?);
+        }
+        if let Some(field) = node.optional_field("periodLength") {
+            let period_length = field.as_u32()?;
+            if period_length == 0 {
+                return Err(field.error("must be greater than 0"));
+            }
+            settings.period_length = Some(period_length);
+        }
+        if let Some(field) = node.optional_field("defaultNonFlowedDistributionMethod") {
+            settings.non_flowed_distribution_method = Some(field.as_enum(&NON_FLOWED_DISTRIBUTION_METHODS)?);
+        }
+
+        Ok(settings)
+    }
+
+    /// A definition without a `dayOfWeek` applies to every day of the week.
+    fn shift(node: &JsonNode, job_id: JobId, standard_set_id: StandardSetId) -> Result<JobShift, LoadError> {
+        let id = JobShiftId::from(node.field("id")?.as_uuid()?);
+        let name = match node.optional_field("name") {
+            Some(name) => name.as_str()?.to_string(),
+            None => String::new(),
+        };
+        let sequence = match node.optional_field("sequence") {
+            Some(sequence) => sequence.as_u32()?,
+            None => 0,
+        };
+
+        let mut definitions = Vec::new();
+        for definition in node.optional_array("definitions")? {
+            let start_time = definition.field("startTime")?.as_time()?;
+            let end_time = definition.field("endTime")?.as_time()?;
+            let hours_before = Self::optional_f64(&definition, "hoursBefore")?;
+            let hours_after = Self::optional_f64(&definition, "hoursAfter")?;
+            let optional_u32 = |name: &str| match definition.optional_field(name) {
+                Some(field) => field.as_u32(),
+                None => Ok(0),
+            };
+            let min_number_shifts = optional_u32("minNumberShifts")?;
+            let shift_grouping_for_same_event = optional_u32("shiftGroupingForSameEvent")?;
+            let weighting_factor = optional_u32("weightingFactor")?;
+
+            let shift_definitions = match (definition.optional_field("environmentId"), definition.optional_field("dayOfWeek")) {
+                (Some(_), Some(day_of_week)) => return Err(day_of_week.error("not allowed with environmentId")),
+                (Some(environment_id), None) => vec![
+                    JobShiftDefinition::for_environment(Environment::new(environment_id.as_i32()?), start_time, end_time, hours_before, hours_after, min_number_shifts),
+                ],
+                (None, Some(day_of_week)) => vec![
+                    JobShiftDefinition::new(day_of_week.as_day_of_week()?, start_time, end_time, hours_before, hours_after, min_number_shifts),
+                ],
+                (None, None) => (1..=7)
+                    .map(|day_of_week| JobShiftDefinition::new(DayOfWeek::of(day_of_week), start_time, end_time, hours_before, hours_after, min_number_shifts))
+                    .collect(),
+            };
+            definitions.extend(shift_definitions.into_iter().map(|shift_definition| shift_definition
+                .with_shift_grouping_for_same_event(shift_grouping_for_same_event)
+                .with_weighting_factor(weighting_factor)));
+        }
+
+        Ok(JobShift::new(job_id, standard_set_id, name, sequence, definitions).with_id(id))
+    }
+
+    fn salaried_standard(node: &JsonNode, job_id: JobId, standard_set_id: StandardSetId, shifts: &[JobShift]) -> Result<SalariedStandard, LoadError> {
+        let shift = Self::shift_for_id(node, shifts)?;
+
+        Ok(SalariedStandard {
+            job_id,
+            standard_set_id,
+            shift: shift.clone(),
+            salary_mode: node.field("salaryMode")?.as_enum(&SALARY_MODES)?,
+            hours_per_week: Self::optional_f64(node, "hoursPerWeek")?,
+            vacation_hours_per_year: Self::optional_f64(node, "vacationHoursPerYear")?,
+            hours_per_year: Self::optional_f64(node, "hoursPerYear")?,
+        })
+    }
+
+    fn basic_standard(node: &JsonNode, job_id: JobId, standard_set_id: StandardSetId) -> Result<BasicStandard, LoadError> {
+        Ok(BasicStandard {
+            job_id,
+            standard_set_id,
+            shift_id: JobShiftId::from(node.field("shiftId")?.as_uuid()?),
+            business_driver_id: match node.optional_field("businessDriverId") {
+                Some(field) => Some(BusinessDriverId::from(field.as_uuid()?)),
+                None => None,
+            },
+            units: node.field("units")?.as_enum(&UNITS)?,
+            standard_value: node.field("standardValue")?.as_f64()?,
+        })
+    }
+
+    fn shift_related_standard(node: &JsonNode,
+                              job_id: JobId,
+                              standard_set_id: StandardSetId,
+                              environment_calendar: &EnvironmentCalendar) -> Result<ShiftRelatedStandard, LoadError> {
+        let mut ranges = Vec::new();
+        for range in node.optional_array("ranges")? {
+            let mut values = Vec::new();
+            for day in range.optional_array("days")? {
+                values.push(ShiftRelatedStandardValue::new(
+                    Self::environment(&day, environment_calendar)?,
+                    day.field("value")?.as_f64()?,
+                ));
+            }
+            ranges.push(ShiftRelatedRange::new(
+                range.field("fromValue")?.as_i32()?,
+                range.field("toValue")?.as_i32()?,
+                values,
+            ));
+        }
+
+        Ok(ShiftRelatedStandard {
+            job_id,
+            standard_set_id,
+            shift_id: JobShiftId::from(node.field("shiftId")?.as_uuid()?),
+            business_driver_id: BusinessDriverId::from(node.field("businessDriverId")?.as_uuid()?),
+            name: match node.optional_field("name") {
+                Some(name) => name.as_str()?.to_string(),
+                None => String::new(),
+            },
+            work_type: node.field("workType")?.as_enum(&WORK_TYPES)?,
+            units: node.field("units")?.as_enum(&UNITS)?,
+            distribution_method: node.field("distributionMethod")?.as_enum(&DISTRIBUTION_METHODS)?,
+            non_flowed_distribution_method: match node.optional_field("nonFlowedDistributionMethod") {
+                Some(field) => Some(field.as_enum(&NON_FLOWED_DISTRIBUTION_METHODS)?),
+                None => None,
+            },
+            flow_plan_id: match node.optional_field("flowPlanId") {
+                Some(field) => Some(FlowPlanId::from(field.as_uuid()?)),
+                None => None,
+            },
+            ignore_retention: match node.optional_field("ignoreRetention") {
+                Some(field) => field.as_bool()?,
+                None => false,
+            },
+            earliest_work_start_time: match node.optional_field("earliestWorkStartTime") {
+                Some(field) => Some(field.as_time()?),
+                None => None,
+            },
+            latest_work_end_time: match node.optional_field("latestWorkEndTime") {
+                Some(field) => Some(field.as_time()?),
+                None => None,
+            },
+            ranges,
+            task_standard_details: Vec::new(),
+        })
+    }
+
+    fn spread_standard(node: &JsonNode,
+                       job_id: JobId,
+                       standard_set_id: StandardSetId,
+                       environment_calendar: &EnvironmentCalendar) -> Result<SpreadStandard, LoadError> {
+        let mut ranges = Vec::new();
+        for range in node.optional_array("ranges")? {
+            let mut values = Vec::new();
+            for day in range.optional_array("days")? {
+                let mut spread_values = Vec::new();
+                for spread_value in day.optional_array("spreadValues")? {
+                    spread_values.push(spread_value.as_u32()?);
+                }
+                values.push(SpreadStandardValue::new(Self::environment(&day, environment_calendar)?, spread_values));
+            }
+            ranges.push(SpreadStandardRange::new(
+                range.field("fromValue")?.as_i32()?,
+                range.field("toValue")?.as_i32()?,
+            ).with_values(values));
+        }
+
+        Ok(SpreadStandard {
+            job_id,
+            standard_set_id,
+            shift_id: JobShiftId::from(node.field("shiftId")?.as_uuid()?),
+            business_driver_id: BusinessDriverId::from(node.field("businessDriverId")?.as_uuid()?),
+            spread_standard_type: match node.optional_field("spreadStandardType") {
+                Some(field) => field.as_enum(&SPREAD_STANDARD_TYPES)?,
+                None => SpreadStandardType::FIXED,
+            },
+            units: match node.optional_field("units") {
+                Some(field) => Some(field.as_enum(&UNITS)?),
+                None => None,
+            },
+            ranges,
+        })
+    }
+
+    fn shift_for_id<'a>(node: &JsonNode, shifts: &'a [JobShift]) -> Result<&'a JobShift, LoadError> {
+        let field = node.field("shiftId")?;
+        let shift_id = JobShiftId::from(field.as_uuid()?);
+        shifts.iter()
+            .find(|shift| *shift.id() == shift_id)
+            .ok_or_else(|| field.error(format!("no shift {} in the config set", shift_id)))
+    }
+
+    fn layout(node: &JsonNode) -> Result<ModelLayout, LoadError> {
+        match node.as_u32()? {
+            1 => Ok(ModelLayout::Nested),
+            2 => Ok(ModelLayout::Flat),
+            version => Err(node.error(format!("unknown version {}, expected 1 or 2", version))),
+        }
+    }
+
+    /// One of the `openDates` or `effectiveDates` of a config set, which must not end before it
+    /// starts.
+    fn effective_dates(node: &JsonNode) -> Result<DateRange, LoadError> {
+        let start_date = node.field("startDate")?.as_date()?;
+        let end_date_node = node.field("endDate")?;
+        let end_date = end_date_node.as_date()?;
+        if end_date.is_before(start_date) {
+            return Err(end_date_node.error("must not be before startDate"));
+        }
+        Ok(DateRange::new(start_date, end_date))
+    }
+
+    /// The `environmentId` of a standard value, or the system environment of its `day`.
+    fn environment(node: &JsonNode, environment_calendar: &EnvironmentCalendar) -> Result<Environment, LoadError> {
+        match (node.optional_field("environmentId"), node.optional_field("day")) {
+            (Some(_), Some(day)) => Err(day.error("not allowed with environmentId")),
+            (Some(environment_id), None) => Ok(Environment::new(environment_id.as_i32()?)),
+            (None, _) => Ok(environment_calendar.system_environment(node.field("day")?.as_day_of_week()?)),
+        }
+    }
+
+    fn optional_f64(node: &JsonNode, name: &str) -> Result<f64, LoadError> {
+        match node.optional_field(name) {
+            Some(field) => field.as_f64(),
+            None => Ok(0.0),
+        }
+    }
+}
+
+/// How the config sets of a job are laid out in the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModelLayout {
+    /// Version 1, `model.json`: one `config.<name>` object for each config set.
+    Nested,
+    /// Version 2, `model2.json`: job level arrays keyed by `configSetId`.
+    Flat,
+}
+
+/// The parts of a document making up one config set, whichever layout they came from.
+struct ConfigSetNodes<'a> {
+    standard_set_id: StandardSetId,
+    settings: JsonNode<'a>,
+    effective_dates: Vec<JsonNode<'a>>,
+    shifts: Vec<JsonNode<'a>>,
+    salaried: Vec<JsonNode<'a>>,
+    basic: Vec<JsonNode<'a>>,
+    advanced: Vec<JsonNode<'a>>,
+    spread: Vec<JsonNode<'a>>,
+}
+
+struct ConfigSet {
+    standard_set_id: StandardSetId,
+    settings: PlannerSettings,
+    shifts: Vec<JobShift>,
+    salaried_standards: Vec<SalariedStandard>,
+    basic_standards: Vec<BasicStandard>,
+    shift_related_standards: Vec<ShiftRelatedStandard>,
+    spread_standards: Vec<SpreadStandard>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::LocalTime;
+    use crate::workcontent::domain::environment_calendar::EnvironmentSource;
+    use crate::workcontent::domain::planner_settings_resolver::SettingSource;
+    use crate::workcontent::generators::basic::basic_calculator::BasicCalculator;
+    use crate::workcontent::main::main::generate_work_content;
+
+    const LOCATION_ID: &str = "7d4f6a0e-1111-4a5b-9c2d-000000000001";
+    const DRIVER_ID: &str = "7d4f6a0e-2222-4a5b-9c2d-000000000001";
+    const JOB_ID: &str = "7d4f6a0e-3333-4a5b-9c2d-000000000001";
+    const CONFIG_SET_ID: &str = "7d4f6a0e-4444-4a5b-9c2d-000000000001";
+    const OTHER_CONFIG_SET_ID: &str = "7d4f6a0e-4444-4a5b-9c2d-000000000002";
+    const SHIFT_ID: &str = "7d4f6a0e-5555-4a5b-9c2d-000000000001";
+
+    fn model_json(period_length: &str) -> String {
+        format!(r#"{{
+            "location": {{ "id": "{LOCATION_ID}", "name": "Harbour Hotel" }},
+            "businessDrivers": [
+                {{
+                    "id": "{DRIVER_ID}",
+                    "name": "Occupied rooms",
+                    "values": [
+                        {{ "date": "2025-01-06", "value": 120 }},
+                        {{ "date": "2025-01-07", "value": "80" }}
+                    ]
+                }}
+            ],
+            "jobs": [
+                {{
+                    "id": "{JOB_ID}",
+                    "name": "Room attendant",
+                    "config": {{
+                        "configSet1": {{
+                            "settings": {{
+                                "configSetId": "{CONFIG_SET_ID}",
+                                "standardType": "BASIC",
+                                "periodLength": {period_length},
+                                "maxShift": 10.0,
+                                "mealBreakAfter": 4.0,
+                                "mealBreakLength": 30.0,
+                                "generateLongShifts": true,
+                                "defaultNonFlowedDistributionMethod": ""
+                            }},
+                            "shifts": [
+                                {{
+                                    "id": "{SHIFT_ID}",
+                                    "sequence": 1,
+                                    "name": "Day",
+                                    "wage": 123.45,
+                                    "definitions": [
+                                        {{ "startTime": "07:00", "endTime": "15:00", "hoursBefore": 1.0, "hoursAfter": 0.5, "minNumberShifts": 2 }}
+                                    ]
+                                }}
+                            ],
+                            "openDates": [
+                                {{ "startDate": "2025-01-01", "endDate": "2025-06-30" }}
+                            ],
+                            "coverage": [],
+                            "standards": {{
+                                "salaried": [],
+                                "basic": [
+                                    {{ "shiftId": "{SHIFT_ID}", "businessDriverId": "{DRIVER_ID}", "units": "MinutesPerUnit", "standardValue": 30.0 }}
+                                ],
+                                "advanced": [],
+                                "spread": [
+                                    {{
+                                        "shiftId": "{SHIFT_ID}",
+                                        "businessDriverId": "{DRIVER_ID}",
+                                        "ranges": [
+                                            {{ "fromValue": 1, "toValue": 1000, "days": [ {{ "day": "MON", "spreadValues": [1, 2, 3] }} ] }}
+                                        ]
+                                    }}
+                                ]
+                            }}
+                        }},
+                        "configSet2": {{
+                            "settings": {{ "configSetId": "{OTHER_CONFIG_SET_ID}", "standardType": "SALARIED" }}
+                        }}
+                    }}
+                }}
+            ]
+        }}"#)
+    }
+
+    fn flat_model_json(version: &str, period_length: &str, shift_config_set_id: &str) -> String {
+        format!(r#"{{
+            {version}
+            "location": {{ "id": "{LOCATION_ID}" }},
+            "jobs": [
+                {{
+                    "id": "{JOB_ID}",
+                    "settings": [
+                        {{ "configSetId": "{CONFIG_SET_ID}", "standardType": "BASIC", "periodLength": {period_length} }},
+                        {{ "configSetId": "{OTHER_CONFIG_SET_ID}", "standardType": "SALARIED" }}
+                    ],
+                    "shifts": [
+                        {{
+                            "id": "{SHIFT_ID}",
+                            "configSetId": "{shift_config_set_id}",
+                            "name": "Day",
+                            "definitions": [ {{ "dayOfWeek": "MON", "startTime": "07:00", "endTime": "15:00" }} ]
+                        }}
+                    ],
+                    "effectiveDates": [
+                        {{ "configSetId": "{CONFIG_SET_ID}", "startDate": "2025-01-01", "endDate": "2025-06-30" }}
+                    ],
+                    "coverage": [],
+                    "standards": {{
+                        "basic": [
+                            {{ "configSetId": "{CONFIG_SET_ID}", "shiftId": "{SHIFT_ID}", "units": "Hours", "standardValue": 8.0 }}
+                        ]
+                    }}
+                }}
+            ]
+        }}"#)
+    }
+
+    fn dates() -> DateRange {
+        DateRange::new(LocalDate::new(2025, 1, 6), LocalDate::new(2025, 1, 12))
+    }
+
+    fn standard_set_id() -> StandardSetId {
+        StandardSetId::from(uuid::Uuid::parse_str(CONFIG_SET_ID).unwrap())
+    }
+
+    #[test]
+    fn loads_the_planner_model() {
+        let model = ModelLoader::new().load(&model_json("15"), dates(), standard_set_id()).unwrap();
+        let driver_id = BusinessDriverId::from(uuid::Uuid::parse_str(DRIVER_ID).unwrap());
+
+        assert_eq!(model.location_id().to_string(), LOCATION_ID);
+        assert_eq!(model.business_drivers()[0].name(), "Occupied rooms");
+        assert_eq!(model.value_for(driver_id, LocalDate::new(2025, 1, 6)), 120);
+        assert_eq!(model.value_for(driver_id, LocalDate::new(2025, 1, 7)), 80);
+
+        let job = &model.jobs()[0];
+        assert_eq!(job.id().to_string(), JOB_ID);
+        assert_eq!(job.planner_settings().standard_type, StandardType::BASIC);
+        assert_eq!(job.planner_settings().period_length, 15);
+        assert_eq!(job.planner_settings().max_shift_length, 10.0);
+        assert_eq!(job.planner_settings().min_shift_length, 4.0);
+        assert_eq!(job.planner_settings().meal_break.as_ref().unwrap().break_length, 0.5);
+        assert!(job.planner_settings().non_meal_break.is_none());
+        assert_eq!(job.planner_settings().non_flowed_distribution_method, NonFlowedDistributionMethod::VARYING);
+        assert_eq!(job.planner_settings().effective_dates[0].end_date(), LocalDate::new(2025, 6, 30));
+
+        let shift = &job.shifts()[0];
+        assert_eq!(shift.id().to_string(), SHIFT_ID);
+        assert_eq!(shift.shift_definitions().len(), 7);
+        let definition = shift.shift_detail_for_date(None, LocalDate::new(2025, 1, 11)).unwrap();
+        assert_eq!(definition.start_time(), LocalTime::new(7, 0, 0));
+        assert_eq!(definition.hours_after(), 0.5);
+        assert_eq!(definition.min_number_shifts(), 2);
+
+        assert_eq!(job.basic_standards()[0].units, Units::MinutesPerUnit);
+        assert_eq!(job.basic_standards()[0].business_driver_id, Some(driver_id));
+        assert_eq!(job.spread_standards()[0].ranges[0].values_for_environment(Environment::new(1)).unwrap().spread_values(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn loads_the_environment_calendar() {
+        let json = model_json("30").replacen(r#""businessDrivers": ["#, r#""environments": [
+                { "id": 20, "name": "High Season", "ignoreDayOfWeek": true },
+                { "id": 101, "name": "Monday", "dayOfWeek": "MON", "systemEnvironment": true }
+            ],
+            "environmentCalendar": [
+                { "date": "2025-01-07", "forecastEnvironmentId": 20 },
+                { "date": "2025-01-08", "businessDriverId": "BUSINESS_DRIVER", "forecastEnvironmentId": 20, "actualEnvironmentId": 21 }
+            ],
+            "businessDrivers": ["#, 1).replace("BUSINESS_DRIVER", DRIVER_ID);
+        let driver_id = BusinessDriverId::from(uuid::Uuid::parse_str(DRIVER_ID).unwrap());
+        let options = RunOptions::new(dates(), LocationId::from(uuid::Uuid::parse_str(LOCATION_ID).unwrap()), standard_set_id(), vec![]);
+
+        let forecast = ModelLoader::new().load_for_run(&json, &options).unwrap();
+        let actual = ModelLoader::new().load_for_run(&json, &options.with_environment_source(EnvironmentSource::Actual)).unwrap();
+
+        let monday = LocalDate::new(2025, 1, 6);
+        assert_eq!(forecast.environment_calendar().environments()[0].name(), "High Season");
+        assert_eq!(forecast.environment_for_date(monday), Environment::new(101));
+        assert_eq!(forecast.environment_for_date(monday.plus_days(1)), Environment::new(20));
+        assert_eq!(forecast.environment_for(driver_id, monday.plus_days(2)), Environment::new(20));
+        assert_eq!(actual.environment_for(driver_id, monday.plus_days(2)), Environment::new(21));
+        assert_eq!(actual.environment_for_date(monday.plus_days(2)), Environment::new(3));
+    }
+
+    #[test]
+    fn standard_days_are_the_system_environments_of_the_day() {
+        let json = model_json("30")
+            .replacen(r#""businessDrivers": ["#, r#""environments": [
+                { "id": 20, "name": "High Season", "ignoreDayOfWeek": true },
+                { "id": 101, "name": "Monday", "dayOfWeek": "MON", "systemEnvironment": true }
+            ],
+            "businessDrivers": ["#, 1)
+            .replace(r#""standardType": "BASIC""#, r#""standardType": "ADVANCED""#)
+            .replace(r#""advanced": []"#, &format!(r#""advanced": [
+                {{
+                    "shiftId": "{SHIFT_ID}",
+                    "businessDriverId": "{DRIVER_ID}",
+                    "name": "Check-ins",
+                    "workType": "Variable",
+                    "units": "MinutesPerUnit",
+                    "distributionMethod": "NonFlowed",
+                    "nonFlowedDistributionMethod": "EVEN",
+                    "ranges": [
+                        {{ "fromValue": 1, "toValue": 1000, "days": [ {{ "day": "MON", "value": 3.0 }}, {{ "environmentId": 20, "value": 5.0 }} ] }}
+                    ]
+                }}
+            ]"#));
+
+        let model = ModelLoader::new().load(&json, dates(), standard_set_id()).unwrap();
+
+        let job = &model.jobs()[0];
+        let range = &job.shift_related_standards()[0].ranges[0];
+        assert_eq!(range.value_for_environment(Environment::new(101)), Some(3.0));
+        assert_eq!(range.value_for_environment(Environment::new(20)), Some(5.0));
+        assert_eq!(range.value_for_environment(Environment::new(1)), None);
+        assert_eq!(job.spread_standards()[0].ranges[0].values_for_environment(Environment::new(101)).unwrap().spread_values(), &[1, 2, 3]);
+
+        // Monday is system environment 101 and has work; Tuesday has no standard value
+        let results = generate_work_content(model);
+        let work_contents = results[0].work_contents();
+        assert!(!work_contents.is_empty());
+        assert!(work_contents.iter().all(|work_content| work_content.shift_date() == LocalDate::new(2025, 1, 6)));
+        // 120 rooms at 3 minutes each, spread evenly over the sixteen half hours of the shift
+        let check_ins: Vec<f64> = work_contents.iter()
+            .filter(|work_content| work_content.description() == "Check-ins")
+            .map(|work_content| work_content.calculated_hours())
+            .collect();
+        assert_eq!(check_ins, vec![0.38; 16]);
+    }
+
+    #[test]
+    fn jobs_without_the_config_set_are_left_out() {
+        let model = ModelLoader::new().load(&model_json("30"), dates(), StandardSetId::new()).unwrap();
+
+        assert!(model.jobs().is_empty());
+    }
+
+    #[test]
+    fn run_options_choose_the_dates_config_set_and_jobs() {
+        let location_id = LocationId::from(uuid::Uuid::parse_str(LOCATION_ID).unwrap());
+        let job_id = JobId::from(uuid::Uuid::parse_str(JOB_ID).unwrap());
+        let other_standard_set_id = StandardSetId::from(uuid::Uuid::parse_str(OTHER_CONFIG_SET_ID).unwrap());
+
+        let all_jobs = RunOptions::new(dates(), location_id, other_standard_set_id, vec![]);
+        let model = ModelLoader::new().load_for_run(&model_json("30"), &all_jobs).unwrap();
+        assert_eq!(model.standard_set_id(), other_standard_set_id);
+        assert_eq!(model.dates().start_date(), LocalDate::new(2025, 1, 6));
+        assert_eq!(model.jobs()[0].planner_settings().standard_type, StandardType::SALARIED);
+
+        let listed_job = RunOptions::new(dates(), location_id, standard_set_id(), vec![job_id]);
+        assert_eq!(ModelLoader::new().load_for_run(&model_json("30"), &listed_job).unwrap().jobs().len(), 1);
+
+    }
+
+    #[test]
+    fn a_listed_job_missing_for_the_config_set_is_an_error() {
+        let location_id = LocationId::from(uuid::Uuid::parse_str(LOCATION_ID).unwrap());
+        let job_id = JobId::from(uuid::Uuid::parse_str(JOB_ID).unwrap());
+        let options = RunOptions::new(dates(), location_id, standard_set_id(), vec![job_id, JobId::new()]);
+
+        let error = ModelLoader::new().load_for_run(&model_json("30"), &options).err().unwrap();
+
+        assert!(matches!(error, LoadError::InvalidField { path, .. } if path == "$.jobIds[1]"));
+    }
+
+    #[test]
+    fn run_options_for_another_location_are_an_error() {
+        let options = RunOptions::new(dates(), LocationId::new(), standard_set_id(), vec![]);
+
+        let error = ModelLoader::new().load_for_run(&model_json("30"), &options).err().unwrap();
+
+        assert!(matches!(error, LoadError::InvalidField { path, .. } if path == "$.location.id"));
+    }
+
+    #[test]
+    fn bad_fields_are_reported_with_their_path() {
+        let error = ModelLoader::new().load(&model_json("\"half hour\""), dates(), standard_set_id()).err().unwrap();
+
+        assert_eq!(error, LoadError::invalid_field(
+            "$.jobs[0].config.configSet1.settings.periodLength",
+            "expected a number but was \"half hour\"",
+        ));
+    }
+
+    #[test]
+    fn validation_checks_every_config_set() {
+        assert_eq!(ModelLoader::new().validate(&model_json("30")), Ok(()));
+        assert!(matches!(
+            ModelLoader::new().validate(&model_json("\"half hour\"")),
+            Err(LoadError::InvalidField { path, .. }) if path == "$.jobs[0].config.configSet1.settings.periodLength"
+        ));
+    }
+
+    #[test]
+    fn loads_the_flat_layout_into_the_same_model() {
+        for version in ["", "\"version\": 2,"] {
+            let model = ModelLoader::new().load(&flat_model_json(version, "15", CONFIG_SET_ID), dates(), standard_set_id()).unwrap();
+
+            let job = &model.jobs()[0];
+            assert_eq!(job.id().to_string(), JOB_ID);
+            assert_eq!(job.planner_settings().standard_type, StandardType::BASIC);
+            assert_eq!(job.planner_settings().period_length, 15);
+            assert_eq!(job.planner_settings().effective_dates[0].end_date(), LocalDate::new(2025, 6, 30));
+            assert_eq!(job.shifts()[0].id().to_string(), SHIFT_ID);
+            assert_eq!(job.shifts()[0].shift_definitions().len(), 1);
+            assert_eq!(job.basic_standards()[0].standard_value, 8.0);
+        }
+
+        let other_model = ModelLoader::new().load(&flat_model_json("", "15", CONFIG_SET_ID), dates(), StandardSetId::from(uuid::Uuid::parse_str(OTHER_CONFIG_SET_ID).unwrap())).unwrap();
+        assert_eq!(other_model.jobs()[0].planner_settings().standard_type, StandardType::SALARIED);
+        assert!(other_model.jobs()[0].shifts().is_empty());
+    }
+
+    #[test]
+    fn loads_every_effective_date_range_of_the_config_set() {
+        let json = flat_model_json("", "15", CONFIG_SET_ID).replace(
+            &format!(r#"{{ "configSetId": "{CONFIG_SET_ID}", "startDate": "2025-01-01", "endDate": "2025-06-30" }}"#),
+            &format!(r#"{{ "configSetId": "{CONFIG_SET_ID}", "startDate": "2024-11-01", "endDate": "2025-01-06" }},
+                        {{ "configSetId": "{OTHER_CONFIG_SET_ID}", "startDate": "2025-01-08", "endDate": "2025-01-08" }},
+                        {{ "configSetId": "{CONFIG_SET_ID}", "startDate": "2025-01-10", "endDate": "2025-01-11" }}"#),
+        );
+
+        let model = ModelLoader::new().load(&json, dates(), standard_set_id()).unwrap();
+
+        assert_eq!(model.jobs()[0].planner_settings().effective_dates.len(), 2);
+        assert_eq!(model.jobs()[0].planner_settings().dates(&model), vec![
+            LocalDate::new(2025, 1, 6),
+            LocalDate::new(2025, 1, 10),
+            LocalDate::new(2025, 1, 11),
+        ]);
+
+        let backwards = ModelLoader::new().load(&json.replace("\"endDate\": \"2025-01-11\"", "\"endDate\": \"2025-01-09\""), dates(), standard_set_id()).err().unwrap();
+        assert_eq!(backwards, LoadError::invalid_field("$.jobs[0].effectiveDates[2].endDate", "must not be before startDate"));
+    }
+
+    #[test]
+    fn job_settings_inherit_the_location_settings_of_the_config_set() {
+        let json = flat_model_json("", "15", CONFIG_SET_ID).replacen(
+            &format!(r#""location": {{ "id": "{LOCATION_ID}" }}"#),
+            &format!(r#""location": {{
+                "id": "{LOCATION_ID}",
+                "plannerSettings": [
+                    {{ "configSetId": "{CONFIG_SET_ID}", "periodLength": 30, "minShift": 3.0, "mealBreakAfter": 5.0, "mealBreakLength": 30.0 }},
+                    {{ "configSetId": "{OTHER_CONFIG_SET_ID}", "maxShift": 12.0 }}
+                ]
+            }}"#),
+            1,
+        );
+
+        let model = ModelLoader::new().load(&json, dates(), standard_set_id()).unwrap();
+
+        let settings = model.jobs()[0].planner_settings();
+        assert_eq!((settings.period_length, settings.sources.period_length), (15, SettingSource::Assignment));
+        assert_eq!((settings.min_shift_length, settings.sources.min_shift_length), (3.0, SettingSource::Property));
+        assert_eq!((settings.max_shift_length, settings.sources.max_shift_length), (8.0, SettingSource::Default));
+        assert_eq!(settings.meal_break.as_ref().unwrap().break_after, 5.0);
+        assert_eq!(settings.meal_break.as_ref().unwrap().break_length, 0.5);
+        assert_eq!(settings.sources.meal_break_length, SettingSource::Property);
+
+        let bad_location_setting = ModelLoader::new().load(&json.replace("\"periodLength\": 30", "\"periodLength\": 0"), dates(), standard_set_id()).err().unwrap();
+        assert_eq!(bad_location_setting, LoadError::invalid_field("$.location.plannerSettings[0].periodLength", "must be greater than 0"));
+    }
+
+    #[test]
+    fn shift_definitions_can_be_for_an_environment() {
+        let json = flat_model_json("", "15", CONFIG_SET_ID).replace(
+            r#"{ "dayOfWeek": "MON", "startTime": "07:00", "endTime": "15:00" }"#,
+            r#"{ "dayOfWeek": "MON", "startTime": "07:00", "endTime": "15:00" },
+               { "environmentId": 20, "startTime": "22:00", "endTime": "06:30", "shiftGroupingForSameEvent": 1, "weightingFactor": 3 }"#,
+        );
+
+        let model = ModelLoader::new().load(&json, dates(), standard_set_id()).unwrap();
+
+        let shift = &model.jobs()[0].shifts()[0];
+        let definition = shift.shift_detail_for_date(Some(Environment::new(20)), LocalDate::new(2025, 1, 6)).unwrap();
+        assert_eq!(definition.environment(), Some(Environment::new(20)));
+        assert_eq!(definition.day_of_week(), None);
+        assert_eq!((definition.start_time(), definition.shift_grouping_for_same_event(), definition.weighting_factor()), (LocalTime::new(22, 0, 0), 1, 3));
+        assert_eq!(shift.shift_detail_for_date(Some(Environment::new(1)), LocalDate::new(2025, 1, 6)).unwrap().start_time(), LocalTime::new(7, 0, 0));
+
+        let both = ModelLoader::new().load(&json.replace(r#""environmentId": 20,"#, r#""environmentId": 20, "dayOfWeek": "TUE","#), dates(), standard_set_id()).err().unwrap();
+        assert_eq!(both, LoadError::invalid_field("$.jobs[0].shifts[0].definitions[1].dayOfWeek", "not allowed with environmentId"));
+    }
+
+    #[test]
+    fn flat_layout_errors_are_reported_with_their_path() {
+        let bad_setting = ModelLoader::new().load(&flat_model_json("", "\"half hour\"", CONFIG_SET_ID), dates(), standard_set_id()).err().unwrap();
+        let unknown_config_set = ModelLoader::new().load(&flat_model_json("", "15", "7d4f6a0e-4444-4a5b-9c2d-000000000009"), dates(), standard_set_id()).err().unwrap();
+
+        assert_eq!(bad_setting, LoadError::invalid_field("$.jobs[0].settings[0].periodLength", "expected a number but was \"half hour\""));
+        assert_eq!(unknown_config_set, LoadError::invalid_field(
+            "$.jobs[0].shifts[0].configSetId",
+            "no settings for config set 7d4f6a0e-4444-4a5b-9c2d-000000000009",
+        ));
+    }
+
+    #[test]
+    fn version_picks_the_layout() {
+        let nested_as_flat = ModelLoader::new().load(&model_json("30").replacen('{', "{ \"version\": 2,", 1), dates(), standard_set_id()).err().unwrap();
+        let unknown_version = ModelLoader::new().load(&flat_model_json("\"version\": 3,", "15", CONFIG_SET_ID), dates(), standard_set_id()).err().unwrap();
+
+        assert_eq!(nested_as_flat, LoadError::invalid_field("$.jobs[0].settings", "missing field"));
+        assert_eq!(unknown_version, LoadError::invalid_field("$.version", "unknown version 3, expected 1 or 2"));
+    }
+
+    #[test]
+    fn model_resource_settings_calculate_shifts_with_breaks_in_hours() {
+        let json = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/model.json")).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        let node = JsonNode::root(&value).field("jobs").unwrap().array().unwrap()[0]
+            .field("config").unwrap().field("configSet1").unwrap().field("settings").unwrap();
+
+        let layer = ModelLoader::settings(&node).unwrap();
+        let settings = PlannerSettingsResolver::new().resolve(standard_set_id(), &layer);
+        let result = BasicCalculator::new().calculate(&settings, 8.0, 20 * 60);
+
+        // An 8 hour shift has a quarter hour break at 2 and 6 hours and half an hour meal break at
+        // 4 hours, so 7 productive hours: two full shifts and 6 hours of work with 1 hour of breaks
+        assert_eq!(settings.meal_break.as_ref().unwrap().break_length, 0.5);
+        assert_eq!(settings.non_meal_break.as_ref().unwrap().break_length, 0.25);
+        assert_eq!(result.number_of_full_time_shifts, 2);
+        assert_eq!(result.remaining_work_hours, 7.0);
+        assert_eq!(result.work_hours_to_cover_breaks, 3.0);
+    }
+
+    #[test]
+    fn loads_the_model2_resource() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/model2.json");
+
+        let model = ModelLoader::new().load_file(path, dates(), standard_set_id()).unwrap();
+
+        let job = &model.jobs()[0];
+        assert_eq!(job.id().to_string(), JOB_ID);
+        let shift = &job.shifts()[0];
+        assert_eq!(shift.id().to_string(), SHIFT_ID);
+        assert_eq!(shift.shift_definitions().len(), 7);
+        let definition = shift.shift_detail_for_date(None, LocalDate::new(2025, 1, 6)).unwrap();
+        assert_eq!((definition.start_time(), definition.end_time()), (LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0)));
+        assert_eq!((definition.hours_before(), definition.hours_after()), (1.0, 1.0));
+
+        let settings = job.planner_settings();
+        assert_eq!(settings.effective_dates, vec![DateRange::new(LocalDate::new(2025, 1, 1), LocalDate::new(2025, 6, 30))]);
+        assert_eq!(settings.standard_type, StandardType::BASIC);
+        assert_eq!(settings.period_length, 30);
+        assert_eq!((settings.min_shift_length, settings.max_shift_length), (4.0, 8.0));
+        assert_eq!(settings.meal_break.as_ref().map(|meal_break| (meal_break.break_after, meal_break.break_length)), Some((4.0, 0.5)));
+        assert_eq!(settings.non_meal_break.as_ref().map(|non_meal_break| (non_meal_break.break_every, non_meal_break.break_length)), Some((2.0, 0.25)));
+        assert!(settings.generate_long_shifts && settings.limit_shift_to_max_shift && settings.truncate_max_coverage);
+    }
+
+    #[test]
+    fn syntax_errors_are_reported_with_their_position() {
+        let error = ModelLoader::new().load("{\n  \"location\": }", dates(), standard_set_id()).err().unwrap();
+
+        assert!(matches!(error, LoadError::Syntax { line: 2, .. }));
+    }
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::Path;
+use date_range_rs::DateRange;
+use serde_json::Value;
+use crate::workcontent::domain::environment_calendar::EnvironmentSource;
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::domain::location::LocationId;
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::loader::json_node::JsonNode;
+use crate::workcontent::loader::load_error::LoadError;
+
+const ENVIRONMENT_SOURCES: [(&str, EnvironmentSource); 2] = [
+    ("FORECAST", EnvironmentSource::Forecast),
+    ("ACTUAL", EnvironmentSource::Actual),
+];
+
+/// What to generate in a run, read from the `resources/options.json` document: the dates, the
+/// location, the config set to plan with and the jobs to plan. No jobs means every job. The
+/// optional `environmentSource` plans with the `FORECAST` (the default) or `ACTUAL` environments.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    dates: DateRange,
+    location_id: LocationId,
+    standard_set_id: StandardSetId,
+    job_ids: Vec<JobId>,
+    job_id_indexes: Vec<usize>,
+    environment_source: EnvironmentSource,
+}
+
+impl RunOptions {
+    pub fn new(dates: DateRange, location_id: LocationId, standard_set_id: StandardSetId, job_ids: Vec<JobId>) -> Self {
+        Self {
+            dates,
+            location_id,
+            standard_set_id,
+            job_id_indexes: (0..job_ids.len()).collect(),
+            job_ids,
+            environment_source: EnvironmentSource::default(),
+        }
+    }
+
+    pub fn with_environment_source(mut self, environment_source: EnvironmentSource) -> Self {
+        self.environment_source = environment_source;
+        self
+    }
+
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|error| LoadError::io(path, error))?;
+        Self::load(&json)
+    }
+
+    /// Blank entries in `jobIds` are ignored.
+    pub fn load(json: &str) -> Result<Self, LoadError> {
+        let value: Value = serde_json::from_str(json)?;
+        let root = JsonNode::root(&value);
+
+        let dates = root.field("dates")?;
+        let start_date = dates.field("startDate")?.as_date()?;
+        let end_date_node = dates.field("endDate")?;
+        let end_date = end_date_node.as_date()?;
+        if end_date.is_before(start_date) {
+            return Err(end_date_node.error("must not be before the start date"));
+        }
+
+        let mut job_ids = Vec::new();
+        let mut job_id_indexes = Vec::new();
+        for (index, job_id) in root.optional_array("jobIds")?.into_iter().enumerate() {
+            if !job_id.as_str()?.trim().is_empty() {
+                job_ids.push(JobId::from(job_id.as_uuid()?));
+                job_id_indexes.push(index);
+            }
+        }
+
+        let mut options = Self::new(
+            DateRange::new(start_date, end_date),
+            LocationId::from(root.field("locationId")?.as_uuid()?),
+            StandardSetId::from(root.field("configSetId")?.as_uuid()?),
+            job_ids,
+        );
+        options.job_id_indexes = job_id_indexes;
+        match root.optional_field("environmentSource") {
+            Some(environment_source) => Ok(options.with_environment_source(environment_source.as_enum(&ENVIRONMENT_SOURCES)?)),
+            None => Ok(options),
+        }
+    }
+
+    pub fn dates(&self) -> DateRange {
+        self.dates
+    }
+
+    pub fn location_id(&self) -> LocationId {
+        self.location_id
+    }
+
+    pub fn standard_set_id(&self) -> StandardSetId {
+        self.standard_set_id
+    }
+
+    pub fn job_ids(&self) -> &[JobId] {
+        &self.job_ids
+    }
+
+    /// The JSON path of the `jobIds` entry the job id at `position` in `job_ids` was read from.
+    pub fn job_id_path(&self, position: usize) -> String {
+        format!("$.jobIds[{}]", self.job_id_indexes[position])
+    }
+
+    pub fn environment_source(&self) -> EnvironmentSource {
+        self.environment_source
+    }
+
+    pub fn includes_job(&self, job_id: JobId) -> bool {
+        self.job_ids.is_empty() || self.job_ids.contains(&job_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::LocalDate;
+
+    #[test]
+    fn loads_the_run_options() {
+        let json = r#"{
+            "dates": { "startDate": "2025-01-06", "endDate": "2025-01-12" },
+            "locationId": "7d4f6a0e-1111-4a5b-9c2d-000000000001",
+            "configSetId": "7d4f6a0e-4444-4a5b-9c2d-000000000001",
+            "jobIds": ["7d4f6a0e-3333-4a5b-9c2d-000000000001", ""],
+            "environmentSource": "Actual"
+        }"#;
+
+        let options = RunOptions::load(json).unwrap();
+
+        assert_eq!(options.dates().start_date(), LocalDate::new(2025, 1, 6));
+        assert_eq!(options.dates().end_date(), LocalDate::new(2025, 1, 12));
+        assert_eq!(options.location_id().to_string(), "7d4f6a0e-1111-4a5b-9c2d-000000000001");
+        assert_eq!(options.standard_set_id().to_string(), "7d4f6a0e-4444-4a5b-9c2d-000000000001");
+        assert_eq!(options.job_ids().len(), 1);
+        assert_eq!(options.job_id_path(0), "$.jobIds[0]");
+        assert!(options.includes_job(options.job_ids()[0]));
+        assert!(!options.includes_job(JobId::new()));
+        assert_eq!(options.environment_source(), EnvironmentSource::Actual);
+    }
+
+    #[test]
+    fn no_job_ids_includes_every_job() {
+        let options = RunOptions::new(
+            DateRange::new(LocalDate::new(2025, 1, 6), LocalDate::new(2025, 1, 6)),
+            LocationId::new(),
+            StandardSetId::new(),
+            vec![],
+        );
+
+        assert!(options.includes_job(JobId::new()));
+        assert_eq!(options.environment_source(), EnvironmentSource::Forecast);
+    }
+
+    #[test]
+    fn end_date_before_start_date_is_an_error() {
+        let json = r#"{
+            "dates": { "startDate": "2025-01-06", "endDate": "2025-01-05" },
+            "locationId": "7d4f6a0e-1111-4a5b-9c2d-000000000001",
+            "configSetId": "7d4f6a0e-4444-4a5b-9c2d-000000000001"
+        }"#;
+
+        assert_eq!(RunOptions::load(json).unwrap_err(), LoadError::invalid_field("$.dates.endDate", "must not be before the start date"));
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use clap::{Parser, Subcommand};
+use joda_rs::LocalDate;
+use uuid::Uuid;
+use crate::workcontent::common::date_times::format_date;
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::generators::work_generators::WorkResults;
+use crate::workcontent::loader::json_node::parse_date;
+use crate::workcontent::loader::load_error::LoadError;
+use crate::workcontent::loader::model_loader::ModelLoader;
+use crate::workcontent::loader::run_options::RunOptions;
+use crate::workcontent::main::main;
+use crate::workcontent::output::work_results_json::WorkResultsJson;
+
+/// The `planner` command line. Usage errors exit with 2, load, validation and write errors with 1.
+#[derive(Debug, Parser)]
+#[command(name = "planner", about = "Generates work content and planned shifts from a model.json document")]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generates the work results of a run and writes them as JSON.
+    Generate {
+        #[arg(long)]
+        model: PathBuf,
+        #[arg(long)]
+        options: PathBuf,
+        /// Written to standard output when not given.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Checks every field of a model document.
+    Validate {
+        model: PathBuf,
+    },
+    /// Prints how the work of a job on a date was calculated.
+    Explain {
+        #[arg(long)]
+        model: PathBuf,
+        #[arg(long)]
+        options: PathBuf,
+        #[arg(long)]
+        job: String,
+        /// A `yyyy-MM-dd` date of the run.
+        #[arg(long)]
+        date: String,
+    },
+}
+
+/// Why a command failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    Load(LoadError),
+    InvalidArgument { name: String, message: String },
+    Write { file: String, message: String },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Load(error) => write!(f, "{}", error),
+            CliError::InvalidArgument { name, message } => write!(f, "--{}: {}", name, message),
+            CliError::Write { file, message } => write!(f, "could not write {}: {}", file, message),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<LoadError> for CliError {
+    fn from(error: LoadError) -> Self {
+        CliError::Load(error)
+    }
+}
+
+/// Runs a command, writing its output to `out` unless it names an output file.
+pub fn run(cli: Cli, out: &mut impl Write) -> Result<(), CliError> {
+    match cli.command {
+        Command::Generate { model, options, out: out_file } => {
+            let work_results = generate(&model, &RunOptions::load_file(options)?)?;
+            let json = WorkResultsJson::new().to_json(&work_results);
+            match out_file {
+                Some(path) => fs::write(&path, json).map_err(|error| write_error(&path, error)),
+                None => writeln!(out, "{}", json).map_err(|error| write_error(Path::new("standard output"), error)),
+            }
+        }
+        Command::Validate { model } => {
+            ModelLoader::new().validate(&read(&model)?)?;
+            writeln!(out, "{}: valid", model.display()).map_err(|error| write_error(Path::new("standard output"), error))
+        }
+        Command::Explain { model, options, job, date } => {
+            let job_id = Uuid::parse_str(job.trim())
+                .map(JobId::from)
+                .map_err(|_| invalid_argument("job", format!("expected a UUID but was \"{}\"", job)))?;
+            let date = parse_date(&date)
+                .ok_or_else(|| invalid_argument("date", format!("expected a yyyy-MM-dd date but was \"{}\"", date)))?;
+            let options = RunOptions::load_file(options)?;
+            let dates = options.dates();
+            if !dates.contains_date(date) {
+                return Err(invalid_argument("date", format!(
+                    "{} is not in the run's dates, {} to {}",
+                    format_date(date),
+                    format_date(dates.start_date()),
+                    format_date(dates.end_date()),
+                )));
+            }
+            let work_results = generate(&model, &options)?;
+            let explanation = explain(&work_results, job_id, date)?;
+            write!(out, "{}", explanation).map_err(|error| write_error(Path::new("standard output"), error))
+        }
+    }
+}
+
+fn generate(model: &Path, options: &RunOptions) -> Result<Vec<WorkResults>, LoadError> {
+    main::run(&read(model)?, options)
+}
+
+/// The calculation log of a job on a date: each log's total, then the minutes and formula of each
+/// of its details. Work content of the date that was generated without a log is reported as such
+/// rather than as no work.
+fn explain(work_results: &[WorkResults], job_id: JobId, date: LocalDate) -> Result<String, CliError> {
+    let results = work_results.iter()
+        .find(|results| results.job_id() == job_id)
+        .ok_or_else(|| invalid_argument("job", format!("job {} is not in the run", job_id)))?;
+    let logs: Vec<_> = results.work_content_logs().iter()
+        .filter(|log| log.shift_date() == date)
+        .collect();
+
+    let mut explanation = format!("Job {} on {}\n", job_id, format_date(date));
+    if logs.is_empty() {
+        let work_contents: Vec<_> = results.work_contents().iter()
+            .filter(|work_content| work_content.shift_date() == date)
+            .collect();
+        if work_contents.is_empty() {
+            explanation.push_str("  no work content\n");
+        } else {
+            let hours: f64 = work_contents.iter().map(|work_content| work_content.calculated_hours()).sum();
+            explanation.push_str(&format!("  {:.2} hours of work content but no calculation log\n", hours));
+        }
+    }
+    for log in logs {
+        explanation.push_str(&format!("{}: {:.2} minutes ({:.2} hours)\n", log.description(), log.work_in_minutes(), log.work_in_minutes() / 60.0));
+        for (name, detail) in log.details() {
+            explanation.push_str(&format!("  {}: {} = {:.2} minutes\n", name, detail.formula(), detail.work_in_minutes()));
+        }
+    }
+    Ok(explanation)
+}
+
+fn read(path: &Path) -> Result<String, LoadError> {
+    fs::read_to_string(path).map_err(|error| LoadError::io(path, error))
+}
+
+fn invalid_argument(name: &str, message: String) -> CliError {
+    CliError::InvalidArgument {
+        name: name.to_string(),
+        message,
+    }
+}
+
+fn write_error(file: &Path, error: std::io::Error) -> CliError {
+    CliError::Write {
+        file: file.display().to_string(),
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::LocalTime;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+    use crate::workcontent::domain::work_content::WorkContent;
+    use crate::workcontent::generators::calculator::work_content_log::{WorkContentLog, WorkContentLogDetail};
+
+    const LOCATION_ID: &str = "7d4f6a0e-1111-4a5b-9c2d-000000000001";
+    const CONFIG_SET_ID: &str = "7d4f6a0e-4444-4a5b-9c2d-000000000001";
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("planner-cli-{}", Uuid::new_v4()));
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn file(&self, name: &str, contents: &str) -> PathBuf {
+            let path = self.0.join(name);
+            fs::write(&path, contents).unwrap();
+            path
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn run_args(args: &[&str]) -> (Result<(), CliError>, String) {
+        let cli = Cli::try_parse_from(std::iter::once("planner").chain(args.iter().copied())).unwrap();
+        let mut out = Vec::new();
+        let result = run(cli, &mut out);
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    fn options_json() -> String {
+        format!(r#"{{
+            "dates": {{ "startDate": "2025-01-06", "endDate": "2025-01-12" }},
+            "locationId": "{LOCATION_ID}",
+            "configSetId": "{CONFIG_SET_ID}",
+            "jobIds": []
+        }}"#)
+    }
+
+    #[test]
+    fn generate_writes_the_results_to_the_out_file() {
+        let dir = TempDir::new();
+        let model = dir.file("model.json", &format!(r#"{{ "location": {{ "id": "{LOCATION_ID}" }}, "jobs": [] }}"#));
+        let options = dir.file("options.json", &options_json());
+        let out = dir.0.join("results.json");
+
+        let (result, stdout) = run_args(&["generate", "--model", model.to_str().unwrap(), "--options", options.to_str().unwrap(), "--out", out.to_str().unwrap()]);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(stdout, "");
+        assert_eq!(WorkResultsJson::new().from_json(&fs::read_to_string(out).unwrap()).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn validate_reports_the_bad_field() {
+        let dir = TempDir::new();
+        let valid = dir.file("valid.json", &format!(r#"{{ "location": {{ "id": "{LOCATION_ID}" }}, "jobs": [] }}"#));
+        let invalid = dir.file("invalid.json", r#"{ "location": { "id": "hotel" }, "jobs": [] }"#);
+
+        let (valid_result, stdout) = run_args(&["validate", valid.to_str().unwrap()]);
+        let (invalid_result, _) = run_args(&["validate", invalid.to_str().unwrap()]);
+
+        assert_eq!(valid_result, Ok(()));
+        assert!(stdout.ends_with("valid.json: valid\n"), "{}", stdout);
+        assert!(invalid_result.unwrap_err().to_string().starts_with("$.location.id: "));
+    }
+
+    #[test]
+    fn explain_rejects_bad_arguments() {
+        let (bad_job, _) = run_args(&["explain", "--model", "model.json", "--options", "options.json", "--job", "room attendant", "--date", "2025-01-06"]);
+        let (bad_date, _) = run_args(&["explain", "--model", "model.json", "--options", "options.json", "--job", LOCATION_ID, "--date", "06/01/2025"]);
+
+        assert_eq!(bad_job.unwrap_err().to_string(), "--job: expected a UUID but was \"room attendant\"");
+        assert_eq!(bad_date.unwrap_err().to_string(), "--date: expected a yyyy-MM-dd date but was \"06/01/2025\"");
+    }
+
+    #[test]
+    fn explain_rejects_a_date_outside_the_run() {
+        let dir = TempDir::new();
+        let model = dir.file("model.json", &format!(r#"{{ "location": {{ "id": "{LOCATION_ID}" }}, "jobs": [] }}"#));
+        let options = dir.file("options.json", &options_json());
+
+        let (result, stdout) = run_args(&["explain", "--model", model.to_str().unwrap(), "--options", options.to_str().unwrap(), "--job", LOCATION_ID, "--date", "2025-01-13"]);
+
+        assert_eq!(result.unwrap_err().to_string(), "--date: 2025-01-13 is not in the run's dates, 2025-01-06 to 2025-01-12");
+        assert_eq!(stdout, "");
+    }
+
+    #[test]
+    fn missing_arguments_are_usage_errors() {
+        let error = Cli::try_parse_from(["planner", "generate", "--model", "model.json"]).unwrap_err();
+
+        assert_eq!(error.exit_code(), 2);
+    }
+
+    #[test]
+    fn explain_prints_the_calculation_log_of_the_date() {
+        let job_id = JobId::new();
+        let monday = LocalDate::new(2025, 1, 6);
+        let work_results = [WorkResults::with_shifts(job_id, vec![]).with_work_content_logs(vec![
+            WorkContentLog::new(job_id, monday, "Day".to_string(), vec![
+                ("Occupied rooms".to_string(), WorkContentLogDetail::new(90.0, "120 * 0.75".to_string())),
+                ("Fixed".to_string(), WorkContentLogDetail::new(30.0, "30".to_string())),
+            ]),
+            WorkContentLog::new(job_id, monday.plus_days(1), "Day".to_string(), vec![]),
+        ])];
+
+        let explanation = explain(&work_results, job_id, monday).unwrap();
+
+        assert_eq!(explanation, format!("Job {job_id} on 2025-01-06\n\
+            Day: 120.00 minutes (2.00 hours)\n  \
+            Occupied rooms: 120 * 0.75 = 90.00 minutes\n  \
+            Fixed: 30 = 30.00 minutes\n"));
+        assert!(explain(&work_results, JobId::new(), monday).is_err());
+    }
+
+    #[test]
+    fn explain_reports_work_content_without_a_log() {
+        let job_id = JobId::new();
+        let monday = LocalDate::new(2025, 1, 6);
+        let work_content = WorkContent::new(
+            job_id,
+            LocationId::new(),
+            PlannedShiftType::Projected,
+            monday,
+            monday.at_time(LocalTime::new(7, 0, 0)),
+            monday.at_time(LocalTime::new(7, 0, 0)),
+            monday.at_time(LocalTime::new(15, 0, 0)),
+            monday.at_time(LocalTime::new(7, 0, 0)),
+            monday.at_time(LocalTime::new(9, 30, 0)),
+            2.5,
+            2.5,
+            false,
+            "Day".to_string(),
+            0,
+            0,
+            monday.at_time(LocalTime::new(7, 0, 0)),
+        );
+        let work_results = [WorkResults::with_shifts(job_id, vec![]).with_work_contents(vec![work_content])];
+
+        assert_eq!(explain(&work_results, job_id, monday).unwrap(), format!("Job {job_id} on 2025-01-06\n  \
+            2.50 hours of work content but no calculation log\n"));
+        assert_eq!(explain(&work_results, job_id, monday.plus_days(1)).unwrap(), format!("Job {job_id} on 2025-01-07\n  \
+            no work content\n"));
+    }
+}
@@ -1,4 +1,7 @@
 use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::loader::load_error::LoadError;
+use crate::workcontent::loader::model_loader::ModelLoader;
+use crate::workcontent::loader::run_options::RunOptions;
 use crate::workcontent::generators::work_generators;
 use crate::workcontent::generators::work_generators::{WorkGenerator, WorkGeneratorKind, WorkResults};
 
@@ -13,6 +16,12 @@ pub fn generate_work_content(planner_model: PlannerModel) -> Vec<WorkResults> {
         .collect()
 }
 
+/// Generates the work content of a model.json document for the dates, config set and jobs of a run.
+pub fn run(model_json: &str, options: &RunOptions) -> Result<Vec<WorkResults>, LoadError> {
+    let planner_model = ModelLoader::new().load_for_run(model_json, options)?;
+    Ok(generate_work_content(planner_model))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -20,7 +29,6 @@ mod tests {
     use crate::workcontent::domain::standard_set::{StandardSet, StandardSetId};
     use date_range_rs::DateRange;
     use std::any::type_name;
-    use std::collections::HashMap;
     use joda_rs::LocalDate;
     use crate::workcontent::domain::job::Job;
 
@@ -39,7 +47,7 @@ mod tests {
         );
         let location_id = LocationId::new();
         let standard_set_id = StandardSetId::new();
-        let planner_model = PlannerModel::new(dates, location_id, standard_set_id, vec![], vec![], HashMap::new());
+        let planner_model = PlannerModel::new(dates, location_id, standard_set_id, vec![], vec![], vec![]);
         let results = generate_work_content(planner_model);
         assert!(results.is_empty());
     }
@@ -52,7 +60,7 @@ mod tests {
         );
         let location_id = LocationId::new();
         let standard_set_id = StandardSetId::new();
-        let planner_model = PlannerModel::new(dates, location_id, standard_set_id, vec![Job::test()], vec![], HashMap::new());
+        let planner_model = PlannerModel::new(dates, location_id, standard_set_id, vec![Job::test()], vec![], vec![]);
         let results = generate_work_content(planner_model);
         assert_eq!(results.len(), 1);
     }
@@ -2,3 +2,8 @@ pub mod common;
 pub mod domain;
 pub mod main;
 mod generators;
+pub mod loader;
+pub mod output;
+pub mod schema;
+#[cfg(feature = "sqlite")]
+pub mod repository;
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use csv::Writer;
+use joda_rs::LocalDate;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use crate::workcontent::common::date_times::format_date;
+use crate::workcontent::generators::work_generators::WorkResults;
+
+const HEADER: [&str; 3] = ["job", "date", "hours"];
+const HOURS_SCALE: u32 = 2;
+
+/// Writes hours for each job and day as CSV with a `job,date,hours` header. Jobs are written as
+/// their ids, dates as `yyyy-MM-dd` and hours rounded half away from zero to 2 decimals.
+#[derive(Default)]
+pub struct LaborCsvWriter;
+
+impl LaborCsvWriter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// The labor data of the results, e.g. the daily hours of salaried jobs, in result order.
+    pub fn labor_data(&self, work_results: &[WorkResults]) -> String {
+        let rows = work_results.iter()
+            .flat_map(|results| results.labor_data().into_iter().flatten())
+            .map(|labor_data| {
+                let hours = Decimal::from_f64(labor_data.hours()).unwrap_or_default();
+                (labor_data.job_id().to_string(), labor_data.date(), hours)
+            });
+        write_csv(rows)
+    }
+
+    /// The total duration of the planned shifts of each job on each shift date, in result order
+    /// then date order.
+    pub fn planned_shift_hours(&self, work_results: &[WorkResults]) -> String {
+        let rows = work_results.iter().flat_map(|results| {
+            let mut hours_by_date: BTreeMap<LocalDate, Decimal> = BTreeMap::new();
+            for shift in results.shifts().into_iter().flatten() {
+                *hours_by_date.entry(shift.shift_date()).or_default() += shift.duration();
+            }
+            let job_id = results.job_id().to_string();
+            hours_by_date.into_iter().map(move |(date, hours)| (job_id.clone(), date, hours))
+        });
+        write_csv(rows)
+    }
+}
+
+fn write_csv(rows: impl Iterator<Item = (String, LocalDate, Decimal)>) -> String {
+    let mut writer = Writer::from_writer(Vec::new());
+    writer.write_record(HEADER).expect("writing to memory");
+    for (job_id, date, hours) in rows {
+        let mut hours = hours.round_dp_with_strategy(HOURS_SCALE, RoundingStrategy::MidpointAwayFromZero);
+        hours.rescale(HOURS_SCALE);
+        writer.write_record([job_id, format_date(date), hours.to_string()]).expect("writing to memory");
+    }
+    String::from_utf8(writer.into_inner().expect("writing to memory")).expect("CSV of UTF-8 fields")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::LocalTime;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::labor_data::LaborData;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planned_shift::PlannedShift;
+    use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+
+    fn monday() -> LocalDate {
+        LocalDate::new(2025, 1, 6)
+    }
+
+    fn shift(job_id: JobId, date: LocalDate, start: LocalTime, end: LocalTime) -> PlannedShift {
+        PlannedShift::new(LocationId::new(), job_id, PlannedShiftType::Projected, date, date.at_time(start), date.at_time(end))
+    }
+
+    #[test]
+    fn labor_data_is_written_for_each_job_and_day() {
+        let job_id = JobId::new();
+        let work_results = [WorkResults::with_labor_data(job_id, vec![
+            LaborData::new(job_id, monday(), 5.71),
+            LaborData::new(job_id, monday().plus_days(1), 8.0),
+            LaborData::new(job_id, monday().plus_days(2), 1.005),
+        ])];
+
+        let csv = LaborCsvWriter::new().labor_data(&work_results);
+
+        assert_eq!(csv, format!("job,date,hours\n\
+            {job_id},2025-01-06,5.71\n\
+            {job_id},2025-01-07,8.00\n\
+            {job_id},2025-01-08,1.01\n"));
+    }
+
+    #[test]
+    fn planned_shift_hours_are_totalled_by_day() {
+        let job_id = JobId::new();
+        let other_job_id = JobId::new();
+        let work_results = [
+            WorkResults::with_shifts(job_id, vec![
+                shift(job_id, monday().plus_days(1), LocalTime::new(7, 0, 0), LocalTime::new(11, 0, 0)),
+                shift(job_id, monday(), LocalTime::new(7, 0, 0), LocalTime::new(15, 30, 0)),
+                shift(job_id, monday(), LocalTime::new(17, 0, 0), LocalTime::new(21, 20, 0)),
+            ]),
+            WorkResults::with_shifts(other_job_id, vec![]),
+        ];
+
+        let csv = LaborCsvWriter::new().planned_shift_hours(&work_results);
+
+        assert_eq!(csv, format!("job,date,hours\n\
+            {job_id},2025-01-06,12.83\n\
+            {job_id},2025-01-07,4.00\n"));
+    }
+}
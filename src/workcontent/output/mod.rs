@@ -0,0 +1,4 @@
+pub mod work_results_json;
+pub mod table_rows;
+pub mod schema_export;
+pub mod labor_csv;
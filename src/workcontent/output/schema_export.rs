@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::workcontent::common::db_code::DbCode;
+use crate::workcontent::domain::planned_shift::{PlannedShift, PlannedShiftId};
+use crate::workcontent::domain::work_content::{WorkContent, WorkContentId};
+use crate::workcontent::domain::work_content_detail_type::WorkContentDetailType;
+use crate::workcontent::domain::work_content_type::WorkContentType;
+use crate::workcontent::generators::work_generators::WorkResults;
+use crate::workcontent::output::table_rows::{Column, ColumnType, ColumnValue, ExportError, TableRows};
+
+const MINUTES_PER_HOUR: f64 = 60.0;
+
+pub const WORK_CONTENT_COLUMNS: [Column; 25] = [
+    Column::new("ID", ColumnType::Int, true),
+    Column::new("PropertyID", ColumnType::Int, true),
+    Column::new("JobID", ColumnType::Int, true),
+    Column::new("AssignmentID", ColumnType::Int, false),
+    Column::new("WorkContentType", ColumnType::Enum { length: 1 }, true),
+    Column::new("ShiftType", ColumnType::Enum { length: 1 }, true),
+    Column::new("ShiftDate", ColumnType::Date, true),
+    Column::new("WorkContentLogID", ColumnType::Int, false),
+    Column::new("EarliestStartDateTime", ColumnType::DateTime, false),
+    Column::new("LatestEndDateTime", ColumnType::DateTime, false),
+    Column::new("PreferredStartDateTime", ColumnType::DateTime, false),
+    Column::new("CalculatedStartDateTime", ColumnType::DateTime, false),
+    Column::new("CalculatedEndDateTime", ColumnType::DateTime, false),
+    Column::new("CalculatedHours", ColumnType::Decimal { scale: 2 }, true),
+    Column::new("AdjustedHours", ColumnType::Decimal { scale: 2 }, true),
+    Column::new("Locked", ColumnType::Boolean, true),
+    Column::new("TaskStandardID", ColumnType::Int, false),
+    Column::new("SetupTaskStandardID", ColumnType::Int, false),
+    Column::new("TearDownTaskStandardID", ColumnType::Int, false),
+    Column::new("LinkedTaskStandardID", ColumnType::Int, false),
+    Column::new("Description", ColumnType::Varchar { length: 100 }, false),
+    Column::new("MinNumberEmployees", ColumnType::Int, false),
+    Column::new("MinSkillLevel", ColumnType::Int, false),
+    Column::new("DistributedToDateTime", ColumnType::DateTime, false),
+    Column::new("CalculatedPriority", ColumnType::Int, false),
+];
+
+pub const PLANNED_SHIFT_COLUMNS: [Column; 14] = [
+    Column::new("ID", ColumnType::Int, true),
+    Column::new("PropertyID", ColumnType::Int, true),
+    Column::new("JobID", ColumnType::Int, true),
+    Column::new("ShiftType", ColumnType::Enum { length: 1 }, true),
+    Column::new("ShiftDate", ColumnType::Date, true),
+    Column::new("DateShiftGeneratedFrom", ColumnType::Date, true),
+    Column::new("StartDateTime", ColumnType::DateTime, true),
+    Column::new("EndDateTime", ColumnType::DateTime, true),
+    Column::new("Duration", ColumnType::Decimal { scale: 4 }, true),
+    Column::new("Source", ColumnType::Enum { length: 1 }, true),
+    Column::new("AssignmentID", ColumnType::Int, false),
+    Column::new("WorkContentLogID", ColumnType::Int, false),
+    Column::new("ShiftCategoryID", ColumnType::Int, true),
+    Column::new("PlannedEventID", ColumnType::Int, false),
+];
+
+pub const PLANNED_SHIFT_WORK_CONTENT_COLUMNS: [Column; 6] = [
+    Column::new("ID", ColumnType::Int, true),
+    Column::new("PlannedShiftID", ColumnType::Int, true),
+    Column::new("WorkContentID", ColumnType::Int, true),
+    Column::new("WorkStart", ColumnType::DateTime, true),
+    Column::new("WorkEnd", ColumnType::DateTime, true),
+    Column::new("WorkHours", ColumnType::Decimal { scale: 2 }, true),
+];
+
+pub const WORK_CONTENT_DETAIL_COLUMNS: [Column; 9] = [
+    Column::new("ID", ColumnType::Int, true),
+    Column::new("PropertyID", ColumnType::Int, true),
+    Column::new("WorkContentID", ColumnType::Int, true),
+    Column::new("WorkContentDetailType", ColumnType::Enum { length: 1 }, true),
+    Column::new("TotalHours", ColumnType::Decimal { scale: 2 }, true),
+    Column::new("KBIID", ColumnType::Int, false),
+    Column::new("ResourceID", ColumnType::Int, false),
+    Column::new("Notes", ColumnType::Memo, false),
+    Column::new("TaskStandardID", ColumnType::Int, false),
+];
+
+/// Maps work results onto the `WorkContent`, `PlannedShift`, `PlannedShiftWorkContent` and
+/// `WorkContentDetail` tables of `resources/schema.xml`, column for column.
+///
+/// Rows are numbered from the first id, in that table order, and rows of later tables refer to
+/// those numbers. Locations, jobs, assignments and shift categories are written as the database
+/// id given for them, and one without a database id is an error. A work content is linked only to the shifts
+/// planned to cover it, see `PlannedShift::work_content_ids`, for the hours of its calculated
+/// time inside each shift, even when shifts planned for other work overlap it. It gets one
+/// normal task detail for its adjusted hours, the hours that are planned once any manual
+/// adjustment has been made.
+///
+/// The generators only make non event related work without a task standard, so every work
+/// content is written as `WorkContentType` `N` with a null `TaskStandardID`.
+pub struct SchemaExporter {
+    first_id: i64,
+    database_ids: HashMap<Uuid, i64>,
+}
+
+impl Default for SchemaExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaExporter {
+    pub fn new() -> Self {
+        Self {
+            first_id: 1,
+            database_ids: HashMap::new(),
+        }
+    }
+
+    pub fn with_first_id(mut self, first_id: i64) -> Self {
+        self.first_id = first_id;
+        self
+    }
+
+    pub fn with_database_id(mut self, uuid: Uuid, database_id: i64) -> Self {
+        self.database_ids.insert(uuid, database_id);
+        self
+    }
+
+    /// The rows of each table, in an order that can be inserted.
+    pub fn export(&self, work_results: &[WorkResults]) -> Result<Vec<TableRows>, ExportError> {
+        let mut work_content_rows = TableRows::new("WorkContent", &WORK_CONTENT_COLUMNS);
+        let mut planned_shift_rows = TableRows::new("PlannedShift", &PLANNED_SHIFT_COLUMNS);
+        let mut planned_shift_work_content_rows = TableRows::new("PlannedShiftWorkContent", &PLANNED_SHIFT_WORK_CONTENT_COLUMNS);
+        let mut work_content_detail_rows = TableRows::new("WorkContentDetail", &WORK_CONTENT_DETAIL_COLUMNS);
+
+        let mut work_content_ids: HashMap<WorkContentId, (i64, &WorkContent)> = HashMap::new();
+        let mut planned_shift_ids: HashMap<PlannedShiftId, i64> = HashMap::new();
+
+        for work_content in work_results.iter().flat_map(|results| results.work_contents()) {
+            let id = self.first_id + work_content_ids.len() as i64;
+            work_content_ids.insert(work_content.id(), (id, work_content));
+            work_content_rows.push(self.work_content_row(id, work_content)?)?;
+        }
+
+        for shift in work_results.iter().filter_map(|results| results.shifts()).flatten() {
+            let id = self.first_id + planned_shift_ids.len() as i64;
+            planned_shift_ids.insert(shift.id(), id);
+            planned_shift_rows.push(self.planned_shift_row(id, shift)?)?;
+        }
+
+        let mut next_id = self.first_id;
+        for shift in work_results.iter().filter_map(|results| results.shifts()).flatten() {
+            for work_content_id in shift.work_content_ids() {
+                let Some(&(work_content_row_id, work_content)) = work_content_ids.get(work_content_id) else {
+                    continue;
+                };
+                let Some(row) = Self::planned_shift_work_content_row(
+                    next_id,
+                    planned_shift_ids[&shift.id()],
+                    work_content_row_id,
+                    shift,
+                    work_content,
+                ) else {
+                    continue;
+                };
+                planned_shift_work_content_rows.push(row)?;
+                next_id += 1;
+            }
+        }
+
+        for (index, work_content) in work_results.iter().flat_map(|results| results.work_contents()).enumerate() {
+            work_content_detail_rows.push(vec![
+                ColumnValue::Int(self.first_id + index as i64),
+                self.database_id("WorkContentDetail", "PropertyID", work_content.property_id().as_uuid())?,
+                ColumnValue::Int(work_content_ids[&work_content.id()].0),
+                Self::code(WorkContentDetailType::NormalTask),
+                ColumnValue::Float(work_content.adjusted_hours()),
+                ColumnValue::Null,
+                ColumnValue::Null,
+                Self::text(work_content.description()),
+                ColumnValue::Null,
+            ])?;
+        }
+
+        Ok(vec![work_content_rows, planned_shift_rows, planned_shift_work_content_rows, work_content_detail_rows])
+    }
+
+    /// `INSERT` statements for every table.
+    pub fn to_sql(&self, work_results: &[WorkResults]) -> Result<String, ExportError> {
+        Ok(self.export(work_results)?.iter().map(TableRows::to_sql).collect())
+    }
+
+    fn work_content_row(&self, id: i64, work_content: &WorkContent) -> Result<Vec<ColumnValue>, ExportError> {
+        Ok(vec![
+            ColumnValue::Int(id),
+            self.database_id("WorkContent", "PropertyID", work_content.property_id().as_uuid())?,
+            self.database_id("WorkContent", "JobID", work_content.job_id().as_uuid())?,
+            ColumnValue::Null,
+            Self::code(WorkContentType::NonEventRelated),
+            Self::code(work_content.shift_type()),
+            ColumnValue::Date(work_content.shift_date()),
+            ColumnValue::Null,
+            ColumnValue::DateTime(work_content.earliest_start_date_time()),
+            ColumnValue::DateTime(work_content.latest_end_date_time()),
+            ColumnValue::DateTime(work_content.preferred_start_date_time()),
+            ColumnValue::DateTime(work_content.calculated_start_date_time()),
+            ColumnValue::DateTime(work_content.calculated_end_date_time()),
+            ColumnValue::Float(work_content.calculated_hours()),
+            ColumnValue::Float(work_content.adjusted_hours()),
+            ColumnValue::Boolean(work_content.is_locked()),
+            ColumnValue::Null,
+            ColumnValue::Null,
+            ColumnValue::Null,
+            ColumnValue::Null,
+            Self::text(work_content.description()),
+            ColumnValue::Int(work_content.min_number_employees() as i64),
+            ColumnValue::Int(work_content.min_skill_level() as i64),
+            ColumnValue::DateTime(work_content.distributed_to_date_time()),
+            ColumnValue::Null,
+        ])
+    }
+
+    fn planned_shift_row(&self, id: i64, shift: &PlannedShift) -> Result<Vec<ColumnValue>, ExportError> {
+        Ok(vec![
+            ColumnValue::Int(id),
+            self.database_id("PlannedShift", "PropertyID", shift.location_id().as_uuid())?,
+            self.database_id("PlannedShift", "JobID", shift.job_id().as_uuid())?,
+            Self::code(shift.shift_type()),
+            ColumnValue::Date(shift.shift_date()),
+            ColumnValue::Date(shift.date_shift_generated_from()),
+            ColumnValue::DateTime(shift.start_date_time()),
+            ColumnValue::DateTime(shift.end_date_time()),
+            ColumnValue::Decimal(shift.duration()),
+            Self::code(shift.source()),
+            shift.assignment_id().map(|id| self.database_id("PlannedShift", "AssignmentID", id.as_uuid())).transpose()?.into(),
+            ColumnValue::Null,
+            shift.shift_category_id().map(|id| self.database_id("PlannedShift", "ShiftCategoryID", id.as_uuid())).transpose()?.into(),
+            ColumnValue::Null,
+        ])
+    }
+
+    /// The part of the work content's calculated time inside the shift, if any.
+    fn planned_shift_work_content_row(id: i64,
+                                      planned_shift_id: i64,
+                                      work_content_id: i64,
+                                      shift: &PlannedShift,
+                                      work_content: &WorkContent) -> Option<Vec<ColumnValue>> {
+        let work_start = shift.start_date_time().max(work_content.calculated_start_date_time());
+        let work_end = shift.end_date_time().min(work_content.calculated_end_date_time());
+        if work_start >= work_end {
+            return None;
+        }
+
+        Some(vec![
+            ColumnValue::Int(id),
+            ColumnValue::Int(planned_shift_id),
+            ColumnValue::Int(work_content_id),
+            ColumnValue::DateTime(work_start),
+            ColumnValue::DateTime(work_end),
+            ColumnValue::Float((work_end - work_start).to_minutes() as f64 / MINUTES_PER_HOUR),
+        ])
+    }
+
+    fn database_id(&self, table: &str, column: &str, uuid: &Uuid) -> Result<ColumnValue, ExportError> {
+        match self.database_ids.get(uuid) {
+            Some(id) => Ok(ColumnValue::Int(*id)),
+            None => Err(ExportError {
+                table: table.to_string(),
+                column: column.to_string(),
+                message: format!("no database id for {}", uuid),
+            }),
+        }
+    }
+
+    fn text(text: &str) -> ColumnValue {
+        if text.is_empty() {
+            ColumnValue::Null
+        } else {
+            ColumnValue::Text(text.to_string())
+        }
+    }
+
+    fn code(value: impl DbCode) -> ColumnValue {
+        ColumnValue::Text(value.code().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::{LocalDate, LocalTime};
+    use rust_decimal::Decimal;
+    use crate::workcontent::domain::job::JobId;
+    use crate::workcontent::domain::location::LocationId;
+    use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+    use crate::workcontent::domain::shift_category::ShiftCategoryId;
+
+    fn date() -> LocalDate {
+        LocalDate::new(2025, 1, 6)
+    }
+
+    fn work_content(job_id: JobId, location_id: LocationId, start: LocalTime, end: LocalTime, hours: f64, adjusted_hours: f64) -> WorkContent {
+        WorkContent::new(
+            job_id,
+            location_id,
+            PlannedShiftType::Projected,
+            date(),
+            date().at_time(LocalTime::new(7, 0, 0)),
+            date().at_time(start),
+            date().at_time(LocalTime::new(23, 0, 0)),
+            date().at_time(start),
+            date().at_time(end),
+            hours,
+            adjusted_hours,
+            false,
+            "Check-ins".to_string(),
+            1,
+            0,
+            date().at_time(end),
+        )
+    }
+
+    fn planned_shift(location_id: LocationId, job_id: JobId, start: LocalTime, end: LocalTime, work_content: &WorkContent) -> PlannedShift {
+        PlannedShift::new(location_id, job_id, PlannedShiftType::Projected, date(), date().at_time(start), date().at_time(end))
+            .with_work_content_ids(vec![work_content.id()])
+    }
+
+    fn work_results(shift_category_id: Option<ShiftCategoryId>) -> (WorkResults, LocationId, JobId) {
+        let location_id = LocationId::new();
+        let job_id = JobId::new();
+        let work_contents = vec![
+            work_content(job_id, location_id, LocalTime::new(11, 0, 0), LocalTime::new(13, 30, 0), 2.5, 2.5),
+            work_content(job_id, location_id, LocalTime::new(14, 0, 0), LocalTime::new(15, 0, 0), 1.0 / 3.0, 1.0 / 3.0),
+        ];
+        let shift = planned_shift(location_id, job_id, LocalTime::new(8, 0, 0), LocalTime::new(12, 0, 0), &work_contents[0])
+            .with_shift_category_id(shift_category_id);
+
+        let work_results = WorkResults::with_shifts(job_id, vec![shift]).with_work_contents(work_contents);
+        (work_results, location_id, job_id)
+    }
+
+    fn exporter(location_id: LocationId, job_id: JobId, shift_category_id: ShiftCategoryId) -> SchemaExporter {
+        SchemaExporter::new()
+            .with_database_id(*location_id.as_uuid(), 7)
+            .with_database_id(*job_id.as_uuid(), 42)
+            .with_database_id(*shift_category_id.as_uuid(), 3)
+    }
+
+    #[test]
+    fn work_results_map_onto_the_schema_tables() {
+        let shift_category_id = ShiftCategoryId::new();
+        let (work_results, location_id, job_id) = work_results(Some(shift_category_id));
+        let exporter = exporter(location_id, job_id, shift_category_id).with_first_id(100);
+
+        let tables = exporter.export(&[work_results]).unwrap();
+
+        let names: Vec<&str> = tables.iter().map(|table| table.table()).collect();
+        assert_eq!(names, ["WorkContent", "PlannedShift", "PlannedShiftWorkContent", "WorkContentDetail"]);
+
+        let work_content = &tables[0].rows()[1];
+        assert_eq!(work_content[0], ColumnValue::Int(101));
+        assert_eq!(work_content[1], ColumnValue::Int(7));
+        assert_eq!(work_content[2], ColumnValue::Int(42));
+        assert_eq!(work_content[4], ColumnValue::Text("N".to_string()));
+        assert_eq!(work_content[5], ColumnValue::Text("F".to_string()));
+        assert_eq!(work_content[13], ColumnValue::Decimal(Decimal::new(33, 2)));
+        // Only non event related work without a task standard is generated
+        assert!(tables[0].rows().iter().all(|row| row[4] == ColumnValue::Text("N".to_string()) && row[16] == ColumnValue::Null));
+
+        let planned_shift = &tables[1].rows()[0];
+        assert_eq!(planned_shift[8], ColumnValue::Decimal(Decimal::new(40000, 4)));
+        assert_eq!(planned_shift[9], ColumnValue::Text("A".to_string()));
+        assert_eq!(planned_shift[12], ColumnValue::Int(3));
+
+        assert_eq!(tables[2].rows(), &[vec![
+            ColumnValue::Int(100),
+            ColumnValue::Int(100),
+            ColumnValue::Int(100),
+            ColumnValue::DateTime(date().at_time(LocalTime::new(11, 0, 0))),
+            ColumnValue::DateTime(date().at_time(LocalTime::new(12, 0, 0))),
+            ColumnValue::Decimal(Decimal::new(100, 2)),
+        ]]);
+
+        assert_eq!(tables[3].rows().len(), 2);
+        assert_eq!(tables[3].rows()[0][3], ColumnValue::Text("T".to_string()));
+        assert_eq!(tables[3].rows()[0][4], ColumnValue::Decimal(Decimal::new(250, 2)));
+    }
+
+    #[test]
+    fn work_is_linked_only_to_the_shifts_planned_for_it() {
+        let location_id = LocationId::new();
+        let job_id = JobId::new();
+        let shift_category_id = ShiftCategoryId::new();
+        let day_work = work_content(job_id, location_id, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), 8.0, 6.5);
+        let swing_work = work_content(job_id, location_id, LocalTime::new(11, 0, 0), LocalTime::new(19, 0, 0), 8.0, 8.0);
+        let shifts = vec![
+            planned_shift(location_id, job_id, LocalTime::new(7, 0, 0), LocalTime::new(15, 0, 0), &day_work),
+            planned_shift(location_id, job_id, LocalTime::new(11, 0, 0), LocalTime::new(19, 0, 0), &swing_work),
+        ];
+        let work_results = WorkResults::with_shifts(job_id, shifts.into_iter()
+            .map(|shift| shift.with_shift_category_id(Some(shift_category_id)))
+            .collect())
+            .with_work_contents(vec![day_work, swing_work]);
+
+        let tables = exporter(location_id, job_id, shift_category_id).export(&[work_results]).unwrap();
+
+        // The shifts overlap from 11:00 to 15:00, but each only does the work it was planned for
+        assert_eq!(tables[2].rows(), &[
+            vec![
+                ColumnValue::Int(1),
+                ColumnValue::Int(1),
+                ColumnValue::Int(1),
+                ColumnValue::DateTime(date().at_time(LocalTime::new(7, 0, 0))),
+                ColumnValue::DateTime(date().at_time(LocalTime::new(15, 0, 0))),
+                ColumnValue::Decimal(Decimal::new(800, 2)),
+            ],
+            vec![
+                ColumnValue::Int(2),
+                ColumnValue::Int(2),
+                ColumnValue::Int(2),
+                ColumnValue::DateTime(date().at_time(LocalTime::new(11, 0, 0))),
+                ColumnValue::DateTime(date().at_time(LocalTime::new(19, 0, 0))),
+                ColumnValue::Decimal(Decimal::new(800, 2)),
+            ],
+        ]);
+        assert_eq!(tables[3].rows()[0][4], ColumnValue::Decimal(Decimal::new(650, 2)));
+    }
+
+    #[test]
+    fn sql_inserts_every_table_in_order() {
+        let shift_category_id = ShiftCategoryId::new();
+        let (work_results, location_id, job_id) = work_results(Some(shift_category_id));
+
+        let sql = exporter(location_id, job_id, shift_category_id).to_sql(&[work_results]).unwrap();
+
+        let tables: Vec<&str> = sql.lines()
+            .map(|line| line.split_whitespace().nth(2).unwrap())
+            .collect();
+        assert_eq!(tables, ["WorkContent", "WorkContent", "PlannedShift", "PlannedShiftWorkContent", "WorkContentDetail", "WorkContentDetail"]);
+        assert!(sql.contains(", 2.50, 2.50, 'N', NULL, NULL, NULL, NULL, 'Check-ins', 1, 0, '2025-01-06 13:30:00', NULL);"));
+    }
+
+    #[test]
+    fn shifts_need_a_shift_category() {
+        let (work_results, location_id, job_id) = work_results(None);
+
+        let error = exporter(location_id, job_id, ShiftCategoryId::new()).export(&[work_results]).unwrap_err();
+
+        assert_eq!(error.to_string(), "PlannedShift.ShiftCategoryID: is required");
+    }
+
+    #[test]
+    fn ids_without_a_database_id_are_errors() {
+        let shift_category_id = ShiftCategoryId::new();
+        let (work_results, location_id, _) = work_results(Some(shift_category_id));
+        let exporter = SchemaExporter::new()
+            .with_database_id(*location_id.as_uuid(), 7)
+            .with_database_id(*shift_category_id.as_uuid(), 3);
+
+        let error = exporter.export(&[work_results]).unwrap_err();
+
+        assert_eq!(error.table, "WorkContent");
+        assert_eq!(error.column, "JobID");
+        assert!(error.message.starts_with("no database id for "));
+    }
+}
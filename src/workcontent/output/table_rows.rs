@@ -0,0 +1,281 @@
+use std::fmt;
+use joda_rs::{LocalDate, LocalDateTime};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use crate::workcontent::common::date_times::{format_date, format_date_time};
+
+/// Digits of the `decimal` columns of the schema, e.g. `decimal(9,2)`.
+pub const DECIMAL_PRECISION: u32 = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Int,
+    Varchar { length: usize },
+    Memo,
+    Decimal { scale: u32 },
+    Date,
+    DateTime,
+    Boolean,
+    /// A code of a schema enum.
+    Enum { length: usize },
+}
+
+/// A column of a schema table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub column_type: ColumnType,
+    pub required: bool,
+}
+
+impl Column {
+    pub const fn new(name: &'static str, column_type: ColumnType, required: bool) -> Self {
+        Self {
+            name,
+            column_type,
+            required,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnValue {
+    Null,
+    Int(i64),
+    Text(String),
+    Float(f64),
+    Decimal(Decimal),
+    Date(LocalDate),
+    DateTime(LocalDateTime),
+    Boolean(bool),
+}
+
+impl From<Option<ColumnValue>> for ColumnValue {
+    fn from(value: Option<ColumnValue>) -> Self {
+        value.unwrap_or(ColumnValue::Null)
+    }
+}
+
+/// Why results could not be exported, naming the table and column of the bad value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportError {
+    pub table: String,
+    pub column: String,
+    pub message: String,
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}: {}", self.table, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// The rows of one schema table, checked against its columns as they are added: required
+/// columns are not null, int columns do not hold text, decimals are rounded half away from zero
+/// to the column scale and fit `decimal(9,scale)`, and text fits the column length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableRows {
+    table: &'static str,
+    columns: &'static [Column],
+    rows: Vec<Vec<ColumnValue>>,
+}
+
+impl TableRows {
+    pub fn new(table: &'static str, columns: &'static [Column]) -> Self {
+        Self {
+            table,
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn table(&self) -> &str {
+        self.table
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        self.columns
+    }
+
+    pub fn rows(&self) -> &[Vec<ColumnValue>] {
+        &self.rows
+    }
+
+    /// Adds a row with one value for each column, in column order.
+    pub fn push(&mut self, values: Vec<ColumnValue>) -> Result<(), ExportError> {
+        assert_eq!(values.len(), self.columns.len(), "a {} row needs a value for each column", self.table);
+
+        let row = self.columns.iter()
+            .zip(values)
+            .map(|(column, value)| self.checked_value(column, value))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.rows.push(row);
+        Ok(())
+    }
+
+    /// A header line of column names, then one line for each row. Dates and date times are
+    /// written as `yyyy-MM-dd` and `yyyy-MM-dd HH:mm:ss`, booleans as `Y`/`N` and nulls as
+    /// empty fields.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        let header: Vec<String> = self.columns.iter().map(|column| csv_field(column.name)).collect();
+        csv.push_str(&header.join(","));
+        csv.push('\n');
+
+        for row in &self.rows {
+            let fields: Vec<String> = row.iter()
+                .map(|value| match value {
+                    ColumnValue::Null => String::new(),
+                    ColumnValue::Text(text) => csv_field(text),
+                    value => sql_literal(value).trim_matches('\'').to_string(),
+                })
+                .collect();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// One `INSERT` statement for each row.
+    pub fn to_sql(&self) -> String {
+        let columns: Vec<&str> = self.columns.iter().map(|column| column.name).collect();
+        let columns = columns.join(", ");
+
+        self.rows.iter()
+            .map(|row| {
+                let values: Vec<String> = row.iter().map(sql_literal).collect();
+                format!("INSERT INTO {} ({}) VALUES ({});\n", self.table, columns, values.join(", "))
+            })
+            .collect()
+    }
+
+    fn checked_value(&self, column: &Column, value: ColumnValue) -> Result<ColumnValue, ExportError> {
+        let error = |message: String| ExportError {
+            table: self.table.to_string(),
+            column: column.name.to_string(),
+            message,
+        };
+
+        match (column.column_type, value) {
+            (_, ColumnValue::Null) if column.required => Err(error("is required".to_string())),
+            (_, ColumnValue::Null) => Ok(ColumnValue::Null),
+            (ColumnType::Int, ColumnValue::Text(text)) => Err(error(format!("\"{}\" is not an int", text))),
+            (ColumnType::Decimal { scale }, ColumnValue::Float(value)) => {
+                let decimal = Decimal::from_f64(value).ok_or_else(|| error(format!("{} is not a decimal", value)))?;
+                decimal_with_scale(decimal, scale).ok_or_else(|| error(format!("{} does not fit decimal({},{})", value, DECIMAL_PRECISION, scale)))
+            }
+            (ColumnType::Decimal { scale }, ColumnValue::Decimal(decimal)) => {
+                decimal_with_scale(decimal, scale).ok_or_else(|| error(format!("{} does not fit decimal({},{})", decimal, DECIMAL_PRECISION, scale)))
+            }
+            (ColumnType::Varchar { length } | ColumnType::Enum { length }, ColumnValue::Text(text)) if text.chars().count() > length => {
+                Err(error(format!("\"{}\" is longer than {} characters", text, length)))
+            }
+            (_, value) => Ok(value),
+        }
+    }
+}
+
+fn decimal_with_scale(decimal: Decimal, scale: u32) -> Option<ColumnValue> {
+    let mut rounded = decimal.round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero);
+    rounded.rescale(scale);
+    let limit = Decimal::from_i64(10_i64.pow(DECIMAL_PRECISION - scale))?;
+    if rounded.abs() >= limit {
+        return None;
+    }
+    Some(ColumnValue::Decimal(rounded))
+}
+
+fn sql_literal(value: &ColumnValue) -> String {
+    match value {
+        ColumnValue::Null => "NULL".to_string(),
+        ColumnValue::Int(value) => value.to_string(),
+        ColumnValue::Text(text) => format!("'{}'", text.replace('\'', "''")),
+        ColumnValue::Float(value) => value.to_string(),
+        ColumnValue::Decimal(value) => value.to_string(),
+        ColumnValue::Date(date) => format!("'{}'", format_date(*date)),
+        ColumnValue::DateTime(date_time) => format!("'{}'", format_date_time(*date_time).replace('T', " ")),
+        ColumnValue::Boolean(value) => if *value { "'Y'".to_string() } else { "'N'".to_string() },
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::LocalTime;
+    use rstest::rstest;
+
+    const COLUMNS: [Column; 5] = [
+        Column::new("ID", ColumnType::Int, true),
+        Column::new("Hours", ColumnType::Decimal { scale: 2 }, true),
+        Column::new("Description", ColumnType::Varchar { length: 10 }, false),
+        Column::new("Start", ColumnType::DateTime, false),
+        Column::new("Locked", ColumnType::Boolean, true),
+    ];
+
+    fn row(hours: f64, description: Option<&str>) -> Vec<ColumnValue> {
+        vec![
+            ColumnValue::Int(1),
+            ColumnValue::Float(hours),
+            description.map(|description| ColumnValue::Text(description.to_string())).into(),
+            ColumnValue::DateTime(LocalDate::new(2025, 1, 6).at_time(LocalTime::new(7, 30, 0))),
+            ColumnValue::Boolean(false),
+        ]
+    }
+
+    #[rstest]
+    #[case(1.005, "1.01")]
+    #[case(2.0, "2.00")]
+    #[case(-0.125, "-0.13")]
+    #[case(9999999.994, "9999999.99")]
+    fn decimals_are_rounded_to_the_column_scale(#[case] hours: f64, #[case] expected: &str) {
+        let mut table = TableRows::new("Work", &COLUMNS);
+
+        table.push(row(hours, None)).unwrap();
+
+        assert_eq!(table.rows()[0][1], ColumnValue::Decimal(Decimal::from_str_exact(expected).unwrap()));
+        assert!(table.to_sql().contains(&format!(", {},", expected)));
+    }
+
+    #[test]
+    fn values_outside_the_columns_are_errors() {
+        let mut table = TableRows::new("Work", &COLUMNS);
+
+        let too_large = table.push(row(10_000_000.0, None)).unwrap_err();
+        let too_long = table.push(row(1.0, Some("Deep cleaning"))).unwrap_err();
+        let mut missing = row(1.0, None);
+        missing[4] = ColumnValue::Null;
+        let missing = table.push(missing).unwrap_err();
+        let mut text_id = row(1.0, None);
+        text_id[0] = ColumnValue::Text("7d4f6a0e-1111-4a5b-9c2d-000000000001".to_string());
+        let text_id = table.push(text_id).unwrap_err();
+
+        assert_eq!(too_large.to_string(), "Work.Hours: 10000000 does not fit decimal(9,2)");
+        assert_eq!(too_long.to_string(), "Work.Description: \"Deep cleaning\" is longer than 10 characters");
+        assert_eq!(missing.to_string(), "Work.Locked: is required");
+        assert_eq!(text_id.to_string(), "Work.ID: \"7d4f6a0e-1111-4a5b-9c2d-000000000001\" is not an int");
+        assert!(table.rows().is_empty());
+    }
+
+    #[test]
+    fn rows_are_written_as_csv_and_sql() {
+        let mut table = TableRows::new("Work", &COLUMNS);
+        table.push(row(1.5, Some("O'Neil, A"))).unwrap();
+        table.push(row(2.0, None)).unwrap();
+
+        assert_eq!(table.to_csv(), "ID,Hours,Description,Start,Locked\n\
+            1,1.50,\"O'Neil, A\",2025-01-06 07:30:00,N\n\
+            1,2.00,,2025-01-06 07:30:00,N\n");
+        assert_eq!(table.to_sql(), "INSERT INTO Work (ID, Hours, Description, Start, Locked) VALUES (1, 1.50, 'O''Neil, A', '2025-01-06 07:30:00', 'N');\n\
+            INSERT INTO Work (ID, Hours, Description, Start, Locked) VALUES (1, 2.00, NULL, '2025-01-06 07:30:00', 'N');\n");
+    }
+}
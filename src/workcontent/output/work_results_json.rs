@@ -0,0 +1,379 @@
+use serde_json::{json, Value};
+use crate::workcontent::common::date_times::{format_date, format_date_time};
+use crate::workcontent::domain::job::JobId;
+use crate::workcontent::domain::labor_data::LaborData;
+use crate::workcontent::domain::location::LocationId;
+use crate::workcontent::domain::planned_shift::{PlannedShift, PlannedShiftId};
+use crate::workcontent::domain::planned_shift_source::PlannedShiftSource;
+use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+use crate::workcontent::domain::shift_category::ShiftCategoryId;
+use crate::workcontent::domain::work_content::{WorkContent, WorkContentId};
+use crate::workcontent::generators::calculator::work_content_log::{WorkContentLog, WorkContentLogDetail};
+use crate::workcontent::generators::work_generators::WorkResults;
+use crate::workcontent::loader::json_node::JsonNode;
+use crate::workcontent::loader::load_error::LoadError;
+
+/// Version of the output format, written as `"version"`.
+pub const WORK_RESULTS_VERSION: u32 = 1;
+
+const SHIFT_TYPES: [(&str, PlannedShiftType); 4] = [
+    ("Projected", PlannedShiftType::Projected),
+    ("Original", PlannedShiftType::Original),
+    ("Standard", PlannedShiftType::Standard),
+    ("Generated", PlannedShiftType::Generated),
+];
+
+const SHIFT_SOURCES: [(&str, PlannedShiftSource); 3] = [
+    ("Auto", PlannedShiftSource::Auto),
+    ("Manual", PlannedShiftSource::Manual),
+    ("Rule", PlannedShiftSource::Rule),
+];
+
+/// Writes generated work results as JSON and reads them back.
+///
+/// Dates are written as `yyyy-MM-dd`, date times as `yyyy-MM-ddTHH:mm:ss` and shift durations
+/// as decimal strings so that they keep their scale. `shifts` and `laborData` are `null` when a
+/// generator did not produce them. Keys are written in alphabetical order, so the same results
+/// always give the same document.
+#[derive(Default)]
+pub struct WorkResultsJson;
+
+impl WorkResultsJson {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn to_json(&self, work_results: &[WorkResults]) -> String {
+        let document = json!({
+            "version": WORK_RESULTS_VERSION,
+            "results": work_results.iter().map(Self::work_results_value).collect::<Vec<_>>(),
+        });
+        serde_json::to_string_pretty(&document).expect("work results are always valid JSON")
+    }
+
+    pub fn from_json(&self, json: &str) -> Result<Vec<WorkResults>, LoadError> {
+        let value: Value = serde_json::from_str(json)?;
+        let root = JsonNode::root(&value);
+
+        let version_node = root.field("version")?;
+        let version = version_node.as_u32()?;
+        if version != WORK_RESULTS_VERSION {
+            return Err(version_node.error(format!("unknown version {}, expected {}", version, WORK_RESULTS_VERSION)));
+        }
+
+        let mut work_results = Vec::new();
+        for node in root.field("results")?.array()? {
+            work_results.push(Self::work_results(&node)?);
+        }
+        Ok(work_results)
+    }
+
+    fn work_results_value(work_results: &WorkResults) -> Value {
+        json!({
+            "jobId": work_results.job_id().to_string(),
+            "shifts": work_results.shifts()
+                .map(|shifts| shifts.iter().map(Self::shift_value).collect::<Vec<_>>()),
+            "laborData": work_results.labor_data()
+                .map(|labor_data| labor_data.iter().map(Self::labor_data_value).collect::<Vec<_>>()),
+            "workContents": work_results.work_contents().iter().map(Self::work_content_value).collect::<Vec<_>>(),
+            "workContentLogs": work_results.work_content_logs().iter().map(Self::work_content_log_value).collect::<Vec<_>>(),
+        })
+    }
+
+    fn shift_value(shift: &PlannedShift) -> Value {
+        json!({
+            "id": shift.id().to_string(),
+            "locationId": shift.location_id().to_string(),
+            "jobId": shift.job_id().to_string(),
+            "shiftType": format!("{:?}", shift.shift_type()),
+            "shiftDate": format_date(shift.shift_date()),
+            "dateShiftGeneratedFrom": format_date(shift.date_shift_generated_from()),
+            "startDateTime": format_date_time(shift.start_date_time()),
+            "endDateTime": format_date_time(shift.end_date_time()),
+            "duration": shift.duration().to_string(),
+            "source": format!("{:?}", shift.source()),
+            "shiftCategoryId": shift.shift_category_id().map(|id| id.to_string()),
+            "assignmentId": shift.assignment_id().map(|id| id.to_string()),
+            "workContentIds": shift.work_content_ids().iter().map(|id| id.to_string()).collect::<Vec<_>>(),
+        })
+    }
+
+    fn labor_data_value(labor_data: &LaborData) -> Value {
+        json!({
+            "jobId": labor_data.job_id().to_string(),
+            "date": format_date(labor_data.date()),
+            "hours": labor_data.hours(),
+        })
+    }
+
+    fn work_content_value(work_content: &WorkContent) -> Value {
+        json!({
+            "id": work_content.id().to_string(),
+            "jobId": work_content.job_id().to_string(),
+            "propertyId": work_content.property_id().to_string(),
+            "shiftType": format!("{:?}", work_content.shift_type()),
+            "shiftDate": format_date(work_content.shift_date()),
+            "earliestStartDateTime": format_date_time(work_content.earliest_start_date_time()),
+            "preferredStartDateTime": format_date_time(work_content.preferred_start_date_time()),
+            "latestEndDateTime": format_date_time(work_content.latest_end_date_time()),
+            "calculatedStartDateTime": format_date_time(work_content.calculated_start_date_time()),
+            "calculatedEndDateTime": format_date_time(work_content.calculated_end_date_time()),
+            "calculatedHours": work_content.calculated_hours(),
+            "adjustedHours": work_content.adjusted_hours(),
+            "locked": work_content.is_locked(),
+            "description": work_content.description(),
+            "minNumberEmployees": work_content.min_number_employees(),
+            "minSkillLevel": work_content.min_skill_level(),
+            "distributedToDateTime": format_date_time(work_content.distributed_to_date_time()),
+        })
+    }
+
+    fn work_content_log_value(work_content_log: &WorkContentLog) -> Value {
+        json!({
+            "jobId": work_content_log.job_id().to_string(),
+            "shiftDate": format_date(work_content_log.shift_date()),
+            "description": work_content_log.description(),
+            "details": work_content_log.details().iter()
+                .map(|(name, detail)| json!({
+                    "name": name,
+                    "workInMinutes": detail.work_in_minutes(),
+                    "formula": detail.formula(),
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    /// Results have either shifts or labor data.
+    fn work_results(node: &JsonNode) -> Result<WorkResults, LoadError> {
+        let job_id = JobId::from(node.field("jobId")?.as_uuid()?);
+
+        let work_results = match (node.optional_field("shifts"), node.optional_field("laborData")) {
+            (Some(shifts), None) => {
+                let mut planned_shifts = Vec::new();
+                for shift in shifts.array()? {
+                    planned_shifts.push(Self::shift(&shift)?);
+                }
+                WorkResults::with_shifts(job_id, planned_shifts)
+            }
+            (None, Some(labor_data)) => {
+                let mut labor = Vec::new();
+                for labor_data in labor_data.array()? {
+                    labor.push(LaborData::new(
+                        JobId::from(labor_data.field("jobId")?.as_uuid()?),
+                        labor_data.field("date")?.as_date()?,
+                        labor_data.field("hours")?.as_f64()?,
+                    ));
+                }
+                WorkResults::with_labor_data(job_id, labor)
+            }
+            (Some(_), Some(labor_data)) => return Err(labor_data.error("expected either shifts or labor data, not both")),
+            (None, None) => return Err(node.error("expected shifts or labor data")),
+        };
+
+        let mut work_contents = Vec::new();
+        for work_content in node.optional_array("workContents")? {
+            work_contents.push(Self::work_content(&work_content)?);
+        }
+
+        let mut work_content_logs = Vec::new();
+        for work_content_log in node.optional_array("workContentLogs")? {
+            work_content_logs.push(Self::work_content_log(&work_content_log)?);
+        }
+
+        Ok(work_results
+            .with_work_contents(work_contents)
+            .with_work_content_logs(work_content_logs))
+    }
+
+    /// The duration is always that of the start and end date times, so it is checked rather than
+    /// read.
+    fn shift(node: &JsonNode) -> Result<PlannedShift, LoadError> {
+        let shift = PlannedShift::new(
+            LocationId::from(node.field("locationId")?.as_uuid()?),
+            JobId::from(node.field("jobId")?.as_uuid()?),
+            node.field("shiftType")?.as_enum(&SHIFT_TYPES)?,
+            node.field("shiftDate")?.as_date()?,
+            node.field("startDateTime")?.as_date_time()?,
+            node.field("endDateTime")?.as_date_time()?,
+        )
+            .with_id(PlannedShiftId::from(node.field("id")?.as_uuid()?))
+            .with_date_shift_generated_from(node.field("dateShiftGeneratedFrom")?.as_date()?)
+            .with_source(node.field("source")?.as_enum(&SHIFT_SOURCES)?)
+            .with_shift_category_id(match node.optional_field("shiftCategoryId") {
+                Some(field) => Some(ShiftCategoryId::from(field.as_uuid()?)),
+                None => None,
+            })
+            .with_assignment_id(match node.optional_field("assignmentId") {
+                Some(field) => Some(JobId::from(field.as_uuid()?)),
+                None => None,
+            });
+
+        let mut work_content_ids = Vec::new();
+        for work_content_id in node.optional_array("workContentIds")? {
+            work_content_ids.push(WorkContentId::from(work_content_id.as_uuid()?));
+        }
+        let shift = shift.with_work_content_ids(work_content_ids);
+
+        if let Some(duration) = node.optional_field("duration")
+            && duration.as_decimal()? != shift.duration() {
+            return Err(duration.error(format!("expected the duration of the shift, {}", shift.duration())));
+        }
+
+        Ok(shift)
+    }
+
+    fn work_content(node: &JsonNode) -> Result<WorkContent, LoadError> {
+        Ok(WorkContent::new(
+            JobId::from(node.field("jobId")?.as_uuid()?),
+            LocationId::from(node.field("propertyId")?.as_uuid()?),
+            node.field("shiftType")?.as_enum(&SHIFT_TYPES)?,
+            node.field("shiftDate")?.as_date()?,
+            node.field("earliestStartDateTime")?.as_date_time()?,
+            node.field("preferredStartDateTime")?.as_date_time()?,
+            node.field("latestEndDateTime")?.as_date_time()?,
+            node.field("calculatedStartDateTime")?.as_date_time()?,
+            node.field("calculatedEndDateTime")?.as_date_time()?,
+            node.field("calculatedHours")?.as_f64()?,
+            node.field("adjustedHours")?.as_f64()?,
+            node.field("locked")?.as_bool()?,
+            match node.optional_field("description") {
+                Some(description) => description.as_str()?.to_string(),
+                None => String::new(),
+            },
+            node.field("minNumberEmployees")?.as_u32()?,
+            node.field("minSkillLevel")?.as_u32()?,
+            node.field("distributedToDateTime")?.as_date_time()?,
+        ).with_id(WorkContentId::from(node.field("id")?.as_uuid()?)))
+    }
+
+    fn work_content_log(node: &JsonNode) -> Result<WorkContentLog, LoadError> {
+        let mut details = Vec::new();
+        for detail in node.optional_array("details")? {
+            let name = match detail.optional_field("name") {
+                Some(name) => name.as_str()?.to_string(),
+                None => String::new(),
+            };
+            let formula = match detail.optional_field("formula") {
+                Some(formula) => formula.as_str()?.to_string(),
+                None => String::new(),
+            };
+            details.push((name, WorkContentLogDetail::new(detail.field("workInMinutes")?.as_f64()?, formula)));
+        }
+
+        Ok(WorkContentLog::new(
+            JobId::from(node.field("jobId")?.as_uuid()?),
+            node.field("shiftDate")?.as_date()?,
+            match node.optional_field("description") {
+                Some(description) => description.as_str()?.to_string(),
+                None => String::new(),
+            },
+            details,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use joda_rs::{LocalDate, LocalTime};
+
+    fn date() -> LocalDate {
+        LocalDate::new(2025, 1, 6)
+    }
+
+    fn shift_results() -> WorkResults {
+        let job_id = JobId::new();
+        let location_id = LocationId::new();
+        let shift = PlannedShift::new(
+            location_id,
+            job_id,
+            PlannedShiftType::Projected,
+            date(),
+            date().at_time(LocalTime::new(22, 0, 0)),
+            date().plus_days(1).at_time(LocalTime::new(6, 30, 0)),
+        )
+            .with_shift_category_id(Some(ShiftCategoryId::new()))
+            .with_date_shift_generated_from(date().minus_days(1));
+        let work_content = WorkContent::new(
+            job_id,
+            location_id,
+            PlannedShiftType::Projected,
+            date(),
+            date().at_time(LocalTime::new(7, 0, 0)),
+            date().at_time(LocalTime::new(8, 0, 0)),
+            date().at_time(LocalTime::new(15, 0, 0)),
+            date().at_time(LocalTime::new(8, 0, 0)),
+            date().at_time(LocalTime::new(9, 30, 0)),
+            1.5,
+            1.5,
+            false,
+            "Check-ins \"front\"".to_string(),
+            1,
+            0,
+            date().at_time(LocalTime::new(9, 30, 0)),
+        );
+        let work_content_log = WorkContentLog::new(job_id, date(), "Check-ins".to_string(), vec![
+            ("Check-ins".to_string(), WorkContentLogDetail::new(90.0, "3 * 30".to_string())),
+        ]);
+
+        WorkResults::with_shifts(job_id, vec![shift.with_work_content_ids(vec![work_content.id()])])
+            .with_work_contents(vec![work_content])
+            .with_work_content_logs(vec![work_content_log])
+    }
+
+    #[test]
+    fn work_results_round_trip() {
+        let job_id = JobId::new();
+        let work_results = vec![
+            shift_results(),
+            WorkResults::with_labor_data(job_id, vec![LaborData::new(job_id, date(), 7.333333333333333)]),
+        ];
+        let json_writer = WorkResultsJson::new();
+
+        let json = json_writer.to_json(&work_results);
+        let reloaded = json_writer.from_json(&json).unwrap();
+
+        assert_eq!(reloaded, work_results);
+        assert_eq!(json_writer.to_json(&reloaded), json);
+    }
+
+    #[test]
+    fn shifts_are_written_in_the_output_format() {
+        let work_results = shift_results();
+        let shift = &work_results.shifts().unwrap()[0];
+
+        let value: Value = serde_json::from_str(&WorkResultsJson::new().to_json(std::slice::from_ref(&work_results))).unwrap();
+        let written = &value["results"][0];
+
+        assert_eq!(value["version"], 1);
+        assert_eq!(written["laborData"], Value::Null);
+        assert_eq!(written["shifts"][0], json!({
+            "id": shift.id().to_string(),
+            "locationId": shift.location_id().to_string(),
+            "jobId": shift.job_id().to_string(),
+            "shiftType": "Projected",
+            "shiftDate": "2025-01-06",
+            "dateShiftGeneratedFrom": "2025-01-05",
+            "startDateTime": "2025-01-06T22:00:00",
+            "endDateTime": "2025-01-07T06:30:00",
+            "duration": "8.50",
+            "source": "Auto",
+            "shiftCategoryId": shift.shift_category_id().unwrap().to_string(),
+            "assignmentId": null,
+            "workContentIds": [work_results.work_contents()[0].id().to_string()],
+        }));
+    }
+
+    #[test]
+    fn bad_fields_are_reported_with_their_path() {
+        let json = WorkResultsJson::new().to_json(&[shift_results()])
+            .replace("2025-01-06T22:00:00", "2025-01-06 22:00");
+
+        let error = WorkResultsJson::new().from_json(&json).unwrap_err();
+
+        assert_eq!(error, LoadError::invalid_field(
+            "$.results[0].shifts[0].startDateTime",
+            "expected a date and time (yyyy-MM-ddTHH:mm:ss) but was \"2025-01-06 22:00\"",
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod repository_error;
+pub mod table_ddl;
+pub mod sqlite_repository;
@@ -0,0 +1,54 @@
+use std::fmt;
+use uuid::Uuid;
+use crate::workcontent::output::table_rows::ExportError;
+
+/// Why a model could not be loaded from, or results saved to, the database. Bad values name the
+/// table, row id and column, e.g. `AssignmentPlannerSettings 3.StandardType`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepositoryError {
+    Database { message: String },
+    MissingTable { table: String },
+    InvalidValue { table: String, id: i64, column: String, message: String },
+    /// An id of the results that was not loaded from the database.
+    NotADatabaseId { uuid: Uuid },
+    Export(ExportError),
+}
+
+impl RepositoryError {
+    pub fn invalid_value(table: &str, id: i64, column: &str, message: impl Into<String>) -> Self {
+        RepositoryError::InvalidValue {
+            table: table.to_string(),
+            id,
+            column: column.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::Database { message } => write!(f, "database error: {}", message),
+            RepositoryError::MissingTable { table } => write!(f, "the schema has no table {}", table),
+            RepositoryError::InvalidValue { table, id, column, message } => write!(f, "{} {}.{}: {}", table, id, column, message),
+            RepositoryError::NotADatabaseId { uuid } => write!(f, "{} is not a database id", uuid),
+            RepositoryError::Export(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+impl From<rusqlite::Error> for RepositoryError {
+    fn from(error: rusqlite::Error) -> Self {
+        RepositoryError::Database {
+            message: error.to_string(),
+        }
+    }
+}
+
+impl From<ExportError> for RepositoryError {
+    fn from(error: ExportError) -> Self {
+        RepositoryError::Export(error)
+    }
+}
@@ -0,0 +1,677 @@
+use std::collections::HashMap;
+use std::path::Path;
+use date_range_rs::DateRange;
+use joda_rs::{DayOfWeek, LocalDate};
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, Row, Transaction};
+use rust_decimal::prelude::ToPrimitive;
+use uuid::Uuid;
+use crate::workcontent::common::date_times::{format_date, format_date_time};
+use crate::workcontent::common::db_code::DbCode;
+use crate::workcontent::domain::business_driver::BusinessDriverId;
+use crate::workcontent::domain::environment::Environment;
+use crate::workcontent::domain::environment_calendar::{EnvironmentCalendar, EnvironmentDefinition, EnvironmentStat};
+use crate::workcontent::domain::job::{Job, JobId};
+use crate::workcontent::domain::job_shift::{JobShift, JobShiftDefinition, JobShiftId};
+use crate::workcontent::domain::location::LocationId;
+use crate::workcontent::domain::non_flowed_distribution_method::NonFlowedDistributionMethod;
+use crate::workcontent::domain::planned_shift_type::PlannedShiftType;
+use crate::workcontent::domain::planner_model::PlannerModel;
+use crate::workcontent::domain::planner_settings::PlannerSettings;
+use crate::workcontent::domain::planner_settings_resolver::{PlannerSettingsLayer, PlannerSettingsResolver};
+use crate::workcontent::domain::salaried_standard::SalariedStandard;
+use crate::workcontent::domain::salary_mode::SalaryMode;
+use crate::workcontent::domain::standard_set::StandardSetId;
+use crate::workcontent::domain::standard_type::StandardType;
+use crate::workcontent::generators::work_generators::WorkResults;
+use crate::workcontent::loader::json_node::{parse_date, parse_time};
+use crate::workcontent::output::schema_export::SchemaExporter;
+use crate::workcontent::output::table_rows::{ColumnValue, TableRows};
+use crate::workcontent::repository::repository_error::RepositoryError;
+use crate::workcontent::repository::table_ddl::create_table_statements;
+use crate::workcontent::schema::schema_catalogue::SchemaCatalogue;
+
+/// The tables of `schema.xml` the repository reads and writes. `Assignment` gives the location
+/// of each job and `KBI` the location of each business driver's environment stats; the link and
+/// detail tables hold the rest of the saved results.
+pub const PLANNER_TABLES: [&str; 13] = [
+    "Assignment",
+    "KBI",
+    "Environment",
+    "EnvStat",
+    "PropertyPlannerSettings",
+    "AssignmentPlannerSettings",
+    "AssignmentShift",
+    "AssignmentShiftDetail",
+    "SalariedStandard",
+    "WorkContent",
+    "PlannedShift",
+    "PlannedShiftWorkContent",
+    "WorkContentDetail",
+];
+
+/// The result tables, in the order their rows are deleted.
+const RESULT_TABLES: [&str; 4] = ["PlannedShiftWorkContent", "WorkContentDetail", "PlannedShift", "WorkContent"];
+
+/// The id of a domain object loaded from the row with the database id.
+pub fn database_uuid(database_id: i64) -> Uuid {
+    Uuid::from_u64_pair(0, database_id as u64)
+}
+
+/// The database id of a domain object loaded from the database.
+pub fn database_id(uuid: &Uuid) -> Result<i64, RepositoryError> {
+    match uuid.as_u64_pair() {
+        (0, id) if id <= i64::MAX as u64 => Ok(id as i64),
+        _ => Err(RepositoryError::NotADatabaseId { uuid: *uuid }),
+    }
+}
+
+/// Loads planner models from, and saves work results to, a SQLite database holding the planner
+/// tables of `schema.xml`. Domain ids of loaded rows are made from their database ids, see
+/// [`database_uuid`], so results can only be saved for models loaded from the database.
+pub struct SqliteRepository {
+    connection: Connection,
+}
+
+impl SqliteRepository {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            connection: Connection::open(path)?,
+        })
+    }
+
+    pub fn open_in_memory() -> Result<Self, RepositoryError> {
+        Ok(Self {
+            connection: Connection::open_in_memory()?,
+        })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// Creates the planner tables that do not exist yet from their schema definitions.
+    pub fn create_tables(&self, catalogue: &SchemaCatalogue) -> Result<(), RepositoryError> {
+        for name in PLANNER_TABLES {
+            let table = catalogue.table(name).ok_or_else(|| RepositoryError::MissingTable { table: name.to_string() })?;
+            for statement in create_table_statements(table) {
+                self.connection.execute(&statement, [])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The jobs of the location with planner settings for the standard set, with their shifts and
+    /// salaried standards, and the location's environment calendar for the dates. A job's
+    /// settings left null inherit the location's `PropertyPlannerSettings` for the standard set.
+    /// The database holds no effective dates, so settings apply all year. The location's default
+    /// shift category is not a planner table; set it on the model.
+    pub fn load_model(&self, location_id: i64, standard_set_id: i64, dates: DateRange) -> Result<PlannerModel, RepositoryError> {
+        let mut statement = self.connection.prepare(
+            "SELECT s.ID, s.AssignmentID, s.StandardType, s.PeriodLength, s.MinShift, s.MaxShift,
+                    s.RoundingThresholdBelowOne, s.RoundingThresholdAboveOne, s.MealBreakAfter, s.MealBreakLength,
+                    s.NonMealBreakEvery, s.NonMealBreakLength, s.GenerateLongShifts, s.LimitShiftToMaxShift,
+                    s.TruncateMaxCoverage, s.DefaultNonFlowedDistributionMethod
+             FROM AssignmentPlannerSettings s
+             JOIN Assignment a ON a.ID = s.AssignmentID
+             WHERE a.PropertyID = ?1 AND s.StandardSetID = ?2
+             ORDER BY s.AssignmentID",
+        )?;
+        let mut rows = statement.query(params![location_id, standard_set_id])?;
+        let resolver = self.planner_settings_resolver(location_id, standard_set_id)?;
+
+        let mut jobs = Vec::new();
+        while let Some(row) = rows.next()? {
+            let job_id: i64 = row.get(1)?;
+            let planner_settings = Self::planner_settings(row, &resolver, StandardSetId::from(database_uuid(standard_set_id)), dates)?;
+            let shifts = self.shifts(job_id, standard_set_id)?;
+            let salaried_standards = self.salaried_standards(job_id, standard_set_id, &shifts)?;
+
+            jobs.push(Job::new(LocationId::from(database_uuid(location_id)), planner_settings, shifts, salaried_standards)
+                .with_id(JobId::from(database_uuid(job_id))));
+        }
+
+        Ok(PlannerModel::new(
+            dates,
+            LocationId::from(database_uuid(location_id)),
+            StandardSetId::from(database_uuid(standard_set_id)),
+            jobs,
+            vec![],
+            vec![],
+        ).with_environment_calendar(self.environment_calendar(location_id, dates)?))
+    }
+
+    /// Saves the work content and planned shifts of the results in one transaction, replacing the
+    /// results saved before for the same jobs, plan types and dates. Labor data has no planner
+    /// table and is not saved.
+    pub fn save_results(&mut self, work_results: &[WorkResults]) -> Result<(), RepositoryError> {
+        let mut exporter = SchemaExporter::new().with_first_id(self.next_result_id()?);
+        for uuid in Self::result_uuids(work_results) {
+            exporter = exporter.with_database_id(uuid, database_id(&uuid)?);
+        }
+        let tables = exporter.export(work_results)?;
+
+        let transaction = self.connection.transaction()?;
+        for results in work_results {
+            Self::delete_results(&transaction, results)?;
+        }
+        for table in &tables {
+            Self::insert_rows(&transaction, table)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// The settings of the location for the standard set, which assignment settings override.
+    fn planner_settings_resolver(&self, location_id: i64, standard_set_id: i64) -> Result<PlannerSettingsResolver, RepositoryError> {
+        let mut statement = self.connection.prepare(
+            "SELECT ID, PeriodLength, MinShift, MaxShift, RoundingThresholdBelowOne, RoundingThresholdAboveOne,
+                    MealBreakAfter, MealBreakLength, NonMealBreakEvery, NonMealBreakLength, GenerateLongShifts,
+                    LimitShiftToMaxShift, TruncateMaxCoverage, DefaultNonFlowedDistributionMethod
+             FROM PropertyPlannerSettings
+             WHERE PropertyID = ?1 AND StandardSetID = ?2",
+        )?;
+        let mut rows = statement.query(params![location_id, standard_set_id])?;
+
+        let mut resolver = PlannerSettingsResolver::new();
+        if let Some(row) = rows.next()? {
+            let property_settings = Self::planner_settings_layer("PropertyPlannerSettings", row, 1)?;
+            resolver = resolver.with_property_settings(StandardSetId::from(database_uuid(standard_set_id)), property_settings);
+        }
+        Ok(resolver)
+    }
+
+    /// The settings of a `PropertyPlannerSettings` or `AssignmentPlannerSettings` row, from
+    /// `PeriodLength` at the first column to `DefaultNonFlowedDistributionMethod`. Null columns
+    /// inherit.
+    fn planner_settings_layer(table: &str, row: &Row, first_column: usize) -> Result<PlannerSettingsLayer, RepositoryError> {
+        let id: i64 = row.get(0)?;
+        let non_flowed_distribution_method = match row.get::<_, Option<String>>(first_column + 12)? {
+            Some(code) => Some(NonFlowedDistributionMethod::from_code(&code)
+                .map_err(|error| RepositoryError::invalid_value(table, id, "DefaultNonFlowedDistributionMethod", error.to_string()))?),
+            None => None,
+        };
+
+        Ok(PlannerSettingsLayer {
+            standard_type: None,
+            period_length: row.get(first_column)?,
+            min_shift_length: row.get(first_column + 1)?,
+            max_shift_length: row.get(first_column + 2)?,
+            rounding_threshold_below_one: row.get(first_column + 3)?,
+            rounding_threshold_above_one: row.get(first_column + 4)?,
+            meal_break_after: row.get(first_column + 5)?,
+            meal_break_length: row.get(first_column + 6)?,
+            non_meal_break_every: row.get(first_column + 7)?,
+            non_meal_break_length: row.get(first_column + 8)?,
+            generate_long_shifts: row.get(first_column + 9)?,
+            limit_shift_to_max_shift: row.get(first_column + 10)?,
+            truncate_max_coverage: row.get(first_column + 11)?,
+            non_flowed_distribution_method,
+        })
+    }
+
+    fn planner_settings(row: &Row, resolver: &PlannerSettingsResolver, standard_set_id: StandardSetId, dates: DateRange) -> Result<PlannerSettings, RepositoryError> {
+        let id: i64 = row.get(0)?;
+        let year_start = LocalDate::new(dates.start_date().year(), 1, 1);
+
+        let mut assignment_settings = Self::planner_settings_layer("AssignmentPlannerSettings", row, 3)?;
+        if let Some(code) = row.get::<_, Option<String>>(2)? {
+            assignment_settings.standard_type = Some(StandardType::from_code(&code)
+                .map_err(|error| RepositoryError::invalid_value("AssignmentPlannerSettings", id, "StandardType", error.to_string()))?);
+        }
+
+        let mut settings = resolver.resolve(standard_set_id, &assignment_settings);
+        settings.effective_dates = vec![DateRange::new(year_start, year_start.plus_years(1).minus_days(1))];
+        Ok(settings)
+    }
+
+    /// The environments of the location and the environment stats of its business drivers (KBIs)
+    /// on the dates.
+    fn environment_calendar(&self, location_id: i64, dates: DateRange) -> Result<EnvironmentCalendar, RepositoryError> {
+        let mut statement = self.connection.prepare(
+            "SELECT ID, Name, DayOfWeek, IgnoreDOW, SystemEnv FROM Environment
+             WHERE PropertyID = ?1
+             ORDER BY ID",
+        )?;
+        let mut rows = statement.query(params![location_id])?;
+
+        let mut environments = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id: i32 = row.get(0)?;
+            let mut environment = EnvironmentDefinition::new(Environment::new(id), row.get(1)?)
+                .with_ignore_day_of_week(row.get(3)?)
+                .with_system_environment(row.get(4)?);
+            if let Some(day_of_week) = row.get::<_, Option<i32>>(2)? {
+                let day_of_week = DayOfWeek::try_of(day_of_week)
+                    .map_err(|_| RepositoryError::invalid_value("Environment", id as i64, "DayOfWeek", format!("{} is not a day of week", day_of_week)))?;
+                environment = environment.with_day_of_week(day_of_week);
+            }
+            environments.push(environment);
+        }
+
+        let mut statement = self.connection.prepare(
+            "SELECT e.ID, e.KBIID, e.EnvDate, e.ForecastEnvID, e.ActualEnvID
+             FROM EnvStat e
+             JOIN KBI k ON k.ID = e.KBIID
+             WHERE k.PropertyID = ?1 AND e.EnvDate BETWEEN ?2 AND ?3
+             ORDER BY e.ID",
+        )?;
+        let mut rows = statement.query(params![location_id, format_date(dates.start_date()), format_date(dates.end_date())])?;
+
+        let mut stats = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id: i64 = row.get(0)?;
+            let date: String = row.get(2)?;
+            let date = parse_date(&date)
+                .ok_or_else(|| RepositoryError::invalid_value("EnvStat", id, "EnvDate", format!("\"{}\" is not a date", date)))?;
+            stats.push(EnvironmentStat::new(
+                date,
+                Some(BusinessDriverId::from(database_uuid(row.get(1)?))),
+                row.get::<_, Option<i32>>(3)?.map(Environment::new),
+                row.get::<_, Option<i32>>(4)?.map(Environment::new),
+            ));
+        }
+
+        Ok(EnvironmentCalendar::new(environments, stats))
+    }
+
+    fn shifts(&self, job_id: i64, standard_set_id: i64) -> Result<Vec<JobShift>, RepositoryError> {
+        let mut statement = self.connection.prepare(
+            "SELECT ID, ShiftNo, ShiftName FROM AssignmentShift
+             WHERE AssignmentID = ?1 AND StandardSetID = ?2
+             ORDER BY ShiftNo",
+        )?;
+        let mut rows = statement.query(params![job_id, standard_set_id])?;
+
+        let mut shifts = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id: i64 = row.get(0)?;
+            shifts.push(JobShift::new(
+                JobId::from(database_uuid(job_id)),
+                StandardSetId::from(database_uuid(standard_set_id)),
+                row.get(2)?,
+                row.get(1)?,
+                self.shift_definitions(id)?,
+            ).with_id(JobShiftId::from(database_uuid(id))));
+        }
+        Ok(shifts)
+    }
+
+    /// Details that have start and end times, for the day of week of system environments and for
+    /// every date of other environments. Whether an environment is a system environment, and of
+    /// which day, is read from its `Environment` row.
+    fn shift_definitions(&self, shift_id: i64) -> Result<Vec<JobShiftDefinition>, RepositoryError> {
+        let mut statement = self.connection.prepare(
+            "SELECT d.ID, d.EnvironmentID, d.StartTime, d.EndTime, d.HoursBefore, d.HoursAfter, d.MinNumberOfShifts,
+                    d.ShiftGroupingForSameEvent, d.WeightingFactor, e.ID, e.SystemEnv, e.DayOfWeek
+             FROM AssignmentShiftDetail d
+             LEFT JOIN Environment e ON e.ID = d.EnvironmentID
+             WHERE d.AssignmentShiftID = ?1
+             ORDER BY d.EnvironmentID",
+        )?;
+        let mut rows = statement.query(params![shift_id])?;
+
+        let mut definitions = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id: i64 = row.get(0)?;
+            let time = |index: usize, column: &str| -> Result<Option<_>, RepositoryError> {
+                match row.get::<_, Option<String>>(index)? {
+                    Some(time) => parse_time(&time)
+                        .map(Some)
+                        .ok_or_else(|| RepositoryError::invalid_value("AssignmentShiftDetail", id, column, format!("\"{}\" is not a time", time))),
+                    None => Ok(None),
+                }
+            };
+            let (Some(start_time), Some(end_time)) = (time(2, "StartTime")?, time(3, "EndTime")?) else {
+                continue;
+            };
+
+            if row.get::<_, Option<i64>>(9)?.is_none() {
+                return Err(RepositoryError::invalid_value("AssignmentShiftDetail", id, "EnvironmentID", "not an environment"));
+            }
+            let system_environment: bool = row.get(10)?;
+            let definition = match row.get::<_, Option<i32>>(11)? {
+                Some(day_of_week) if system_environment => {
+                    let day_of_week = DayOfWeek::try_of(day_of_week)
+                        .map_err(|_| RepositoryError::invalid_value("AssignmentShiftDetail", id, "EnvironmentID", format!("{} is not a day of week", day_of_week)))?;
+                    JobShiftDefinition::new(day_of_week, start_time, end_time, row.get(4)?, row.get(5)?, row.get(6)?)
+                }
+                _ => {
+                    let environment = Environment::new(row.get(1)?);
+                    JobShiftDefinition::for_environment(environment, start_time, end_time, row.get(4)?, row.get(5)?, row.get(6)?)
+                }
+            };
+            definitions.push(definition
+                .with_shift_grouping_for_same_event(row.get(7)?)
+                .with_weighting_factor(row.get(8)?));
+        }
+        Ok(definitions)
+    }
+
+    fn salaried_standards(&self, job_id: i64, standard_set_id: i64, shifts: &[JobShift]) -> Result<Vec<SalariedStandard>, RepositoryError> {
+        let mut statement = self.connection.prepare(
+            "SELECT ID, AssignmentShiftID, SalaryMode, HoursPerWeek, VacationHoursPerYear, HoursPerYear
+             FROM SalariedStandard
+             WHERE AssignmentID = ?1 AND StandardSetID = ?2
+             ORDER BY ID",
+        )?;
+        let mut rows = statement.query(params![job_id, standard_set_id])?;
+
+        let mut standards = Vec::new();
+        while let Some(row) = rows.next()? {
+            let id: i64 = row.get(0)?;
+            let shift_id = JobShiftId::from(database_uuid(row.get(1)?));
+            let shift = shifts.iter()
+                .find(|shift| *shift.id() == shift_id)
+                .ok_or_else(|| RepositoryError::invalid_value("SalariedStandard", id, "AssignmentShiftID", "not a shift of the job and standard set"))?;
+            let code: String = row.get(2)?;
+
+            standards.push(SalariedStandard {
+                job_id: JobId::from(database_uuid(job_id)),
+                standard_set_id: StandardSetId::from(database_uuid(standard_set_id)),
+                shift: shift.clone(),
+                salary_mode: SalaryMode::from_code(&code)
+                    .map_err(|error| RepositoryError::invalid_value("SalariedStandard", id, "SalaryMode", error.to_string()))?,
+                hours_per_week: row.get::<_, Option<f64>>(3)?.unwrap_or(0.0),
+                vacation_hours_per_year: row.get::<_, Option<f64>>(4)?.unwrap_or(0.0),
+                hours_per_year: row.get::<_, Option<f64>>(5)?.unwrap_or(0.0),
+            });
+        }
+        Ok(standards)
+    }
+
+    /// One more than the largest id of the result tables, so every exported row gets a new id.
+    fn next_result_id(&self) -> Result<i64, RepositoryError> {
+        let mut max_id = 0;
+        for table in RESULT_TABLES {
+            let table_max: Option<i64> = self.connection.query_row(&format!("SELECT MAX(ID) FROM {}", table), [], |row| row.get(0))?;
+            max_id = max_id.max(table_max.unwrap_or(0));
+        }
+        Ok(max_id + 1)
+    }
+
+    fn result_uuids(work_results: &[WorkResults]) -> Vec<Uuid> {
+        let mut uuids = Vec::new();
+        for results in work_results {
+            uuids.push(*results.job_id().as_uuid());
+            for work_content in results.work_contents() {
+                uuids.push(*work_content.job_id().as_uuid());
+                uuids.push(*work_content.property_id().as_uuid());
+            }
+            for shift in results.shifts().into_iter().flatten() {
+                uuids.push(*shift.job_id().as_uuid());
+                uuids.push(*shift.location_id().as_uuid());
+                uuids.extend(shift.assignment_id().map(|id| *id.as_uuid()));
+                uuids.extend(shift.shift_category_id().map(|id| *id.as_uuid()));
+            }
+        }
+        uuids
+    }
+
+    fn delete_results(transaction: &Transaction, results: &WorkResults) -> Result<(), RepositoryError> {
+        let mut dates: HashMap<&str, (LocalDate, LocalDate)> = HashMap::new();
+        let mut add_date = |shift_type: PlannedShiftType, date: LocalDate| {
+            let range = dates.entry(shift_type.code()).or_insert((date, date));
+            *range = (range.0.min(date), range.1.max(date));
+        };
+        for work_content in results.work_contents() {
+            add_date(work_content.shift_type(), work_content.shift_date());
+        }
+        for shift in results.shifts().into_iter().flatten() {
+            add_date(shift.shift_type(), shift.shift_date());
+        }
+
+        let job_id = database_id(results.job_id().as_uuid())?;
+        for (shift_type, (start_date, end_date)) in dates {
+            let job_rows = "JobID = ?1 AND ShiftType = ?2 AND ShiftDate BETWEEN ?3 AND ?4";
+            let parameters = params![job_id, shift_type, format_date(start_date), format_date(end_date)];
+
+            transaction.execute(&format!("DELETE FROM PlannedShiftWorkContent WHERE PlannedShiftID IN (SELECT ID FROM PlannedShift WHERE {})", job_rows), parameters)?;
+            transaction.execute(&format!("DELETE FROM PlannedShiftWorkContent WHERE WorkContentID IN (SELECT ID FROM WorkContent WHERE {})", job_rows), parameters)?;
+            transaction.execute(&format!("DELETE FROM WorkContentDetail WHERE WorkContentID IN (SELECT ID FROM WorkContent WHERE {})", job_rows), parameters)?;
+            transaction.execute(&format!("DELETE FROM PlannedShift WHERE {}", job_rows), parameters)?;
+            transaction.execute(&format!("DELETE FROM WorkContent WHERE {}", job_rows), parameters)?;
+        }
+        Ok(())
+    }
+
+    fn insert_rows(transaction: &Transaction, table: &TableRows) -> Result<(), RepositoryError> {
+        let columns: Vec<&str> = table.columns().iter().map(|column| column.name).collect();
+        let placeholders: Vec<String> = (1..=columns.len()).map(|index| format!("?{}", index)).collect();
+        let mut statement = transaction.prepare(&format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table.table(),
+            columns.join(", "),
+            placeholders.join(", "),
+        ))?;
+
+        for row in table.rows() {
+            statement.execute(params_from_iter(row.iter().map(sqlite_value)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Dates and date times are stored as `yyyy-MM-dd` and `yyyy-MM-dd HH:mm:ss` text, booleans as
+/// 1 and 0.
+fn sqlite_value(value: &ColumnValue) -> Value {
+    match value {
+        ColumnValue::Null => Value::Null,
+        ColumnValue::Int(value) => Value::Integer(*value),
+        ColumnValue::Text(text) => Value::Text(text.clone()),
+        ColumnValue::Float(value) => Value::Real(*value),
+        ColumnValue::Decimal(value) => Value::Real(value.to_f64().unwrap_or_default()),
+        ColumnValue::Date(date) => Value::Text(format_date(*date)),
+        ColumnValue::DateTime(date_time) => Value::Text(format_date_time(*date_time).replace('T', " ")),
+        ColumnValue::Boolean(value) => Value::Integer(*value as i64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::domain::planner_settings_resolver::SettingSource;
+    use joda_rs::LocalTime;
+    use crate::workcontent::domain::planned_shift::PlannedShift;
+    use crate::workcontent::domain::shift_category::ShiftCategoryId;
+    use crate::workcontent::domain::work_content::WorkContent;
+    use crate::workcontent::main::main::generate_work_content;
+    use crate::workcontent::schema::schema_reader::SchemaReader;
+
+    const LOCATION_ID: i64 = 1;
+    const STANDARD_SET_ID: i64 = 5;
+    const JOB_ID: i64 = 10;
+
+    fn monday() -> LocalDate {
+        LocalDate::new(2025, 1, 6)
+    }
+
+    fn repository() -> SqliteRepository {
+        let catalogue = SchemaReader::new()
+            .read_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("resources").join("schema.xml"))
+            .unwrap();
+        let repository = SqliteRepository::open_in_memory().unwrap();
+        repository.create_tables(&catalogue).unwrap();
+
+        repository.connection().execute_batch(
+            "INSERT INTO Assignment (ID, PropertyID, Name, Code, EventLabor, ExcludeFromPayrollExport, SchedulingMethod, BalanceSchedules, DepartmentalSeniority)
+                VALUES (10, 1, 'Front Desk', 'FD', 0, 0, 'S', 0, 0), (11, 2, 'Bell Desk', 'BD', 0, 0, 'S', 0, 0);
+             INSERT INTO PropertyPlannerSettings (ID, PropertyID, StandardSetID, MinShift, RoundingThresholdBelowOne, LimitShiftToMaxShift)
+                VALUES (1, 1, 5, 3.0, 0.5, 1), (2, 1, 6, 2.0, 0.25, 0);
+             INSERT INTO AssignmentPlannerSettings (ID, AssignmentID, StandardSetID, StandardType, PeriodLength, MinShift, MaxShift, MealBreakAfter, MealBreakLength, GenerateLongShifts)
+                VALUES (1, 10, 5, 'S', 15, 4.5, 10.0, 6.0, 0.5, 1), (2, 11, 5, 'S', NULL, NULL, NULL, NULL, NULL, NULL);
+             INSERT INTO AssignmentShift (ID, AssignmentID, StandardSetID, ShiftNo, ShiftName, Wage)
+                VALUES (20, 10, 5, 2, 'Evening', 0), (21, 10, 5, 1, 'Day', 0), (22, 10, 6, 1, 'Day', 0);
+             INSERT INTO AssignmentShiftDetail (ID, AssignmentShiftID, EnvironmentID, StartTime, EndTime, HoursBefore, WeightingFactor)
+                VALUES (30, 21, 1, '07:00:00', '15:00:00', 1.5, 0), (31, 21, 107, '08:00:00', '16:00:00', 0, 0),
+                       (32, 21, 12, '09:00:00', '17:00:00', 0, 2), (33, 20, 1, NULL, NULL, 0, 0),
+                       (34, 21, 2, '10:00:00', '18:00:00', 0, 0);
+             INSERT INTO SalariedStandard (ID, AssignmentID, StandardSetID, AssignmentShiftID, SalaryMode, HoursPerWeek, VacationHoursPerYear, HoursPerYear)
+                VALUES (40, 10, 5, 21, 'M', 40, 80, 2000);
+             INSERT INTO KBI (ID, PropertyID, Name, Code, ShowInModule) VALUES (50, 1, 'Occupied rooms', 'ROOMS', 'A'), (51, 2, 'Covers', 'COVERS', 'A');
+             INSERT INTO Environment (ID, PropertyID, Name, DayOfWeek, IgnoreDOW, SystemEnv)
+                VALUES (1, 1, 'Monday', 1, 0, 1), (2, 1, 'Conference', NULL, 1, 0), (12, 1, 'High Season', NULL, 1, 0),
+                       (107, 1, 'Sunday', 7, 0, 1);
+             INSERT INTO EnvStat (ID, KBIID, EnvDate, ForecastEnvID, ActualEnvID)
+                VALUES (60, 50, '2025-01-07', 12, NULL), (61, 51, '2025-01-08', 12, 12), (62, 50, '2025-02-01', 12, 12);",
+        ).unwrap();
+        repository
+    }
+
+    fn work_results(job_id: JobId, shift_category_id: ShiftCategoryId, hours: f64) -> WorkResults {
+        let location_id = LocationId::from(database_uuid(LOCATION_ID));
+        let work_content = WorkContent::new(
+            job_id,
+            location_id,
+            PlannedShiftType::Projected,
+            monday(),
+            monday().at_time(LocalTime::new(7, 0, 0)),
+            monday().at_time(LocalTime::new(9, 0, 0)),
+            monday().at_time(LocalTime::new(17, 0, 0)),
+            monday().at_time(LocalTime::new(9, 0, 0)),
+            monday().at_time(LocalTime::new(11, 0, 0)),
+            hours,
+            hours,
+            false,
+            "Check-ins".to_string(),
+            1,
+            0,
+            monday().at_time(LocalTime::new(11, 0, 0)),
+        );
+        let shift = PlannedShift::new(
+            location_id,
+            job_id,
+            PlannedShiftType::Projected,
+            monday(),
+            monday().at_time(LocalTime::new(8, 0, 0)),
+            monday().at_time(LocalTime::new(12, 0, 0)),
+        )
+            .with_shift_category_id(Some(shift_category_id))
+            .with_work_content_ids(vec![work_content.id()]);
+
+        WorkResults::with_shifts(job_id, vec![shift]).with_work_contents(vec![work_content])
+    }
+
+    fn count(repository: &SqliteRepository, table: &str) -> i64 {
+        repository.connection().query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn database_ids_round_trip_through_uuids() {
+        assert_eq!(database_id(&database_uuid(42)), Ok(42));
+        assert!(database_id(&Uuid::new_v4()).is_err());
+    }
+
+    #[test]
+    fn loads_the_jobs_of_the_location_and_standard_set() {
+        let repository = repository();
+
+        let model = repository.load_model(LOCATION_ID, STANDARD_SET_ID, DateRange::new(monday(), monday().plus_days(6))).unwrap();
+
+        assert_eq!(model.jobs().len(), 1);
+        let job = &model.jobs()[0];
+        assert_eq!(job.id(), JobId::from(database_uuid(JOB_ID)));
+        let settings = job.planner_settings();
+        assert_eq!(settings.standard_type, StandardType::SALARIED);
+        assert_eq!((settings.period_length, settings.min_shift_length, settings.max_shift_length), (15, 4.5, 10.0));
+        assert_eq!(settings.meal_break.as_ref().map(|meal_break| meal_break.break_length), Some(0.5));
+        assert!(settings.generate_long_shifts && settings.non_meal_break.is_none());
+
+        let shift_names: Vec<&str> = job.shifts().iter().map(JobShift::name).collect();
+        assert_eq!(shift_names, ["Day", "Evening"]);
+        let definitions = job.shifts()[0].shift_definitions();
+        assert_eq!(definitions.len(), 4);
+        assert_eq!((definitions[0].day_of_week(), definitions[0].start_time(), definitions[0].hours_before()),
+                   (Some(DayOfWeek::Monday), LocalTime::new(7, 0, 0), 1.5));
+        // Environment 2 is not a system environment, and system environment 107 is Sunday's
+        assert_eq!((definitions[1].environment(), definitions[1].day_of_week()), (Some(Environment::new(2)), None));
+        assert_eq!((definitions[2].environment(), definitions[2].weighting_factor()), (Some(Environment::new(12)), 2));
+        assert_eq!(definitions[3].day_of_week(), Some(DayOfWeek::Sunday));
+        assert!(job.shifts()[1].shift_definitions().is_empty());
+        assert_eq!(job.salaried_standards()[0].shift.name(), "Day");
+
+        let results = generate_work_content(model);
+        assert_eq!(results[0].labor_data().unwrap().len(), 7);
+    }
+
+    #[test]
+    fn loads_the_environment_calendar_of_the_location() {
+        let repository = repository();
+
+        let model = repository.load_model(LOCATION_ID, STANDARD_SET_ID, DateRange::new(monday(), monday().plus_days(6))).unwrap();
+
+        let rooms_id = BusinessDriverId::from(database_uuid(50));
+        let calendar = model.environment_calendar();
+        assert_eq!(calendar.environments().len(), 4);
+        assert_eq!(calendar.environment_definition(Environment::new(12)).unwrap().name(), "High Season");
+        assert_eq!(model.environment_for(rooms_id, monday()), Environment::new(1));
+        assert_eq!(model.environment_for(rooms_id, monday().plus_days(1)), Environment::new(12));
+        assert_eq!(model.environment_for(BusinessDriverId::from(database_uuid(51)), monday().plus_days(2)), Environment::new(3));
+    }
+
+    #[test]
+    fn assignment_settings_override_the_location_settings() {
+        let repository = repository();
+
+        let model = repository.load_model(LOCATION_ID, STANDARD_SET_ID, DateRange::new(monday(), monday())).unwrap();
+
+        let settings = model.jobs()[0].planner_settings();
+        assert_eq!((settings.min_shift_length, settings.sources.min_shift_length), (4.5, SettingSource::Assignment));
+        assert_eq!((settings.rounding_threshold_below_one, settings.sources.rounding_threshold_below_one), (0.5, SettingSource::Property));
+        assert_eq!((settings.limit_shift_to_max_shift, settings.sources.limit_shift_to_max_shift), (true, SettingSource::Property));
+        assert_eq!(settings.sources.truncate_max_coverage, SettingSource::Default);
+    }
+
+    #[test]
+    fn settings_columns_left_null_keep_the_defaults() {
+        let repository = repository();
+
+        let model = repository.load_model(2, STANDARD_SET_ID, DateRange::new(monday(), monday())).unwrap();
+
+        let settings = model.jobs()[0].planner_settings();
+        assert_eq!(settings.period_length, PlannerSettings::default().period_length);
+        assert!(settings.meal_break.is_none());
+        assert!(model.jobs()[0].shifts().is_empty());
+    }
+
+    #[test]
+    fn bad_codes_name_the_row_and_column() {
+        let repository = repository();
+        repository.connection().execute("UPDATE AssignmentPlannerSettings SET StandardType = 'X' WHERE ID = 1", []).unwrap();
+
+        let error = repository.load_model(LOCATION_ID, STANDARD_SET_ID, DateRange::new(monday(), monday())).err().unwrap();
+
+        assert_eq!(error.to_string(), "AssignmentPlannerSettings 1.StandardType: StandardTypeEnum code \"X\" is not supported");
+    }
+
+    #[test]
+    fn saving_replaces_the_earlier_results() {
+        let mut repository = repository();
+        let job_id = JobId::from(database_uuid(JOB_ID));
+        let shift_category_id = ShiftCategoryId::from(database_uuid(3));
+
+        repository.save_results(&[work_results(job_id, shift_category_id, 2.0)]).unwrap();
+        repository.save_results(&[work_results(job_id, shift_category_id, 1.5)]).unwrap();
+
+        assert_eq!(count(&repository, "WorkContent"), 1);
+        assert_eq!(count(&repository, "PlannedShift"), 1);
+        assert_eq!(count(&repository, "PlannedShiftWorkContent"), 1);
+        assert_eq!(count(&repository, "WorkContentDetail"), 1);
+        let (id, hours, shift_type): (i64, f64, String) = repository.connection()
+            .query_row("SELECT ID, CalculatedHours, ShiftType FROM WorkContent WHERE JobID = 10 AND ShiftDate = '2025-01-06'", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .unwrap();
+        assert_eq!((id, hours, shift_type.as_str()), (2, 1.5, "F"));
+    }
+
+    #[test]
+    fn results_of_other_models_are_not_saved() {
+        let mut repository = repository();
+
+        let error = repository.save_results(&[work_results(JobId::new(), ShiftCategoryId::from(database_uuid(3)), 2.0)]).unwrap_err();
+
+        assert!(matches!(error, RepositoryError::NotADatabaseId { .. }));
+        assert_eq!(count(&repository, "WorkContent"), 0);
+    }
+}
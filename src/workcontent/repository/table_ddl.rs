@@ -0,0 +1,105 @@
+use crate::workcontent::schema::schema_catalogue::{SchemaColumn, SchemaColumnType, SchemaKeyType, SchemaTable};
+
+/// `CREATE TABLE` and `CREATE INDEX` statements for a schema table. Primary and unique keys
+/// become table constraints; relations are left out so the planner tables can be created without
+/// the rest of the schema.
+pub fn create_table_statements(table: &SchemaTable) -> Vec<String> {
+    let mut definitions: Vec<String> = table.columns.iter().map(column_definition).collect();
+    let mut indexes = Vec::new();
+
+    for key in &table.keys {
+        let columns = key.columns.join(", ");
+        match key.key_type {
+            SchemaKeyType::Primary => definitions.push(format!("PRIMARY KEY ({})", columns)),
+            SchemaKeyType::Unique => definitions.push(format!("UNIQUE ({})", columns)),
+            SchemaKeyType::Index => indexes.push(format!(
+                "CREATE INDEX IF NOT EXISTS IX_{}_{} ON {} ({})",
+                table.name,
+                key.columns.join("_"),
+                table.name,
+                columns,
+            )),
+        }
+    }
+
+    let mut statements = vec![format!("CREATE TABLE IF NOT EXISTS {} (\n    {}\n)", table.name, definitions.join(",\n    "))];
+    statements.extend(indexes);
+    statements
+}
+
+fn column_definition(column: &SchemaColumn) -> String {
+    let mut definition = format!("{} {}", column.name, column_type(column));
+    if column.required {
+        definition.push_str(" NOT NULL");
+    }
+    if let Some(default) = column.default.as_deref().and_then(default_value) {
+        definition.push_str(" DEFAULT ");
+        definition.push_str(&default);
+    }
+    definition
+}
+
+/// SQLite type names that keep the schema's type, e.g. `DECIMAL(9,2)` and `VARCHAR(50)`.
+fn column_type(column: &SchemaColumn) -> String {
+    let length = column.length.unwrap_or(1);
+    match column.column_type {
+        SchemaColumnType::Sequence
+        | SchemaColumnType::LongSequence
+        | SchemaColumnType::Byte
+        | SchemaColumnType::Short
+        | SchemaColumnType::Int
+        | SchemaColumnType::Long => "INTEGER".to_string(),
+        SchemaColumnType::Decimal => format!("DECIMAL({},{})", length, column.scale.unwrap_or(0)),
+        SchemaColumnType::Char => format!("CHAR({})", length),
+        SchemaColumnType::Varchar | SchemaColumnType::Enum => format!("VARCHAR({})", length),
+        SchemaColumnType::Text | SchemaColumnType::Memo => "TEXT".to_string(),
+        SchemaColumnType::Boolean => "BOOLEAN".to_string(),
+        SchemaColumnType::Date => "DATE".to_string(),
+        SchemaColumnType::DateTime => "DATETIME".to_string(),
+        SchemaColumnType::Time => "TIME".to_string(),
+        SchemaColumnType::Blob => "BLOB".to_string(),
+        SchemaColumnType::Uuid => "CHAR(36)".to_string(),
+    }
+}
+
+/// Booleans are stored as 1 and 0. A `null` default is no default.
+fn default_value(default: &str) -> Option<String> {
+    match default {
+        "null" => None,
+        "true" => Some("1".to_string()),
+        "false" => Some("0".to_string()),
+        default => Some(default.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workcontent::schema::schema_catalogue::{SchemaColumn, SchemaKey};
+
+    #[test]
+    fn tables_are_created_with_their_columns_keys_and_indexes() {
+        let mut table = SchemaTable::new("AssignmentShiftDetail".to_string());
+        let mut id = SchemaColumn::new("ID".to_string(), SchemaColumnType::Sequence);
+        id.required = true;
+        let mut hours_before = SchemaColumn::new("HoursBefore".to_string(), SchemaColumnType::Decimal);
+        (hours_before.length, hours_before.scale, hours_before.required) = (Some(9), Some(2), true);
+        hours_before.default = Some("0.0".to_string());
+        let mut locked = SchemaColumn::new("Locked".to_string(), SchemaColumnType::Boolean);
+        locked.default = Some("true".to_string());
+        table.columns = vec![id, hours_before, locked];
+        table.keys = vec![
+            SchemaKey { key_type: SchemaKeyType::Primary, columns: vec!["ID".to_string()], clustered: None },
+            SchemaKey { key_type: SchemaKeyType::Index, columns: vec!["HoursBefore".to_string(), "Locked".to_string()], clustered: None },
+        ];
+
+        assert_eq!(create_table_statements(&table), [
+            "CREATE TABLE IF NOT EXISTS AssignmentShiftDetail (\n    \
+                ID INTEGER NOT NULL,\n    \
+                HoursBefore DECIMAL(9,2) NOT NULL DEFAULT 0.0,\n    \
+                Locked BOOLEAN DEFAULT 1,\n    \
+                PRIMARY KEY (ID)\n)",
+            "CREATE INDEX IF NOT EXISTS IX_AssignmentShiftDetail_HoursBefore_Locked ON AssignmentShiftDetail (HoursBefore, Locked)",
+        ]);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod schema_catalogue;
+pub mod schema_reader;
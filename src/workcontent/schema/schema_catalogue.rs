@@ -0,0 +1,288 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaColumnType {
+    Sequence,
+    LongSequence,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Decimal,
+    Char,
+    Varchar,
+    Text,
+    Memo,
+    Boolean,
+    Date,
+    DateTime,
+    Time,
+    Enum,
+    Blob,
+    Uuid,
+}
+
+impl SchemaColumnType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let column_type = match name {
+            "sequence" => SchemaColumnType::Sequence,
+            "longsequence" => SchemaColumnType::LongSequence,
+            "byte" => SchemaColumnType::Byte,
+            "short" => SchemaColumnType::Short,
+            "int" => SchemaColumnType::Int,
+            "long" => SchemaColumnType::Long,
+            "decimal" => SchemaColumnType::Decimal,
+            "char" => SchemaColumnType::Char,
+            "varchar" => SchemaColumnType::Varchar,
+            "text" => SchemaColumnType::Text,
+            "memo" => SchemaColumnType::Memo,
+            "boolean" => SchemaColumnType::Boolean,
+            "date" => SchemaColumnType::Date,
+            "datetime" => SchemaColumnType::DateTime,
+            "time" => SchemaColumnType::Time,
+            "enum" => SchemaColumnType::Enum,
+            "blob" => SchemaColumnType::Blob,
+            "uuid" => SchemaColumnType::Uuid,
+            _ => return None,
+        };
+        Some(column_type)
+    }
+}
+
+/// A column of a table, with the limits the database puts on its values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaColumn {
+    pub name: String,
+    pub column_type: SchemaColumnType,
+    /// Characters of text and enum columns, digits of decimal columns.
+    pub length: Option<u32>,
+    pub scale: Option<u32>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    pub required: bool,
+    pub default: Option<String>,
+    pub enum_type: Option<String>,
+}
+
+impl SchemaColumn {
+    pub fn new(name: String, column_type: SchemaColumnType) -> Self {
+        Self {
+            name,
+            column_type,
+            length: None,
+            scale: None,
+            min_value: None,
+            max_value: None,
+            required: false,
+            default: None,
+            enum_type: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKeyType {
+    Primary,
+    Unique,
+    Index,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaKey {
+    pub key_type: SchemaKeyType,
+    pub columns: Vec<String>,
+    pub clustered: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaRelationType {
+    Cascade,
+    SetNull,
+    Enforce,
+    DoNothing,
+}
+
+impl SchemaRelationType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let relation_type = match name {
+            "cascade" => SchemaRelationType::Cascade,
+            "setnull" => SchemaRelationType::SetNull,
+            "enforce" => SchemaRelationType::Enforce,
+            "donothing" => SchemaRelationType::DoNothing,
+            _ => return None,
+        };
+        Some(relation_type)
+    }
+}
+
+/// A foreign key from a column of the table to a column of another table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaRelation {
+    pub source_column: String,
+    pub table: String,
+    pub column: String,
+    pub relation_type: SchemaRelationType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaTable {
+    pub name: String,
+    pub columns: Vec<SchemaColumn>,
+    pub keys: Vec<SchemaKey>,
+    pub relations: Vec<SchemaRelation>,
+}
+
+impl SchemaTable {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            columns: Vec::new(),
+            keys: Vec::new(),
+            relations: Vec::new(),
+        }
+    }
+
+    pub fn column(&self, name: &str) -> Option<&SchemaColumn> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    pub fn primary_key(&self) -> Option<&SchemaKey> {
+        self.keys.iter().find(|key| key.key_type == SchemaKeyType::Primary)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaEnumValue {
+    pub name: String,
+    /// Values without a `code` attribute are stored under their name.
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaEnum {
+    pub name: String,
+    pub values: Vec<SchemaEnumValue>,
+}
+
+impl SchemaEnum {
+    pub fn code_for(&self, name: &str) -> Option<&str> {
+        self.values.iter()
+            .find(|value| value.name == name)
+            .map(|value| value.code.as_str())
+    }
+
+    pub fn name_for(&self, code: &str) -> Option<&str> {
+        self.values.iter()
+            .find(|value| value.code == code)
+            .map(|value| value.name.as_str())
+    }
+}
+
+/// A value the schema does not allow in a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    pub table: String,
+    pub column: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}: {}", self.table, self.column, self.message)
+    }
+}
+
+impl std::error::Error for SchemaViolation {}
+
+/// The tables and enums of a database schema document such as `resources/schema.xml`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaCatalogue {
+    version: Option<String>,
+    tables: Vec<SchemaTable>,
+    enums: Vec<SchemaEnum>,
+}
+
+impl SchemaCatalogue {
+    pub fn new(version: Option<String>, tables: Vec<SchemaTable>, enums: Vec<SchemaEnum>) -> Self {
+        Self {
+            version,
+            tables,
+            enums,
+        }
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    pub fn tables(&self) -> &[SchemaTable] {
+        &self.tables
+    }
+
+    pub fn enums(&self) -> &[SchemaEnum] {
+        &self.enums
+    }
+
+    pub fn table(&self, name: &str) -> Option<&SchemaTable> {
+        self.tables.iter().find(|table| table.name == name)
+    }
+
+    pub fn enumeration(&self, name: &str) -> Option<&SchemaEnum> {
+        self.enums.iter().find(|enumeration| enumeration.name == name)
+    }
+
+    /// Checks a number against the column's `minValue`/`maxValue` and, for decimals, its
+    /// `decimal(length,scale)` digits.
+    pub fn check_number(&self, table: &str, column: &str, value: f64) -> Result<(), SchemaViolation> {
+        let schema_column = self.column(table, column)?;
+        let violation = |message: String| Self::violation(table, column, message);
+
+        if let Some(min_value) = schema_column.min_value
+            && value < min_value {
+            return Err(violation(format!("{} is below the minimum {}", value, min_value)));
+        }
+        if let Some(max_value) = schema_column.max_value
+            && value > max_value {
+            return Err(violation(format!("{} is above the maximum {}", value, max_value)));
+        }
+        if schema_column.column_type == SchemaColumnType::Decimal
+            && let Some(length) = schema_column.length {
+            let scale = schema_column.scale.unwrap_or(0);
+            let limit = 10_f64.powi(length as i32 - scale as i32);
+            if value.abs() >= limit {
+                return Err(violation(format!("{} does not fit decimal({},{})", value, length, scale)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that a code is one of the values of the column's enum.
+    pub fn check_code(&self, table: &str, column: &str, code: &str) -> Result<(), SchemaViolation> {
+        let schema_column = self.column(table, column)?;
+        let enum_type = schema_column.enum_type.as_deref()
+            .ok_or_else(|| Self::violation(table, column, "is not an enum column".to_string()))?;
+        let enumeration = self.enumeration(enum_type)
+            .ok_or_else(|| Self::violation(table, column, format!("unknown enum {}", enum_type)))?;
+
+        if enumeration.name_for(code).is_none() {
+            let codes: Vec<&str> = enumeration.values.iter().map(|value| value.code.as_str()).collect();
+            return Err(Self::violation(table, column, format!("\"{}\" is not a {} code ({})", code, enum_type, codes.join(", "))));
+        }
+        Ok(())
+    }
+
+    fn column(&self, table: &str, column: &str) -> Result<&SchemaColumn, SchemaViolation> {
+        self.table(table)
+            .ok_or_else(|| Self::violation(table, column, "unknown table".to_string()))?
+            .column(column)
+            .ok_or_else(|| Self::violation(table, column, "unknown column".to_string()))
+    }
+
+    fn violation(table: &str, column: &str, message: String) -> SchemaViolation {
+        SchemaViolation {
+            table: table.to_string(),
+            column: column.to_string(),
+            message,
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use xml::attribute::OwnedAttribute;
+use xml::common::Position;
+use xml::reader::{EventReader, XmlEvent};
+use crate::workcontent::loader::load_error::LoadError;
+use crate::workcontent::schema::schema_catalogue::{SchemaCatalogue, SchemaColumn, SchemaColumnType, SchemaEnum, SchemaEnumValue, SchemaKey, SchemaKeyType, SchemaRelation, SchemaRelationType, SchemaTable};
+
+/// Reads a database schema document (`resources/schema.xml`, `resources/watson60.xml`) into a
+/// catalogue of its tables and enums. Views, procedures, functions and triggers are skipped.
+/// Field errors name the table and column, e.g. `WorkContent.CalculatedHours.scale`.
+#[derive(Default)]
+pub struct SchemaReader;
+
+impl SchemaReader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn read_file(&self, path: impl AsRef<Path>) -> Result<SchemaCatalogue, LoadError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|error| LoadError::io(path, error))?;
+        self.read(BufReader::new(file))
+    }
+
+    pub fn read(&self, source: impl Read) -> Result<SchemaCatalogue, LoadError> {
+        let mut reader = EventReader::new(source);
+        let mut elements: Vec<String> = Vec::new();
+        let mut version = None;
+        let mut tables = Vec::new();
+        let mut enums = Vec::new();
+
+        loop {
+            let event = reader.next().map_err(|error| LoadError::Syntax {
+                line: error.position().row as usize + 1,
+                column: error.position().column as usize + 1,
+                message: error.msg().to_string(),
+            })?;
+
+            match event {
+                XmlEvent::StartElement { name, attributes, .. } => {
+                    let element = name.local_name;
+                    let parents: Vec<&str> = elements.iter().map(String::as_str).collect();
+
+                    match (parents.as_slice(), element.as_str()) {
+                        ([], "database") => version = attribute(&attributes, "version").map(str::to_string),
+                        (["database"], "table") => {
+                            tables.push(SchemaTable::new(required_attribute(&attributes, "name", "table")?.to_string()));
+                        }
+                        (["database", "table", "columns"], "column") => {
+                            let table = tables.last_mut().expect("inside a table");
+                            let column = Self::column(&table.name, &attributes)?;
+                            table.columns.push(column);
+                        }
+                        (["database", "table", "keys"], "primary" | "unique" | "index") => {
+                            let table = tables.last_mut().expect("inside a table");
+                            table.keys.push(SchemaKey {
+                                key_type: match element.as_str() {
+                                    "primary" => SchemaKeyType::Primary,
+                                    "unique" => SchemaKeyType::Unique,
+                                    _ => SchemaKeyType::Index,
+                                },
+                                columns: Vec::new(),
+                                clustered: attribute(&attributes, "cluster").map(|cluster| cluster == "true"),
+                            });
+                        }
+                        (["database", "table", "keys", _], "column") => {
+                            let table = tables.last_mut().expect("inside a table");
+                            let path = format!("{}.keys", table.name);
+                            let column = required_attribute(&attributes, "name", &path)?.to_string();
+                            table.keys.last_mut().expect("inside a key").columns.push(column);
+                        }
+                        (["database", "table", "relations"], "relation") => {
+                            let table = tables.last_mut().expect("inside a table");
+                            let relation = Self::relation(&table.name, &attributes)?;
+                            table.relations.push(relation);
+                        }
+                        (["database"], "enum") => enums.push(SchemaEnum {
+                            name: required_attribute(&attributes, "name", "enum")?.to_string(),
+                            values: Vec::new(),
+                        }),
+                        (["database", "enum"], "value") => {
+                            let enumeration: &mut SchemaEnum = enums.last_mut().expect("inside an enum");
+                            let name = required_attribute(&attributes, "name", &enumeration.name)?;
+                            enumeration.values.push(SchemaEnumValue {
+                                name: name.to_string(),
+                                code: attribute(&attributes, "code").unwrap_or(name).to_string(),
+                            });
+                        }
+                        _ => {}
+                    }
+
+                    elements.push(element);
+                }
+                XmlEvent::EndElement { .. } => {
+                    elements.pop();
+                }
+                XmlEvent::EndDocument => break,
+                _ => {}
+            }
+        }
+
+        Ok(SchemaCatalogue::new(version, tables, enums))
+    }
+
+    fn column(table: &str, attributes: &[OwnedAttribute]) -> Result<SchemaColumn, LoadError> {
+        let name = required_attribute(attributes, "name", &format!("{}.columns", table))?;
+        let path = format!("{}.{}", table, name);
+        let type_name = required_attribute(attributes, "type", &path)?;
+        let column_type = SchemaColumnType::from_name(type_name)
+            .ok_or_else(|| LoadError::invalid_field(&format!("{}.type", path), format!("unknown column type \"{}\"", type_name)))?;
+
+        let mut column = SchemaColumn::new(name.to_string(), column_type);
+        column.length = number_attribute(attributes, "length", &path)?;
+        column.scale = number_attribute(attributes, "scale", &path)?;
+        column.min_value = number_attribute(attributes, "minValue", &path)?;
+        column.max_value = number_attribute(attributes, "maxValue", &path)?;
+        column.required = attribute(attributes, "required") == Some("true");
+        column.default = attribute(attributes, "default").map(str::to_string);
+        column.enum_type = attribute(attributes, "enumType").map(str::to_string);
+
+        if column_type == SchemaColumnType::Enum && column.enum_type.is_none() {
+            return Err(LoadError::invalid_field(&format!("{}.enumType", path), "missing attribute"));
+        }
+        Ok(column)
+    }
+
+    fn relation(table: &str, attributes: &[OwnedAttribute]) -> Result<SchemaRelation, LoadError> {
+        let source_column = required_attribute(attributes, "src", &format!("{}.relations", table))?;
+        let path = format!("{}.relations.{}", table, source_column);
+        let type_name = required_attribute(attributes, "type", &path)?;
+
+        Ok(SchemaRelation {
+            source_column: source_column.to_string(),
+            table: required_attribute(attributes, "table", &path)?.to_string(),
+            column: required_attribute(attributes, "column", &path)?.to_string(),
+            relation_type: SchemaRelationType::from_name(type_name)
+                .ok_or_else(|| LoadError::invalid_field(&format!("{}.type", path), format!("unknown relation type \"{}\"", type_name)))?,
+        })
+    }
+}
+
+fn attribute<'a>(attributes: &'a [OwnedAttribute], name: &str) -> Option<&'a str> {
+    attributes.iter()
+        .find(|attribute| attribute.name.local_name == name)
+        .map(|attribute| attribute.value.as_str())
+}
+
+fn required_attribute<'a>(attributes: &'a [OwnedAttribute], name: &str, path: &str) -> Result<&'a str, LoadError> {
+    attribute(attributes, name)
+        .ok_or_else(|| LoadError::invalid_field(&format!("{}.{}", path, name), "missing attribute"))
+}
+
+fn number_attribute<T: std::str::FromStr>(attributes: &[OwnedAttribute], name: &str, path: &str) -> Result<Option<T>, LoadError> {
+    match attribute(attributes, name) {
+        Some(value) => value.parse()
+            .map(Some)
+            .map_err(|_| LoadError::invalid_field(&format!("{}.{}", path, name), format!("expected a number but was \"{}\"", value))),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resource(name: &str) -> SchemaCatalogue {
+        SchemaReader::new()
+            .read_file(Path::new(env!("CARGO_MANIFEST_DIR")).join("resources").join(name))
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case("schema.xml", 106, 57)]
+    #[case("watson60.xml", 464, 164)]
+    fn reads_every_table_and_enum(#[case] name: &str, #[case] tables: usize, #[case] enums: usize) {
+        let catalogue = resource(name);
+
+        assert_eq!(catalogue.tables().len(), tables);
+        assert_eq!(catalogue.enums().len(), enums);
+    }
+
+    #[test]
+    fn reads_columns_keys_relations_and_enums() {
+        let catalogue = resource("schema.xml");
+        let work_content = catalogue.table("WorkContent").unwrap();
+
+        assert_eq!(catalogue.version(), Some("10.6"));
+        assert_eq!(work_content.columns.len(), 25);
+        let calculated_hours = work_content.column("CalculatedHours").unwrap();
+        assert_eq!(calculated_hours.column_type, SchemaColumnType::Decimal);
+        assert_eq!((calculated_hours.length, calculated_hours.scale, calculated_hours.required), (Some(9), Some(2), true));
+        assert_eq!(work_content.column("ShiftType").unwrap().enum_type.as_deref(), Some("PlanType"));
+        assert_eq!(work_content.primary_key().unwrap().columns, ["ID"]);
+        assert_eq!(work_content.keys[1].clustered, Some(true));
+        assert_eq!(work_content.relations[0], SchemaRelation {
+            source_column: "JobID".to_string(),
+            table: "Assignment".to_string(),
+            column: "ID".to_string(),
+            relation_type: SchemaRelationType::Cascade,
+        });
+
+        let min_shift = catalogue.table("PropertyPlannerSettings").unwrap().column("MinShift").unwrap();
+        assert_eq!((min_shift.min_value, min_shift.max_value), (Some(0.0), Some(24.0)));
+
+        let units = catalogue.enumeration("UnitsEnum").unwrap();
+        assert_eq!(units.code_for("MINUTES_PER_UNIT"), Some("MU"));
+        assert_eq!(units.name_for("UP"), Some("UNITS_PER_PERSON"));
+    }
+
+    #[test]
+    fn checks_values_against_the_schema() {
+        let catalogue = resource("schema.xml");
+
+        assert!(catalogue.check_number("PropertyPlannerSettings", "MinShift", 4.0).is_ok());
+        assert_eq!(
+            catalogue.check_number("PropertyPlannerSettings", "MinShift", 25.0).unwrap_err().to_string(),
+            "PropertyPlannerSettings.MinShift: 25 is above the maximum 24",
+        );
+        assert!(catalogue.check_number("WorkContent", "CalculatedHours", 10_000_000.0).is_err());
+        assert!(catalogue.check_code("AssignmentPlannerSettings", "StandardType", "H").is_ok());
+        assert_eq!(
+            catalogue.check_code("AssignmentPlannerSettings", "StandardType", "B").unwrap_err().to_string(),
+            "AssignmentPlannerSettings.StandardType: \"B\" is not a StandardTypeEnum code (N, H, E, K, S)",
+        );
+        assert!(catalogue.check_code("WorkContent", "Description", "N").is_err());
+    }
+
+    #[test]
+    fn bad_documents_are_reported() {
+        let unknown_type = SchemaReader::new()
+            .read(r#"<database><table name="Job"><columns><column name="ID" type="serial"/></columns></table></database>"#.as_bytes())
+            .unwrap_err();
+        let unclosed = SchemaReader::new()
+            .read("<database>\n<table name=\"Job\">\n</database>".as_bytes())
+            .unwrap_err();
+
+        assert_eq!(unknown_type, LoadError::invalid_field("Job.ID.type", "unknown column type \"serial\""));
+        assert!(matches!(unclosed, LoadError::Syntax { line: 3, .. }));
+    }
+}
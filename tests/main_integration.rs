@@ -6,7 +6,6 @@ use planner::workcontent::domain::standard_type::StandardType;
 use planner::workcontent::main::main::generate_work_content;
 use date_range_rs::DateRange;
 use joda_rs::LocalDate;
-use std::collections::HashMap;
 
 #[test]
 fn integration_single_salaried_job_generates_correct_labor_data() {
@@ -23,10 +22,10 @@ fn integration_single_salaried_job_generates_correct_labor_data() {
     // Force salaried generator path
     settings.standard_type = StandardType::SALARIED;
     // Ensure effective dates include the whole year to match planner dates
-    settings.effective_dates = DateRange::new(
+    settings.effective_dates = vec![DateRange::new(
         LocalDate::new(2025, 1, 1),
         LocalDate::new(2025, 12, 31),
-    );
+    )];
 
     // Create a salaried job with no shifts/standards (expected hours will be 0.0 per day)
     let job = Job::new(location_id, settings, vec![], vec![]);
@@ -37,7 +36,7 @@ fn integration_single_salaried_job_generates_correct_labor_data() {
         standard_set_id,
         vec![job],
         vec![],
-        HashMap::new(),
+        vec![],
     );
 
     // Act